@@ -0,0 +1,28 @@
+use bevy_reflect::Reflect;
+
+// Reason: Two variants cannot share an alias
+#[derive(Reflect)]
+enum Foo {
+    #[reflect(alias = "Old")]
+    A,
+    #[reflect(alias = "Old")]
+    //~^ ERROR: variant alias `Old` is already used by variant `A`
+    B,
+}
+
+// Reason: An alias cannot shadow another variant's name
+#[derive(Reflect)]
+enum Bar {
+    A,
+    #[reflect(alias = "A")]
+    //~^ ERROR: variant alias `A` is already used by variant `A`
+    B,
+}
+
+// Reason: Aliases are only supported on enum variants
+#[derive(Reflect)]
+struct Baz {
+    #[reflect(alias = "old_value")]
+    //~^ ERROR: `alias` may only be used on enum variants
+    value: usize,
+}
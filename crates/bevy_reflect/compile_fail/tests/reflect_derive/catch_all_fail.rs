@@ -0,0 +1,28 @@
+use bevy_reflect::Reflect;
+
+// Reason: The catch-all variant needs a single field to store the variant name in
+#[derive(Reflect)]
+enum Foo {
+    A,
+    #[reflect(catch_all)]
+    //~^ ERROR: `catch_all` variants must have exactly one active field
+    Other(String, usize),
+}
+
+// Reason: Only one variant may be the catch-all
+#[derive(Reflect)]
+enum Bar {
+    #[reflect(catch_all)]
+    A(String),
+    #[reflect(catch_all)]
+    //~^ ERROR: only one variant may be marked `catch_all`
+    B(String),
+}
+
+// Reason: `catch_all` is only supported on enum variants
+#[derive(Reflect)]
+struct Baz {
+    #[reflect(catch_all)]
+    //~^ ERROR: `catch_all` may only be used on enum variants
+    value: String,
+}
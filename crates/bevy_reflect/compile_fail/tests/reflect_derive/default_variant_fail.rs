@@ -0,0 +1,19 @@
+use bevy_reflect::Reflect;
+
+#[derive(Reflect)]
+struct NoDefault;
+
+// Reason: `NoDefault` does not implement `Default`
+#[derive(Reflect)]
+#[reflect(default_variant)]
+enum Foo {
+    A(NoDefault),
+    //~^ ERROR: the trait bound `NoDefault: Default` is not satisfied
+    B,
+}
+
+// Reason: `default_variant` is only supported on enums
+#[derive(Reflect)]
+#[reflect(default_variant)]
+//~^ ERROR: `default_variant` is only supported on enums
+struct Bar;
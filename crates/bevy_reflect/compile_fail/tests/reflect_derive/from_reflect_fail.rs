@@ -18,6 +18,14 @@ struct Bar {
     value: String,
 }
 
+// Reason: `TryFrom` is implemented alongside `FromReflect`
+#[derive(Reflect)]
+#[reflect(from_reflect = false, try_from)]
+//~^ ERROR: cannot be used with `from_reflect = false`
+enum Qux {
+    A,
+}
+
 // Reason: Conflicting `FromReflect` implementations
 #[derive(Reflect, FromReflect)]
 //~^ ERROR: conflicting implementation
@@ -0,0 +1,18 @@
+use bevy_reflect::Reflect;
+
+// Reason: Tags are typed by the discriminants, so the enum must have an integer `#[repr(...)]`
+#[derive(Reflect)]
+#[reflect(from_tag)]
+//~^ ERROR: `from_tag` is only supported on enums with an integer `#[repr(...)]`
+enum Foo {
+    A,
+    B(u32),
+}
+
+// Reason: Only enums have variants to look up by tag
+#[derive(Reflect)]
+#[reflect(from_tag)]
+//~^ ERROR: `from_tag` is only supported on enums
+struct Bar {
+    value: u32,
+}
@@ -0,0 +1,15 @@
+use bevy_reflect::Reflect;
+
+// Reason: non-`'static` references cannot be reflected
+#[derive(Reflect)]
+enum Foo<'a> {
+    Value(&'a str),
+    //~^ ERROR: only `&'static` references can be reflected
+}
+
+// Reason: mutable references cannot be reflected
+#[derive(Reflect)]
+enum Bar {
+    Value { value: &'static mut u32 },
+    //~^ ERROR: only `&'static` references can be reflected
+}
@@ -0,0 +1,18 @@
+use bevy_reflect::Reflect;
+
+// Reason: Only a single variant can be reflected as its field
+#[derive(Reflect)]
+#[reflect(transparent)]
+//~^ ERROR: `transparent` is only supported on enums with a single variant
+enum Foo {
+    A(u32),
+    B(u32),
+}
+
+// Reason: Only a single field can be reflected in place of the enum
+#[derive(Reflect)]
+#[reflect(transparent)]
+//~^ ERROR: `transparent` is only supported on enums with a single variant
+enum Bar {
+    A(u32, u32),
+}
@@ -0,0 +1,21 @@
+use bevy_reflect::Reflect;
+
+fn get_default() -> Result<usize, &'static str> {
+    Ok(123)
+}
+
+// Reason: Ignored fields must always have a default
+#[derive(Reflect)]
+struct Foo {
+    #[reflect(ignore, try_default = "get_default")]
+    //~^ ERROR: `try_default` cannot be used on fields marked
+    value: usize,
+}
+
+// Reason: Cannot have both `default` and `try_default`
+#[derive(Reflect)]
+struct Bar {
+    #[reflect(default, try_default = "get_default")]
+    //~^ ERROR: only one of ["default", "try_default"] is allowed
+    value: usize,
+}
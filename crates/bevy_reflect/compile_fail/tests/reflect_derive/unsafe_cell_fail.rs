@@ -0,0 +1,16 @@
+use bevy_reflect::Reflect;
+use std::cell::UnsafeCell;
+
+// Reason: `UnsafeCell` fields cannot be reflected
+#[derive(Reflect)]
+enum Foo {
+    Value(UnsafeCell<u32>),
+    //~^ ERROR: `UnsafeCell` cannot be soundly accessed through reflection
+}
+
+// Reason: `UnsafeCell` fields cannot be reflected
+#[derive(Reflect)]
+struct Bar {
+    value: std::cell::UnsafeCell<u32>,
+    //~^ ERROR: `UnsafeCell` cannot be soundly accessed through reflection
+}
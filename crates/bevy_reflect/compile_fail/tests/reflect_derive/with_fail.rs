@@ -0,0 +1,18 @@
+use bevy_reflect::Reflect;
+
+mod custom {}
+
+// Reason: `with` is only supported on enum variant fields
+#[derive(Reflect)]
+struct Foo {
+    #[reflect(with = "custom")]
+    //~^ ERROR: `with` may only be used on enum variant fields
+    value: usize,
+}
+
+// Reason: Ignored fields are never reflected
+#[derive(Reflect)]
+enum Bar {
+    Baz(#[reflect(ignore, with = "custom")] usize),
+    //~^ ERROR: `with` cannot be used on fields marked `ignore`
+}
@@ -11,11 +11,15 @@ use crate::utility;
 use crate::utility::terminated_parser;
 use bevy_macro_utils::fq_std::{FQAny, FQOption};
 use proc_macro2::{Ident, Span};
-use quote::quote_spanned;
+use quote::{quote, quote_spanned};
 use syn::ext::IdentExt;
 use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{parenthesized, token, Expr, LitBool, MetaList, MetaNameValue, Path, Token, WhereClause};
+use syn::{
+    parenthesized, token, Expr, LitBool, LitStr, MetaList, MetaNameValue, Path, Token, Type,
+    WhereClause, WherePredicate,
+};
 
 mod kw {
     syn::custom_keyword!(from_reflect);
@@ -24,6 +28,37 @@ mod kw {
     syn::custom_keyword!(PartialEq);
     syn::custom_keyword!(Hash);
     syn::custom_keyword!(no_field_bounds);
+    syn::custom_keyword!(auto_migrate);
+    syn::custom_keyword!(case_insensitive);
+    syn::custom_keyword!(context);
+    syn::custom_keyword!(default);
+    syn::custom_keyword!(default_options);
+    syn::custom_keyword!(only);
+    syn::custom_keyword!(externally_tagged);
+    syn::custom_keyword!(try_from);
+    syn::custom_keyword!(discriminant_names);
+    syn::custom_keyword!(variant_names);
+    syn::custom_keyword!(default_variant);
+    syn::custom_keyword!(collect_apply_errors);
+    syn::custom_keyword!(split_variants);
+    syn::custom_keyword!(strict_apply);
+    syn::custom_keyword!(deny_unknown_fields);
+    syn::custom_keyword!(omit_default_fields);
+    syn::custom_keyword!(bound);
+    syn::custom_keyword!(debug_from_reflect);
+    syn::custom_keyword!(coerce_numeric);
+    syn::custom_keyword!(from_tag);
+    syn::custom_keyword!(transparent);
+    syn::custom_keyword!(from_dynamic_enum);
+    syn::custom_keyword!(common_field);
+    syn::custom_keyword!(rename_all);
+    syn::custom_keyword!(apply_struct_to_variant);
+    syn::custom_keyword!(apply_scalar_to_newtype);
+    syn::custom_keyword!(cold_errors);
+    syn::custom_keyword!(batch_missing_fields);
+    syn::custom_keyword!(repr_offsets);
+    syn::custom_keyword!(apply_counting);
+    syn::custom_keyword!(merge);
 }
 
 // The "special" trait idents that are used internally for reflection.
@@ -37,11 +72,22 @@ const HASH_ATTR: &str = "Hash";
 pub(crate) const REFLECT_DEFAULT: &str = "ReflectDefault";
 
 // Attributes for `FromReflect` implementation
-const FROM_REFLECT_ATTR: &str = "from_reflect";
+pub(crate) const FROM_REFLECT_ATTR: &str = "from_reflect";
 
 // Attributes for `TypePath` implementation
 const TYPE_PATH_ATTR: &str = "type_path";
 
+// Attributes for `TryFrom<&dyn Reflect>` implementation
+pub(crate) const TRY_FROM_ATTR: &str = "try_from";
+pub(crate) const DEFAULT_VARIANT_ATTR: &str = "default_variant";
+pub(crate) const FROM_TAG_ATTR: &str = "from_tag";
+pub(crate) const TRANSPARENT_ATTR: &str = "transparent";
+pub(crate) const FROM_DYNAMIC_ENUM_ATTR: &str = "from_dynamic_enum";
+pub(crate) const COMMON_FIELD_ATTR: &str = "common_field";
+pub(crate) const RENAME_ALL_ATTR: &str = "rename_all";
+pub(crate) const REPR_OFFSETS_ATTR: &str = "repr_offsets";
+pub(crate) const APPLY_COUNTING_ATTR: &str = "apply_counting";
+
 // The error message to show when a trait/type is specified multiple times
 const CONFLICTING_TYPE_DATA_MESSAGE: &str = "conflicting type data registration";
 
@@ -83,6 +129,13 @@ impl TraitImpl {
 #[derive(Clone, Default)]
 pub(crate) struct FromReflectAttrs {
     auto_derive: Option<LitBool>,
+    auto_migrate: bool,
+    default_fields: bool,
+    default_options: bool,
+    case_insensitive: bool,
+    context: Option<Type>,
+    only: Option<(Vec<Ident>, Span)>,
+    externally_tagged: Option<Span>,
 }
 
 impl FromReflectAttrs {
@@ -93,6 +146,157 @@ impl FromReflectAttrs {
             .map(|lit| lit.value())
             .unwrap_or(true)
     }
+
+    /// Returns true if deprecated variants should be constructed as their replacement variant.
+    pub fn should_auto_migrate(&self) -> bool {
+        self.auto_migrate
+    }
+
+    /// Returns true if fields without their own default attribute should fall back to `Default` when missing.
+    pub fn should_default_fields(&self) -> bool {
+        self.default_fields
+    }
+
+    /// Returns true if `Option` fields without their own default attribute should fall back to `None` when missing.
+    pub fn should_default_options(&self) -> bool {
+        self.default_options
+    }
+
+    /// Returns true if variant names should fall back to matching case-insensitively.
+    pub fn should_match_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Returns the type of the context passed to `from_reflect_with_context`, if one was given.
+    pub fn context(&self) -> Option<&Type> {
+        self.context.as_ref()
+    }
+
+    /// Returns the names of the only variants that may be constructed, along with the span of the attribute,
+    /// if they were restricted with `from_reflect(only = "...")`.
+    pub fn only(&self) -> Option<(&[Ident], Span)> {
+        self.only
+            .as_ref()
+            .map(|(variants, span)| (variants.as_slice(), *span))
+    }
+
+    /// Returns true if the variant with the given name may be constructed from reflected data.
+    pub fn includes_variant(&self, variant: &Ident) -> bool {
+        match &self.only {
+            Some((variants, _)) => variants.contains(variant),
+            None => true,
+        }
+    }
+
+    /// Returns the span of `from_reflect(externally_tagged)` if enums should also be constructed
+    /// from a single-entry map of their variant name to its fields.
+    pub fn externally_tagged(&self) -> Option<Span> {
+        self.externally_tagged
+    }
+
+    /// Parse a single option within a `#[reflect(from_reflect(...))]` attribute.
+    fn parse_option(&mut self, input: ParseStream) -> syn::Result<()> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::auto_migrate) {
+            input.parse::<kw::auto_migrate>()?;
+            self.auto_migrate = true;
+            Ok(())
+        } else if lookahead.peek(kw::default_options) {
+            input.parse::<kw::default_options>()?;
+            self.default_options = true;
+            Ok(())
+        } else if lookahead.peek(kw::default) {
+            input.parse::<kw::default>()?;
+            self.default_fields = true;
+            Ok(())
+        } else if lookahead.peek(kw::case_insensitive) {
+            input.parse::<kw::case_insensitive>()?;
+            self.case_insensitive = true;
+            Ok(())
+        } else if lookahead.peek(kw::context) {
+            input.parse::<kw::context>()?;
+            input.parse::<Token![=]>()?;
+            self.context = Some(input.parse()?);
+            Ok(())
+        } else if lookahead.peek(kw::externally_tagged) {
+            let ident = input.parse::<kw::externally_tagged>()?;
+            self.externally_tagged = Some(ident.span);
+            Ok(())
+        } else if lookahead.peek(kw::only) {
+            let ident = input.parse::<kw::only>()?;
+            input.parse::<Token![=]>()?;
+            let lit = input.parse::<LitStr>()?;
+            let variants = lit.parse_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+            self.only = Some((variants.into_iter().collect(), ident.span));
+            Ok(())
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+/// A field shared by every variant of an enum, created via `#[reflect(common_field(name: Type))]`.
+#[derive(Clone)]
+pub(crate) struct CommonField {
+    /// The name of the field.
+    pub ident: Ident,
+    /// The type every variant's field must have.
+    pub ty: Type,
+}
+
+/// A naming convention that field names are converted to, set via `#[reflect(rename_all = "...")]`.
+///
+/// Field names are assumed to be written in `snake_case`, as is conventional in Rust.
+#[derive(Clone, Copy)]
+pub(crate) enum RenameRule {
+    /// `snake_case`, which leaves names unchanged.
+    Snake,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `kebab-case`
+    Kebab,
+}
+
+impl RenameRule {
+    /// Parses the name of a naming convention, such as `"camelCase"`.
+    fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "snake_case" => Ok(Self::Snake),
+            "camelCase" => Ok(Self::Camel),
+            "PascalCase" => Ok(Self::Pascal),
+            "kebab-case" => Ok(Self::Kebab),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "expected one of `snake_case`, `camelCase`, `PascalCase` or `kebab-case`",
+            )),
+        }
+    }
+
+    /// Converts a `snake_case` name to this naming convention.
+    pub fn apply(self, name: &str) -> String {
+        match self {
+            Self::Snake => name.to_string(),
+            Self::Kebab => name.replace('_', "-"),
+            Self::Camel | Self::Pascal => {
+                let mut result = String::with_capacity(name.len());
+                let is_pascal_case = matches!(self, Self::Pascal);
+                let mut capitalize = is_pascal_case;
+                for char in name.chars() {
+                    if char == '_' {
+                        capitalize = is_pascal_case || !result.is_empty();
+                    } else if capitalize {
+                        result.extend(char.to_uppercase());
+                        capitalize = false;
+                    } else {
+                        result.push(char);
+                    }
+                }
+                result
+            }
+        }
+    }
 }
 
 /// A collection of attributes used for deriving `TypePath` via the `Reflect` derive.
@@ -187,7 +391,31 @@ pub(crate) struct ContainerAttributes {
     from_reflect_attrs: FromReflectAttrs,
     type_path_attrs: TypePathAttrs,
     custom_where: Option<WhereClause>,
+    custom_bound: Option<Punctuated<WherePredicate, Token![,]>>,
     no_field_bounds: bool,
+    try_from: Option<Span>,
+    discriminant_names: bool,
+    variant_names: bool,
+    default_variant: Option<Span>,
+    collect_apply_errors: bool,
+    split_variants: bool,
+    strict_apply: bool,
+    deny_unknown_fields: bool,
+    omit_default_fields: bool,
+    debug_from_reflect: bool,
+    coerce_numeric: bool,
+    from_tag: Option<Span>,
+    transparent: Option<Span>,
+    from_dynamic_enum: Option<Span>,
+    common_field: Option<CommonField>,
+    rename_all: Option<(RenameRule, Span)>,
+    apply_struct_to_variant: bool,
+    apply_scalar_to_newtype: bool,
+    cold_errors: bool,
+    batch_missing_fields: bool,
+    repr_offsets: Option<Span>,
+    apply_counting: Option<Span>,
+    merge: Option<Span>,
     custom_attributes: CustomAttributes,
     idents: Vec<Ident>,
 }
@@ -239,6 +467,54 @@ impl ContainerAttributes {
             self.parse_type_path(input, trait_)
         } else if lookahead.peek(kw::no_field_bounds) {
             self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::bound) {
+            self.parse_custom_bound(input)
+        } else if lookahead.peek(kw::try_from) {
+            self.parse_try_from(input)
+        } else if lookahead.peek(kw::discriminant_names) {
+            self.parse_discriminant_names(input)
+        } else if lookahead.peek(kw::variant_names) {
+            self.parse_variant_names(input)
+        } else if lookahead.peek(kw::default_variant) {
+            self.parse_default_variant(input)
+        } else if lookahead.peek(kw::collect_apply_errors) {
+            self.parse_collect_apply_errors(input)
+        } else if lookahead.peek(kw::split_variants) {
+            self.parse_split_variants(input)
+        } else if lookahead.peek(kw::strict_apply) {
+            self.parse_strict_apply(input)
+        } else if lookahead.peek(kw::deny_unknown_fields) {
+            self.parse_deny_unknown_fields(input)
+        } else if lookahead.peek(kw::omit_default_fields) {
+            self.parse_omit_default_fields(input)
+        } else if lookahead.peek(kw::debug_from_reflect) {
+            self.parse_debug_from_reflect(input)
+        } else if lookahead.peek(kw::coerce_numeric) {
+            self.parse_coerce_numeric(input)
+        } else if lookahead.peek(kw::from_tag) {
+            self.parse_from_tag(input)
+        } else if lookahead.peek(kw::transparent) {
+            self.parse_transparent(input)
+        } else if lookahead.peek(kw::from_dynamic_enum) {
+            self.parse_from_dynamic_enum(input)
+        } else if lookahead.peek(kw::common_field) {
+            self.parse_common_field(input)
+        } else if lookahead.peek(kw::rename_all) {
+            self.parse_rename_all(input)
+        } else if lookahead.peek(kw::apply_struct_to_variant) {
+            self.parse_apply_struct_to_variant(input)
+        } else if lookahead.peek(kw::apply_scalar_to_newtype) {
+            self.parse_apply_scalar_to_newtype(input)
+        } else if lookahead.peek(kw::cold_errors) {
+            self.parse_cold_errors(input)
+        } else if lookahead.peek(kw::batch_missing_fields) {
+            self.parse_batch_missing_fields(input)
+        } else if lookahead.peek(kw::repr_offsets) {
+            self.parse_repr_offsets(input)
+        } else if lookahead.peek(kw::apply_counting) {
+            self.parse_apply_counting(input)
+        } else if lookahead.peek(kw::merge) {
+            self.parse_merge(input)
         } else if lookahead.peek(kw::Debug) {
             self.parse_debug(input)
         } else if lookahead.peek(kw::PartialEq) {
@@ -347,6 +623,267 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `bound` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(bound = "T: FromReflect + TypePath")]`
+    /// - `#[reflect(bound = "")]`
+    fn parse_custom_bound(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.custom_bound.is_some() {
+            return Err(input.error("only one `bound` attribute is allowed"));
+        }
+
+        input.parse::<kw::bound>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+        self.custom_bound = Some(lit.parse_with(Punctuated::parse_terminated)?);
+        Ok(())
+    }
+
+    /// Parse `try_from` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(try_from)]`
+    fn parse_try_from(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::try_from>()?;
+        self.try_from = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `discriminant_names` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(discriminant_names)]`
+    fn parse_discriminant_names(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::discriminant_names>()?;
+        self.discriminant_names = true;
+        Ok(())
+    }
+
+    /// Parse `variant_names` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(variant_names)]`
+    fn parse_variant_names(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::variant_names>()?;
+        self.variant_names = true;
+        Ok(())
+    }
+
+    /// Parse `default_variant` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(default_variant)]`
+    fn parse_default_variant(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::default_variant>()?;
+        self.default_variant = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `collect_apply_errors` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(collect_apply_errors)]`
+    fn parse_collect_apply_errors(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::collect_apply_errors>()?;
+        self.collect_apply_errors = true;
+        Ok(())
+    }
+
+    /// Parse `split_variants` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(split_variants)]`
+    fn parse_split_variants(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::split_variants>()?;
+        self.split_variants = true;
+        Ok(())
+    }
+
+    /// Parse `strict_apply` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(strict_apply)]`
+    fn parse_strict_apply(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::strict_apply>()?;
+        self.strict_apply = true;
+        Ok(())
+    }
+
+    /// Parse `deny_unknown_fields` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(deny_unknown_fields)]`
+    fn parse_deny_unknown_fields(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::deny_unknown_fields>()?;
+        self.deny_unknown_fields = true;
+        Ok(())
+    }
+
+    /// Parse `omit_default_fields` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(omit_default_fields)]`
+    fn parse_omit_default_fields(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::omit_default_fields>()?;
+        self.omit_default_fields = true;
+        Ok(())
+    }
+
+    /// Parse `debug_from_reflect` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(debug_from_reflect)]`
+    fn parse_debug_from_reflect(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::debug_from_reflect>()?;
+        self.debug_from_reflect = true;
+        Ok(())
+    }
+
+    /// Parse `coerce_numeric` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(coerce_numeric)]`
+    fn parse_coerce_numeric(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::coerce_numeric>()?;
+        self.coerce_numeric = true;
+        Ok(())
+    }
+
+    /// Parse `apply_struct_to_variant` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(apply_struct_to_variant)]`
+    fn parse_apply_struct_to_variant(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::apply_struct_to_variant>()?;
+        self.apply_struct_to_variant = true;
+        Ok(())
+    }
+
+    /// Parse `apply_scalar_to_newtype` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(apply_scalar_to_newtype)]`
+    fn parse_apply_scalar_to_newtype(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::apply_scalar_to_newtype>()?;
+        self.apply_scalar_to_newtype = true;
+        Ok(())
+    }
+
+    /// Parse `rename_all` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(rename_all = "camelCase")]`
+    fn parse_rename_all(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::rename_all>()?;
+        input.parse::<Token![=]>()?;
+        let lit = input.parse::<LitStr>()?;
+        self.rename_all = Some((RenameRule::from_lit(&lit)?, ident.span));
+        Ok(())
+    }
+
+    /// Parse `cold_errors` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(cold_errors)]`
+    fn parse_cold_errors(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::cold_errors>()?;
+        self.cold_errors = true;
+        Ok(())
+    }
+
+    /// Parse `batch_missing_fields` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(batch_missing_fields)]`
+    fn parse_batch_missing_fields(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::batch_missing_fields>()?;
+        self.batch_missing_fields = true;
+        Ok(())
+    }
+
+    /// Parse `from_tag` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(from_tag)]`
+    fn parse_from_tag(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::from_tag>()?;
+        self.from_tag = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `repr_offsets` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(repr_offsets)]`
+    fn parse_repr_offsets(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::repr_offsets>()?;
+        self.repr_offsets = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `apply_counting` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(apply_counting)]`
+    fn parse_apply_counting(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::apply_counting>()?;
+        self.apply_counting = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `merge` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(merge)]`
+    fn parse_merge(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::merge>()?;
+        self.merge = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `transparent` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(transparent)]`
+    fn parse_transparent(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::transparent>()?;
+        self.transparent = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `from_dynamic_enum` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(from_dynamic_enum)]`
+    fn parse_from_dynamic_enum(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::from_dynamic_enum>()?;
+        self.from_dynamic_enum = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `common_field` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(common_field(id: u32))]`
+    fn parse_common_field(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.common_field.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [COMMON_FIELD_ATTR])));
+        }
+
+        input.parse::<kw::common_field>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let ident = content.parse::<Ident>()?;
+        content.parse::<Token![:]>()?;
+        let ty = content.parse::<Type>()?;
+
+        self.common_field = Some(CommonField { ident, ty });
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -360,11 +897,28 @@ impl ContainerAttributes {
     ///
     /// Examples:
     /// - `#[reflect(from_reflect = false)]`
+    /// - `#[reflect(from_reflect(auto_migrate))]`
+    /// - `#[reflect(from_reflect(default))]`
+    /// - `#[reflect(from_reflect(default_options))]`
+    /// - `#[reflect(from_reflect(case_insensitive))]`
+    /// - `#[reflect(from_reflect(context = AssetServer))]`
+    /// - `#[reflect(from_reflect(only = "Circle, Square"))]`
+    /// - `#[reflect(from_reflect(externally_tagged))]`
     fn parse_from_reflect(
         &mut self,
         input: ParseStream,
         trait_: ReflectTraitToImpl,
     ) -> syn::Result<()> {
+        if input.peek2(token::Paren) {
+            input.parse::<kw::from_reflect>()?;
+            let content;
+            parenthesized!(content in input);
+            terminated_parser(Token![,], |stream| {
+                self.from_reflect_attrs.parse_option(stream)
+            })(&content)?;
+            return Ok(());
+        }
+
         let pair = input.parse::<MetaNameValue>()?;
         let extracted_bool = extract_bool(&pair.value, |lit| {
             // Override `lit` if this is a `FromReflect` derive.
@@ -444,6 +998,13 @@ impl ContainerAttributes {
         &self.type_path_attrs
     }
 
+    /// Returns true if both `Hash` and `PartialEq` were registered using the type's own implementations,
+    /// rather than custom functions.
+    pub fn implements_hash_and_partial_eq(&self) -> bool {
+        matches!(self.hash, TraitImpl::Implemented(_))
+            && matches!(self.partial_eq, TraitImpl::Implemented(_))
+    }
+
     /// Returns the implementation of `Reflect::reflect_hash` as a `TokenStream`.
     ///
     /// If `Hash` was not registered, returns `None`.
@@ -473,15 +1034,30 @@ impl ContainerAttributes {
     pub fn get_partial_eq_impl(
         &self,
         bevy_reflect_path: &Path,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.get_partial_eq_impl_with_fallback(bevy_reflect_path, quote!(#FQOption::Some(false)))
+    }
+
+    /// Returns the implementation of `Reflect::reflect_partial_eq` as a `TokenStream`,
+    /// evaluating `fallback` when a registered `PartialEq` cannot be used
+    /// because `value` is not `Self`.
+    ///
+    /// The `fallback` expression may refer to the compared value as `value`.
+    ///
+    /// If `PartialEq` was not registered, returns `None`.
+    pub fn get_partial_eq_impl_with_fallback(
+        &self,
+        bevy_reflect_path: &Path,
+        fallback: proc_macro2::TokenStream,
     ) -> Option<proc_macro2::TokenStream> {
         match &self.partial_eq {
             &TraitImpl::Implemented(span) => Some(quote_spanned! {span=>
                 fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
-                    let value = <dyn #bevy_reflect_path::Reflect>::as_any(value);
-                    if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(value) {
-                        #FQOption::Some(::core::cmp::PartialEq::eq(self, value))
+                    let any = <dyn #bevy_reflect_path::Reflect>::as_any(value);
+                    if let #FQOption::Some(any) = <dyn #FQAny>::downcast_ref::<Self>(any) {
+                        #FQOption::Some(::core::cmp::PartialEq::eq(self, any))
                     } else {
-                        #FQOption::Some(false)
+                        #fallback
                     }
                 }
             }),
@@ -522,10 +1098,132 @@ impl ContainerAttributes {
         self.custom_where.as_ref()
     }
 
+    /// The predicates of the `bound` attribute found on this type, if any.
+    ///
+    /// These replace the bounds that would otherwise be generated for the active fields.
+    pub fn custom_bound(&self) -> Option<&Punctuated<WherePredicate, Token![,]>> {
+        self.custom_bound.as_ref()
+    }
+
     /// Returns true if the `no_field_bounds` attribute was found on this type.
     pub fn no_field_bounds(&self) -> bool {
         self.no_field_bounds
     }
+
+    /// Returns true if the `discriminant_names` attribute was found on this type.
+    pub fn discriminant_names(&self) -> bool {
+        self.discriminant_names
+    }
+
+    /// Returns true if the `variant_names` attribute was found on this type.
+    pub fn variant_names(&self) -> bool {
+        self.variant_names
+    }
+
+    /// Returns true if the `collect_apply_errors` attribute was found on this type.
+    pub fn collect_apply_errors(&self) -> bool {
+        self.collect_apply_errors
+    }
+
+    /// Returns true if the `split_variants` attribute was found on this type.
+    pub fn split_variants(&self) -> bool {
+        self.split_variants
+    }
+
+    /// Returns true if the `strict_apply` attribute was found on this type.
+    pub fn strict_apply(&self) -> bool {
+        self.strict_apply
+    }
+
+    /// Returns true if the `deny_unknown_fields` attribute was found on this type.
+    pub fn deny_unknown_fields(&self) -> bool {
+        self.deny_unknown_fields
+    }
+
+    /// Returns true if the `omit_default_fields` attribute was found on this type.
+    pub fn omit_default_fields(&self) -> bool {
+        self.omit_default_fields
+    }
+
+    /// Returns true if the `debug_from_reflect` attribute was found on this type.
+    pub fn debug_from_reflect(&self) -> bool {
+        self.debug_from_reflect
+    }
+
+    /// Returns true if the `coerce_numeric` attribute was found on this type.
+    pub fn coerce_numeric(&self) -> bool {
+        self.coerce_numeric
+    }
+
+    /// Returns true if the `apply_struct_to_variant` attribute was found on this type.
+    pub fn apply_struct_to_variant(&self) -> bool {
+        self.apply_struct_to_variant
+    }
+
+    /// Returns true if the `apply_scalar_to_newtype` attribute was found on this type.
+    pub fn apply_scalar_to_newtype(&self) -> bool {
+        self.apply_scalar_to_newtype
+    }
+
+    /// Returns the naming convention given by the `rename_all` attribute, along with its span, if any.
+    pub fn rename_all(&self) -> Option<(RenameRule, Span)> {
+        self.rename_all
+    }
+
+    /// Returns true if the `cold_errors` attribute was found on this type.
+    pub fn cold_errors(&self) -> bool {
+        self.cold_errors
+    }
+
+    /// Returns true if the `batch_missing_fields` attribute was found on this type.
+    pub fn batch_missing_fields(&self) -> bool {
+        self.batch_missing_fields
+    }
+
+    /// Returns the span of the `default_variant` attribute, if it was found on this type.
+    pub fn default_variant(&self) -> Option<Span> {
+        self.default_variant
+    }
+
+    /// Returns the field given by the `common_field` attribute, if it was found on this type.
+    pub fn common_field(&self) -> Option<&CommonField> {
+        self.common_field.as_ref()
+    }
+
+    /// Returns the span of the `from_dynamic_enum` attribute, if it was found on this type.
+    pub fn dynamic_enum_constructor_span(&self) -> Option<Span> {
+        self.from_dynamic_enum
+    }
+
+    /// Returns the span of the `transparent` attribute, if it was found on this type.
+    pub fn transparent(&self) -> Option<Span> {
+        self.transparent
+    }
+
+    /// Returns the span of the `from_tag` attribute, if it was found on this type.
+    pub fn tag_constructor_span(&self) -> Option<Span> {
+        self.from_tag
+    }
+
+    /// Returns the span of the `repr_offsets` attribute, if it was found on this type.
+    pub fn repr_offsets(&self) -> Option<Span> {
+        self.repr_offsets
+    }
+
+    /// Returns the span of the `apply_counting` attribute, if it was found on this type.
+    pub fn apply_counting(&self) -> Option<Span> {
+        self.apply_counting
+    }
+
+    /// Returns the span of the `merge` attribute, if it was found on this type.
+    pub fn merge(&self) -> Option<Span> {
+        self.merge
+    }
+
+    /// Returns the span of the `try_from` attribute, if it was found on this type.
+    pub fn try_from(&self) -> Option<Span> {
+        self.try_from
+    }
 }
 
 /// Adds an identifier to a vector of identifiers if it is not already present.
@@ -1,23 +1,37 @@
 use core::fmt;
 use proc_macro2::Span;
+use std::collections::HashMap;
 
-use crate::container_attributes::{ContainerAttributes, FromReflectAttrs, TypePathAttrs};
-use crate::field_attributes::FieldAttributes;
+use crate::container_attributes::{
+    ContainerAttributes, FromReflectAttrs, RenameRule, TypePathAttrs, APPLY_COUNTING_ATTR,
+    COMMON_FIELD_ATTR, DEFAULT_VARIANT_ATTR, FROM_DYNAMIC_ENUM_ATTR, FROM_REFLECT_ATTR,
+    FROM_TAG_ATTR, REFLECT_DEFAULT, RENAME_ALL_ATTR, REPR_OFFSETS_ATTR, TRANSPARENT_ATTR,
+    TRY_FROM_ATTR,
+};
+use crate::enum_utility::{DefaultVariantBuilder, EnumVariantOutputData, VariantBuilder};
+use crate::field_attributes::{
+    DefaultBehavior, FieldAttributes, MergePolicy, ReflectIgnoreBehavior, ALIAS_ATTR, APPLY_ATTR,
+    APPLY_VIA_ATTR, CATCH_ALL_ATTR, DEFAULT_ATTR, DEPRECATED_ATTR, EQ_ATTR, FROM_ATTR, HIDDEN_ATTR,
+    IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR, MERGE_ATTR, SKIP_IF_ATTR, TRY_DEFAULT_ATTR,
+    WITH_ATTR,
+};
 use crate::type_path::parse_path_no_leading_colon;
 use crate::utility::{StringExpr, WhereClauseOptions};
+use bevy_macro_utils::fq_std::{FQBox, FQOption};
 use quote::{quote, ToTokens};
 use syn::token::Comma;
 
-use crate::serialization::SerializationDataDef;
+use crate::serialization::{EnumSerializationDataDef, SerializationDataDef};
 use crate::{
     utility, REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME, TYPE_NAME_ATTRIBUTE_NAME,
     TYPE_PATH_ATTRIBUTE_NAME,
 };
+use syn::ext::IdentExt;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    parse_str, Data, DeriveInput, Field, Fields, GenericParam, Generics, Ident, LitStr, Meta, Path,
-    PathSegment, Type, TypeParam, Variant,
+    parse_str, Attribute, Data, DeriveInput, Expr, Field, Fields, GenericParam, Generics, Ident,
+    LitStr, Meta, Path, PathSegment, Type, TypeParam, Variant,
 };
 
 pub(crate) enum ReflectDerive<'a> {
@@ -88,6 +102,13 @@ pub(crate) struct ReflectStruct<'a> {
 pub(crate) struct ReflectEnum<'a> {
     meta: ReflectMeta<'a>,
     variants: Vec<EnumVariant<'a>>,
+    /// Maps each variant name to its index in `variants`.
+    variant_indices: HashMap<String, usize>,
+    serialization_data: Option<EnumSerializationDataDef>,
+    /// The integer type given by the enum's `#[repr(...)]` attribute, if any.
+    repr: Option<Ident>,
+    /// The alignment given by the enum's `#[repr(align(N))]` attribute, if any.
+    align: Option<proc_macro2::TokenStream>,
 }
 
 /// Represents a field on a struct or tuple struct.
@@ -106,6 +127,10 @@ pub(crate) struct StructField<'a> {
     ///
     /// [ignored]: crate::field_attributes::ReflectIgnoreBehavior::IgnoreAlways
     pub reflection_index: Option<usize>,
+    /// The name of this field as seen by the reflection API, or `None` if the field is unnamed.
+    ///
+    /// This is the field's identifier, converted by `#[reflect(rename_all = "...")]` for enum variant fields.
+    pub name: Option<String>,
     /// The documentation for this field, if any
     #[cfg(feature = "documentation")]
     pub doc: crate::documentation::Documentation,
@@ -120,14 +145,52 @@ pub(crate) struct EnumVariant<'a> {
     /// The reflection-based attributes on the variant.
     #[allow(dead_code)]
     pub attrs: FieldAttributes,
-    /// The index of this variant within the enum.
-    #[allow(dead_code)]
+    /// The index of this variant within the enum definition.
+    ///
+    /// This counts `#[cfg]`-gated variants, see [`ReflectEnum::variant_index_tokens`] for the reflected index.
     pub index: usize,
+    /// The discriminant of this variant.
+    pub discriminant: VariantDiscriminant<'a>,
     /// The documentation for this variant, if any
     #[cfg(feature = "documentation")]
     pub doc: crate::documentation::Documentation,
 }
 
+/// The discriminant of an enum variant.
+///
+/// Rust assigns implicit discriminants by incrementing the discriminant of the previous variant,
+/// so this is stored as the offset from the last explicit discriminant (if any).
+#[derive(Clone, Default)]
+pub(crate) struct VariantDiscriminant<'a> {
+    /// The last explicit discriminant expression at or before this variant.
+    pub base: Option<&'a Expr>,
+    /// The offset of this variant from `base` (or from zero if there is no `base`).
+    pub offset: usize,
+    /// The `cfg!(...)` predicates of any `#[cfg]`-gated variants between `base` and this variant.
+    ///
+    /// Each of these adds one to the offset, but only when the gated variant is compiled in.
+    pub gated_offsets: Vec<proc_macro2::TokenStream>,
+}
+
+impl<'a> VariantDiscriminant<'a> {
+    /// Returns a const-evaluable expression of the discriminant cast to the given integer type.
+    pub fn to_tokens_as(&self, ty: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let offset = self.offset;
+        let gated_offsets = &self.gated_offsets;
+        let discriminant = match self.base {
+            Some(base) if offset == 0 => quote!(((#base) as #ty)),
+            Some(base) => quote!(((#base) as #ty + #offset as #ty)),
+            None => quote!((#offset as #ty)),
+        };
+
+        if gated_offsets.is_empty() {
+            discriminant
+        } else {
+            quote!((#discriminant #(+ (#gated_offsets as #ty))*))
+        }
+    }
+}
+
 pub(crate) enum EnumVariantFields<'a> {
     Named(Vec<StructField<'a>>),
     Unnamed(Vec<StructField<'a>>),
@@ -315,9 +378,183 @@ impl<'a> ReflectDerive<'a> {
             return Ok(Self::Value(meta));
         }
 
+        if let Some(span) = meta.attrs().try_from() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{TRY_FROM_ATTR}` is only supported on enums"),
+                ));
+            }
+
+            // The `TryFrom` implementation is generated alongside `FromReflect`
+            if !meta.from_reflect().should_auto_derive() {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!(
+                        "`{TRY_FROM_ATTR}` cannot be used with `{FROM_REFLECT_ATTR} = false`"
+                    ),
+                ));
+            }
+        }
+
+        if let Some(span) = meta.attrs().default_variant() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{DEFAULT_VARIANT_ATTR}` is only supported on enums"),
+                ));
+            }
+        }
+
+        if let Some(span) = meta.attrs().tag_constructor_span() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{FROM_TAG_ATTR}` is only supported on enums"),
+                ));
+            }
+
+            // Tags are typed by the discriminants, so their integer type must be known
+            if utility::int_repr(&input.attrs).is_none() {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!(
+                        "`{FROM_TAG_ATTR}` is only supported on enums with an integer `#[repr(...)]`"
+                    ),
+                ));
+            }
+        }
+
+        if let Some(span) = meta.attrs().apply_counting() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{APPLY_COUNTING_ATTR}` is only supported on enums"),
+                ));
+            }
+        }
+
+        if let Some(span) = meta.attrs().merge() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{MERGE_ATTR}` is only supported on enums"),
+                ));
+            }
+        }
+
+        if let Some(span) = meta.attrs().repr_offsets() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{REPR_OFFSETS_ATTR}` is only supported on enums"),
+                ));
+            }
+
+            // Offsets are only meaningful when the layout is defined
+            if !utility::has_c_repr(&input.attrs) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!(
+                        "`{REPR_OFFSETS_ATTR}` is only supported on enums with a `#[repr(C)]` attribute"
+                    ),
+                ));
+            }
+
+            // The layout is mirrored by local items, which cannot name the enum's generic parameters
+            if !input.generics.params.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{REPR_OFFSETS_ATTR}` is not supported on generic enums"),
+                ));
+            }
+        }
+
+        if let Some(span) = meta.attrs().dynamic_enum_constructor_span() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{FROM_DYNAMIC_ENUM_ATTR}` is only supported on enums"),
+                ));
+            }
+        }
+
+        if let Some(common_field) = meta.attrs().common_field() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    common_field.ident.span(),
+                    format_args!("`{COMMON_FIELD_ATTR}` is only supported on enums"),
+                ));
+            }
+        }
+
+        if let Some((_, span)) = meta.attrs().rename_all() {
+            if !matches!(input.data, Data::Enum(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`{RENAME_ALL_ATTR}` is only supported on enums"),
+                ));
+            }
+        }
+
+        if let Some(context) = meta.from_reflect().context() {
+            if !matches!(input.data, Data::Enum(..)) || meta.attrs().transparent().is_some() {
+                return Err(syn::Error::new(
+                    context.span(),
+                    "`from_reflect(context = ...)` is only supported on enums that are not `transparent`",
+                ));
+            }
+        }
+
+        if let Some(span) = meta.from_reflect().externally_tagged() {
+            if !matches!(input.data, Data::Enum(..)) || meta.attrs().transparent().is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`from_reflect(externally_tagged)` is only supported on enums that are not `transparent`",
+                ));
+            }
+        }
+
+        if let Some((_, span)) = meta.from_reflect().only() {
+            if !matches!(input.data, Data::Enum(..)) || meta.attrs().transparent().is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`from_reflect(only = \"...\")` is only supported on enums that are not `transparent`",
+                ));
+            }
+        }
+
         return match &input.data {
             Data::Struct(data) => {
-                let fields = Self::collect_struct_fields(&data.fields)?;
+                let mut fields = Self::collect_struct_fields(&data.fields)?;
+
+                if meta.from_reflect().should_default_fields() {
+                    Self::default_missing_fields(&mut fields);
+                }
+
+                if meta.from_reflect().should_default_options() {
+                    Self::default_missing_options(&mut fields);
+                }
+
+                if let Some((attr, span)) = fields
+                    .iter()
+                    .find_map(|field| field.attrs.enum_field_only_span())
+                {
+                    return Err(syn::Error::new(
+                        span,
+                        format_args!("`{attr}` may only be used on enum variant fields"),
+                    ));
+                }
+
+                if let Some(span) = meta.attrs().transparent() {
+                    if !matches!(fields.as_slice(), [field] if field.attrs.ignore.is_active()) {
+                        return Err(syn::Error::new(
+                            span,
+                            format_args!("`{TRANSPARENT_ATTR}` is only supported on structs with a single reflected field"),
+                        ));
+                    }
+                }
+
                 let reflect_struct = ReflectStruct {
                     meta,
                     serialization_data: SerializationDataDef::new(&fields)?,
@@ -331,9 +568,98 @@ impl<'a> ReflectDerive<'a> {
                 }
             }
             Data::Enum(data) => {
-                let variants = Self::collect_enum_variants(&data.variants)?;
+                let mut variants = Self::collect_enum_variants(&data.variants)?;
+
+                if let Some((rule, _)) = meta.attrs().rename_all() {
+                    Self::rename_variant_fields(&mut variants, rule)?;
+                }
+
+                // Field policies have no effect without the generated `reflect_merge` method
+                if meta.attrs().merge().is_none() {
+                    if let Some((_, span)) = variants
+                        .iter()
+                        .flat_map(|variant| variant.fields())
+                        .find_map(|field| field.attrs.merge)
+                    {
+                        return Err(syn::Error::new(
+                            span,
+                            format_args!(
+                                "`{MERGE_ATTR} = \"...\"` requires the enum to be marked `#[{REFLECT_ATTRIBUTE_NAME}({MERGE_ATTR})]`"
+                            ),
+                        ));
+                    }
+                }
+
+                if let Some((only, _)) = meta.from_reflect().only() {
+                    Self::validate_from_reflect_only(&variants, only, meta.from_reflect())?;
+                }
+
+                if meta.from_reflect().should_default_fields() {
+                    for variant in &mut variants {
+                        if let EnumVariantFields::Named(fields)
+                        | EnumVariantFields::Unnamed(fields) = &mut variant.fields
+                        {
+                            Self::default_missing_fields(fields);
+                        }
+                    }
+                }
+
+                if meta.from_reflect().should_default_options() {
+                    for variant in &mut variants {
+                        if let EnumVariantFields::Named(fields)
+                        | EnumVariantFields::Unnamed(fields) = &mut variant.fields
+                        {
+                            Self::default_missing_options(fields);
+                        }
+                    }
+                }
 
-                let reflect_enum = ReflectEnum { meta, variants };
+                if let Some(span) = meta.attrs().transparent() {
+                    let is_newtype = match variants.as_slice() {
+                        [variant] => matches!(
+                            variant.fields(),
+                            [field] if field.attrs.ignore.is_active()
+                        ),
+                        _ => false,
+                    };
+
+                    if !is_newtype {
+                        return Err(syn::Error::new(
+                            span,
+                            format_args!("`{TRANSPARENT_ATTR}` is only supported on enums with a single variant containing a single reflected field"),
+                        ));
+                    }
+                }
+
+                // The common field's type is checked by the compiler in the generated accessor
+                if let Some(common_field) = meta.attrs().common_field() {
+                    let missing = variants.iter().find(|variant| {
+                        !variant
+                            .fields()
+                            .iter()
+                            .any(|field| field.data.ident.as_ref() == Some(&common_field.ident))
+                    });
+
+                    if let Some(variant) = missing {
+                        return Err(syn::Error::new(
+                            variant.data.span(),
+                            format_args!(
+                                "variant `{}` has no field `{}`, but it is required by `{COMMON_FIELD_ATTR}`",
+                                variant.data.ident, common_field.ident
+                            ),
+                        ));
+                    }
+                }
+
+                let serialization_data = EnumSerializationDataDef::new(&variants)?;
+
+                let reflect_enum = ReflectEnum::new(
+                    meta,
+                    variants,
+                    serialization_data,
+                    utility::int_repr(&input.attrs),
+                    utility::align_repr(&input.attrs),
+                );
                 Ok(Self::Enum(reflect_enum))
             }
             Data::Union(..) => Err(syn::Error::new(
@@ -353,6 +679,67 @@ impl<'a> ReflectDerive<'a> {
         }
     }
 
+    /// Makes every field without its own default attribute fall back to `Default` when missing,
+    /// as requested by `#[reflect(from_reflect(default))]`.
+    ///
+    /// Boxed trait objects marked `#[reflect(apply_via = "downcast")]` have no `Default`, so they are left required.
+    fn default_missing_fields(fields: &mut [StructField<'a>]) {
+        for field in fields {
+            if matches!(field.attrs.default, DefaultBehavior::Required)
+                && field.attrs.apply_via_downcast.is_none()
+            {
+                field.attrs.default = DefaultBehavior::Default;
+            }
+        }
+    }
+
+    /// Makes every `Option` field without its own default attribute fall back to `None` when missing,
+    /// as requested by `#[reflect(from_reflect(default_options))]`.
+    fn default_missing_options(fields: &mut [StructField<'a>]) {
+        for field in fields {
+            if matches!(field.attrs.default, DefaultBehavior::Required)
+                && utility::is_option(&field.data.ty)
+            {
+                field.attrs.default = DefaultBehavior::Default;
+            }
+        }
+    }
+
+    /// Converts the names of every named variant field to the naming convention
+    /// given by `#[reflect(rename_all = "...")]`.
+    ///
+    /// Fails if two fields of the same variant end up with the same name.
+    fn rename_variant_fields(
+        variants: &mut [EnumVariant<'a>],
+        rule: RenameRule,
+    ) -> Result<(), syn::Error> {
+        for variant in variants {
+            let EnumVariantFields::Named(fields) = &mut variant.fields else {
+                continue;
+            };
+
+            let mut names = HashMap::new();
+            for field in fields {
+                let Some(ident) = &field.data.ident else {
+                    continue;
+                };
+
+                let name = rule.apply(&ident.unraw().to_string());
+                if let Some(other) = names.insert(name.clone(), ident) {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format_args!(
+                            "field `{ident}` is renamed to `{name}`, which is already the name of field `{other}`"
+                        ),
+                    ));
+                }
+                field.name = Some(name);
+            }
+        }
+
+        Ok(())
+    }
+
     fn collect_struct_fields(fields: &'a Fields) -> Result<Vec<StructField<'a>>, syn::Error> {
         let mut active_index = 0;
         let sifter: utility::ResultSifter<StructField<'a>> = fields
@@ -360,7 +747,170 @@ impl<'a> ReflectDerive<'a> {
             .enumerate()
             .map(
                 |(declaration_index, field)| -> Result<StructField, syn::Error> {
-                    let attrs = FieldAttributes::parse_attributes(&field.attrs)?;
+                    let mut attrs = FieldAttributes::parse_attributes(&field.attrs)?;
+
+                    // `PhantomData` holds no data to reflect, so it is always constructed directly
+                    if attrs.ignore == ReflectIgnoreBehavior::None
+                        && utility::is_phantom_data(&field.ty)
+                    {
+                        attrs.ignore = ReflectIgnoreBehavior::IgnoreAlways;
+                    }
+
+                    if attrs.deprecation.is_some() {
+                        return Err(syn::Error::new(
+                            field.span(),
+                            format_args!("`{DEPRECATED_ATTR}` may only be used on enum variants"),
+                        ));
+                    }
+
+                    if let Some(alias) = attrs.aliases.first() {
+                        return Err(syn::Error::new(
+                            alias.span(),
+                            format_args!("`{ALIAS_ATTR}` may only be used on enum variants"),
+                        ));
+                    }
+
+                    if let Some(span) = attrs.catch_all {
+                        return Err(syn::Error::new(
+                            span,
+                            format_args!("`{CATCH_ALL_ATTR}` may only be used on enum variants"),
+                        ));
+                    }
+
+                    if let Some(span) = attrs.hidden {
+                        return Err(syn::Error::new(
+                            span,
+                            format_args!("`{HIDDEN_ATTR}` may only be used on enum variants"),
+                        ));
+                    }
+
+                    if attrs.ignore != ReflectIgnoreBehavior::None
+                        && matches!(attrs.default, DefaultBehavior::TryFunc(_))
+                    {
+                        return Err(syn::Error::new(
+                            field.span(),
+                            format_args!(
+                                "`{TRY_DEFAULT_ATTR}` cannot be used on fields marked `{IGNORE_ALL_ATTR}` or `{IGNORE_SERIALIZATION_ATTR}`"
+                            ),
+                        ));
+                    }
+
+                    if let Some(from) = &attrs.from {
+                        if field.ident.is_none() || attrs.ignore.is_ignored() {
+                            return Err(syn::Error::new(
+                                from.span(),
+                                format_args!(
+                                    "`{FROM_ATTR}` may only be used on named fields that are not marked `{IGNORE_ALL_ATTR}`"
+                                ),
+                            ));
+                        }
+                    }
+
+                    if let Some(skip_if) = &attrs.skip_if {
+                        if field.ident.is_none() || attrs.ignore != ReflectIgnoreBehavior::None {
+                            return Err(syn::Error::new(
+                                skip_if.span(),
+                                format_args!(
+                                    "`{SKIP_IF_ATTR}` may only be used on named fields that are not marked `{IGNORE_ALL_ATTR}` or `{IGNORE_SERIALIZATION_ATTR}`"
+                                ),
+                            ));
+                        }
+
+                        // A skipped field is absent from the serialized data, so it must be defaultable
+                        if matches!(attrs.default, DefaultBehavior::Required) {
+                            attrs.default = DefaultBehavior::Default;
+                        }
+                    }
+
+                    if let Some(span) = attrs.ptr_eq {
+                        if attrs.ignore.is_ignored() {
+                            return Err(syn::Error::new(
+                                span,
+                                format_args!(
+                                    "`{EQ_ATTR}` cannot be used on fields marked `{IGNORE_ALL_ATTR}`"
+                                ),
+                            ));
+                        }
+                    }
+
+                    if let Some(span) = attrs.apply_via_downcast {
+                        if attrs.ignore.is_ignored() || attrs.with.is_some() {
+                            return Err(syn::Error::new(
+                                span,
+                                format_args!(
+                                    "`{APPLY_VIA_ATTR}` cannot be used on fields marked `{IGNORE_ALL_ATTR}` or `{WITH_ATTR}`"
+                                ),
+                            ));
+                        }
+
+                        if !utility::is_boxed_trait_object(&field.ty) {
+                            return Err(syn::Error::new(
+                                span,
+                                format_args!(
+                                    "`{APPLY_VIA_ATTR}` may only be used on `Box<dyn Trait>` fields"
+                                ),
+                            ));
+                        }
+                    }
+
+                    if let Some(span) = attrs.apply_extend {
+                        if attrs.ignore.is_ignored() || attrs.apply_via_downcast.is_some() {
+                            return Err(syn::Error::new(
+                                span,
+                                format_args!(
+                                    "`{APPLY_ATTR}` cannot be used on fields marked `{IGNORE_ALL_ATTR}` or `{APPLY_VIA_ATTR}`"
+                                ),
+                            ));
+                        }
+                    }
+
+                    if let Some((policy, span)) = attrs.merge {
+                        if attrs.ignore.is_ignored() {
+                            return Err(syn::Error::new(
+                                span,
+                                format_args!(
+                                    "`{MERGE_ATTR}` cannot be used on fields marked `{IGNORE_ALL_ATTR}`"
+                                ),
+                            ));
+                        }
+
+                        // Only `Option` fields have a value to skip when merging
+                        if policy == MergePolicy::Combine && !utility::is_option(&field.ty) {
+                            return Err(syn::Error::new(
+                                span,
+                                format_args!(
+                                    "`{MERGE_ATTR} = \"combine\"` may only be used on `Option` fields"
+                                ),
+                            ));
+                        }
+                    }
+
+                    if attrs.ignore.is_ignored() && attrs.with.is_some() {
+                        return Err(syn::Error::new(
+                            field.span(),
+                            format_args!(
+                                "`{WITH_ATTR}` cannot be used on fields marked `{IGNORE_ALL_ATTR}`"
+                            ),
+                        ));
+                    }
+
+                    if attrs.ignore.is_active() && utility::is_unsafe_cell(&field.ty) {
+                        return Err(syn::Error::new_spanned(
+                            &field.ty,
+                            format_args!(
+                                "`UnsafeCell` cannot be soundly accessed through reflection, consider marking this field with `#[{REFLECT_ATTRIBUTE_NAME}({IGNORE_ALL_ATTR})]`"
+                            ),
+                        ));
+                    }
+
+                    if attrs.ignore.is_active() && utility::is_non_static_reference(&field.ty) {
+                        return Err(syn::Error::new_spanned(
+                            &field.ty,
+                            format_args!(
+                                "only `&'static` references can be reflected, consider marking this field with `#[{REFLECT_ATTRIBUTE_NAME}({IGNORE_ALL_ATTR})]`"
+                            ),
+                        ));
+                    }
 
                     let reflection_index = if attrs.ignore.is_ignored() {
                         None
@@ -374,6 +924,7 @@ impl<'a> ReflectDerive<'a> {
                         reflection_index,
                         attrs,
                         data: field,
+                        name: field.ident.as_ref().map(|ident| ident.unraw().to_string()),
                         #[cfg(feature = "documentation")]
                         doc: crate::documentation::Documentation::from_attributes(&field.attrs),
                     })
@@ -387,14 +938,172 @@ impl<'a> ReflectDerive<'a> {
         sifter.finish()
     }
 
+    /// Checks that every variant named by `from_reflect(only = "...")` exists and is not ignored,
+    /// and that neither the catch-all variant nor the replacement of a variant migrated by
+    /// `from_reflect(auto_migrate)` is excluded.
+    fn validate_from_reflect_only(
+        variants: &[EnumVariant],
+        only: &[Ident],
+        from_reflect: &FromReflectAttrs,
+    ) -> Result<(), syn::Error> {
+        for name in only {
+            if !variants
+                .iter()
+                .any(|variant| &variant.data.ident == name && variant.attrs.ignore.is_active())
+            {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format_args!("variant `{name}` does not exist or is ignored"),
+                ));
+            }
+        }
+
+        // Unknown variant names would otherwise still construct an excluded catch-all variant
+        if let Some(span) = variants.iter().find_map(|variant| {
+            variant
+                .attrs
+                .catch_all
+                .filter(|_| !from_reflect.includes_variant(&variant.data.ident))
+        }) {
+            return Err(syn::Error::new(
+                span,
+                format_args!("the `{CATCH_ALL_ATTR}` variant must be listed in `from_reflect(only = \"...\")`"),
+            ));
+        }
+
+        if !from_reflect.should_auto_migrate() {
+            return Ok(());
+        }
+
+        for variant in variants {
+            let Some(replaced_by) = variant
+                .attrs
+                .deprecation
+                .as_ref()
+                .and_then(|deprecation| deprecation.replaced_by.as_ref())
+            else {
+                continue;
+            };
+
+            if from_reflect.includes_variant(&variant.data.ident)
+                && !only.iter().any(|name| *name == replaced_by.value())
+            {
+                return Err(syn::Error::new(
+                    replaced_by.span(),
+                    format_args!(
+                        "replacement variant `{}` cannot be constructed, since it is not listed in `from_reflect(only = \"...\")`",
+                        replaced_by.value()
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     fn collect_enum_variants(
         variants: &'a Punctuated<Variant, Comma>,
     ) -> Result<Vec<EnumVariant<'a>>, syn::Error> {
+        let mut discriminant = VariantDiscriminant::default();
+        let mut previous_variant: Option<&Variant> = None;
         let sifter: utility::ResultSifter<EnumVariant<'a>> = variants
             .iter()
             .enumerate()
             .map(|(index, variant)| -> Result<EnumVariant, syn::Error> {
-                let fields = Self::collect_struct_fields(&variant.fields)?;
+                if let Some((_, expr)) = &variant.discriminant {
+                    discriminant = VariantDiscriminant {
+                        base: Some(expr),
+                        ..Default::default()
+                    };
+                } else if let Some(previous) = previous_variant {
+                    // A `#[cfg]`-gated variant only takes up a discriminant when it is compiled in
+                    match utility::cfg_predicate(&previous.attrs) {
+                        Some(predicate) => discriminant.gated_offsets.push(predicate),
+                        None => discriminant.offset += 1,
+                    }
+                }
+                previous_variant = Some(variant);
+
+                let mut fields = Self::collect_struct_fields(&variant.fields)?;
+                let attrs = FieldAttributes::parse_attributes(&variant.attrs)?;
+
+                if attrs.ignore == ReflectIgnoreBehavior::IgnoreSerialization {
+                    return Err(syn::Error::new(
+                        variant.span(),
+                        format_args!(
+                            "enum variants may only be marked `{IGNORE_ALL_ATTR}`, not `{IGNORE_SERIALIZATION_ATTR}`"
+                        ),
+                    ));
+                }
+
+                if attrs.ignore.is_ignored() {
+                    if matches!(attrs.default, DefaultBehavior::Default) || attrs.catch_all.is_some() {
+                        return Err(syn::Error::new(
+                            variant.span(),
+                            format_args!(
+                                "`{IGNORE_ALL_ATTR}` variants cannot be marked `{DEFAULT_ATTR}` or `{CATCH_ALL_ATTR}`"
+                            ),
+                        ));
+                    }
+
+                    // None of the fields of an ignored variant are reflected
+                    for field in &mut fields {
+                        field.attrs.ignore = ReflectIgnoreBehavior::IgnoreAlways;
+                        field.reflection_index = None;
+                    }
+                }
+
+                if let Some((attr, span)) = attrs.enum_field_only_span() {
+                    return Err(syn::Error::new(
+                        span,
+                        format_args!("`{attr}` may only be used on enum variant fields"),
+                    ));
+                }
+
+                if matches!(attrs.default, DefaultBehavior::TryFunc(_)) {
+                    return Err(syn::Error::new(
+                        variant.span(),
+                        format_args!(
+                            "enum variants may not be marked `#[{REFLECT_ATTRIBUTE_NAME}({TRY_DEFAULT_ATTR})]`"
+                        ),
+                    ));
+                }
+
+                // Fields without their own default are taken from the variant's default function instead
+                if let DefaultBehavior::Func(_) = &attrs.default {
+                    if attrs.ignore.is_ignored() {
+                        return Err(syn::Error::new(
+                            variant.span(),
+                            format_args!(
+                                "`{IGNORE_ALL_ATTR}` variants cannot be marked `{DEFAULT_ATTR} = \"...\"`"
+                            ),
+                        ));
+                    }
+
+                    for field in &mut fields {
+                        if matches!(field.attrs.default, DefaultBehavior::Required) {
+                            field.attrs.default = DefaultBehavior::Variant;
+                        }
+                    }
+                }
+
+                if let Some(span) = attrs.catch_all {
+                    if fields.len() != 1 || fields[0].attrs.ignore.is_ignored() {
+                        return Err(syn::Error::new(
+                            span,
+                            format_args!(
+                                "`{CATCH_ALL_ATTR}` variants must have exactly one active field to store the variant name in"
+                            ),
+                        ));
+                    }
+
+                    if utility::cfg_predicate(&variant.attrs).is_some() {
+                        return Err(syn::Error::new(
+                            span,
+                            format_args!("`{CATCH_ALL_ATTR}` variants cannot be `#[cfg]`-gated"),
+                        ));
+                    }
+                }
 
                 let fields = match variant.fields {
                     Fields::Named(..) => EnumVariantFields::Named(fields),
@@ -403,9 +1112,10 @@ impl<'a> ReflectDerive<'a> {
                 };
                 Ok(EnumVariant {
                     fields,
-                    attrs: FieldAttributes::parse_attributes(&variant.attrs)?,
+                    attrs,
                     data: variant,
                     index,
+                    discriminant: discriminant.clone(),
                     #[cfg(feature = "documentation")]
                     doc: crate::documentation::Documentation::from_attributes(&variant.attrs),
                 })
@@ -415,7 +1125,76 @@ impl<'a> ReflectDerive<'a> {
                 utility::ResultSifter::fold,
             );
 
-        sifter.finish()
+        let variants = sifter.finish()?;
+
+        // Aliases must not be shared with any other variant name or alias
+        let mut names = HashMap::new();
+        for variant in &variants {
+            names.insert(variant.data.ident.to_string(), &variant.data.ident);
+        }
+        for variant in &variants {
+            for alias in &variant.attrs.aliases {
+                if let Some(existing) = names.insert(alias.value(), &variant.data.ident) {
+                    return Err(syn::Error::new(
+                        alias.span(),
+                        format_args!(
+                            "variant alias `{}` is already used by variant `{existing}`",
+                            alias.value()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(variant) = variants
+            .iter()
+            .filter(|variant| matches!(variant.attrs.default, DefaultBehavior::Default))
+            .nth(1)
+        {
+            return Err(syn::Error::new(
+                variant.data.span(),
+                format_args!(
+                    "only one variant may be marked `#[{REFLECT_ATTRIBUTE_NAME}({DEFAULT_ATTR})]`"
+                ),
+            ));
+        }
+
+        // Unknown variant names can only be routed to a single variant
+        if let Some(span) = variants
+            .iter()
+            .filter_map(|variant| variant.attrs.catch_all)
+            .nth(1)
+        {
+            return Err(syn::Error::new(
+                span,
+                format_args!("only one variant may be marked `{CATCH_ALL_ATTR}`"),
+            ));
+        }
+
+        for variant in &variants {
+            let Some(replaced_by) = variant
+                .attrs
+                .deprecation
+                .as_ref()
+                .and_then(|deprecation| deprecation.replaced_by.as_ref())
+            else {
+                continue;
+            };
+
+            if !variants.iter().any(|other| {
+                other.data.ident == replaced_by.value() && other.attrs.ignore.is_active()
+            }) {
+                return Err(syn::Error::new(
+                    replaced_by.span(),
+                    format_args!(
+                        "replacement variant `{}` does not exist or is ignored",
+                        replaced_by.value()
+                    ),
+                ));
+            }
+        }
+
+        Ok(variants)
     }
 }
 
@@ -472,6 +1251,7 @@ impl<'a> ReflectMeta<'a> {
             where_clause_options,
             None,
             Option::<std::iter::Empty<&Type>>::None,
+            None,
         )
     }
 
@@ -485,8 +1265,8 @@ impl<'a> ReflectMeta<'a> {
 impl<'a> StructField<'a> {
     /// Generates a `TokenStream` for `NamedField` or `UnnamedField` construction.
     pub fn to_info_tokens(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
-        let name = match &self.data.ident {
-            Some(ident) => ident.to_string().to_token_stream(),
+        let name = match &self.name {
+            Some(name) => name.to_token_stream(),
             None => self.reflection_index.to_token_stream(),
         };
 
@@ -503,9 +1283,16 @@ impl<'a> StructField<'a> {
         let ty = &self.data.ty;
         let custom_attributes = self.attrs.custom_attributes.to_tokens(bevy_reflect_path);
 
+        // Boxed trait objects are described as `dyn Reflect`, since their concrete type is only known at runtime
+        let new_field_info = if self.attrs.apply_via_downcast.is_some() {
+            quote!(#field_info::new_dyn(#name))
+        } else {
+            quote!(#field_info::new::<#ty>(#name))
+        };
+
         #[allow(unused_mut)] // Needs mutability for the feature gate
         let mut info = quote! {
-            #field_info::new::<#ty>(#name).with_custom_attributes(#custom_attributes)
+            #new_field_info.with_custom_attributes(#custom_attributes)
         };
 
         #[cfg(feature = "documentation")]
@@ -541,8 +1328,10 @@ impl<'a> ReflectStruct<'a> {
         crate::registration::impl_get_type_registration(
             self.meta(),
             where_clause_options,
-            self.serialization_data(),
+            self.serialization_data()
+                .map(|data| data.as_serialization_data(self.meta().bevy_reflect_path())),
             Some(self.active_types().iter()),
+            None,
         )
     }
 
@@ -625,6 +1414,29 @@ impl<'a> ReflectStruct<'a> {
 }
 
 impl<'a> ReflectEnum<'a> {
+    fn new(
+        meta: ReflectMeta<'a>,
+        variants: Vec<EnumVariant<'a>>,
+        serialization_data: Option<EnumSerializationDataDef>,
+        repr: Option<Ident>,
+        align: Option<proc_macro2::TokenStream>,
+    ) -> Self {
+        let variant_indices = variants
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| (variant.data.ident.to_string(), index))
+            .collect();
+
+        Self {
+            meta,
+            variants,
+            variant_indices,
+            serialization_data,
+            repr,
+            align,
+        }
+    }
+
     /// Access the metadata associated with this enum definition.
     pub fn meta(&self) -> &ReflectMeta<'a> {
         &self.meta
@@ -643,20 +1455,126 @@ impl<'a> ReflectEnum<'a> {
         &self.variants
     }
 
+    /// Returns the variant with the given name, if any.
+    ///
+    /// Unlike scanning [`variants`](Self::variants), this is a constant-time lookup.
+    pub fn variant_by_name(&self, name: &str) -> Option<&EnumVariant<'a>> {
+        self.variant_indices
+            .get(name)
+            .map(|&index| &self.variants[index])
+    }
+
+    /// Returns the variant marked `#[reflect(default)]`, if any.
+    pub fn declared_default_variant(&self) -> Option<&EnumVariant<'a>> {
+        self.variants
+            .iter()
+            .find(|variant| matches!(variant.attrs.default, DefaultBehavior::Default))
+    }
+
+    /// Returns the variant marked `#[reflect(catch_all)]`, if any.
+    pub fn catch_all_variant(&self) -> Option<&EnumVariant<'a>> {
+        self.variants
+            .iter()
+            .find(|variant| variant.attrs.catch_all.is_some())
+    }
+
+    /// Get an iterator of variants which are exposed to the reflection API
+    pub fn active_variants(&self) -> impl Iterator<Item = &EnumVariant<'a>> {
+        self.variants
+            .iter()
+            .filter(|variant| variant.attrs.ignore.is_active())
+    }
+
+    /// Get an iterator of active variants which may be constructed by `FromReflect`,
+    /// which excludes any not listed in `#[reflect(from_reflect(only = "..."))]`.
+    pub fn constructible_variants(&self) -> impl Iterator<Item = &EnumVariant<'a>> {
+        self.active_variants().filter(|variant| {
+            self.meta
+                .from_reflect()
+                .includes_variant(&variant.data.ident)
+        })
+    }
+
+    /// Get an iterator of variants which are ignored by the reflection API
+    pub fn ignored_variants(&self) -> impl Iterator<Item = &EnumVariant<'a>> {
+        self.variants
+            .iter()
+            .filter(|variant| variant.attrs.ignore.is_ignored())
+    }
+
     /// Get a collection of types which are exposed to the reflection API
+    ///
+    /// Fields marked `#[reflect(apply_via = "downcast")]` are skipped,
+    /// since they are reflected through the concrete type behind the box.
     pub fn active_types(&self) -> Vec<Type> {
-        self.active_fields()
+        self.statically_typed_fields()
             .map(|field| field.data.ty.clone())
             .collect()
     }
 
+    /// Get an iterator of active fields whose type is known statically,
+    /// which excludes fields marked `#[reflect(apply_via = "downcast")]`.
+    fn statically_typed_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
+        self.active_fields()
+            .filter(|field| field.attrs.apply_via_downcast.is_none())
+    }
+
+    /// The integer type given by the enum's `#[repr(...)]` attribute, if any.
+    pub fn repr(&self) -> Option<&Ident> {
+        self.repr.as_ref()
+    }
+
+    /// The alignment given by the enum's `#[repr(align(N))]` attribute, if any.
+    pub fn align(&self) -> Option<&proc_macro2::TokenStream> {
+        self.align.as_ref()
+    }
+
+    /// Returns the integer type of this enum's discriminants.
+    ///
+    /// This is the type given by its `#[repr(...)]` attribute,
+    /// or `i64` if there is none, which holds any `isize` discriminant.
+    pub fn discriminant_ty(&self) -> proc_macro2::TokenStream {
+        match &self.repr {
+            Some(repr) => quote!(#repr),
+            None => quote!(i64),
+        }
+    }
+
+    /// Returns true if any variant of this enum has a field ignored by the reflection API.
+    pub fn has_ignored_fields(&self) -> bool {
+        self.variants
+            .iter()
+            .flat_map(|variant| variant.fields())
+            .any(|field| field.attrs.ignore.is_ignored())
+    }
+
     /// Get an iterator of fields which are exposed to the reflection API
+    ///
+    /// Fields of `#[cfg]`-gated variants are skipped, since their types may not exist
+    /// when the variant is compiled out.
     pub fn active_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
         self.variants
             .iter()
+            .filter(|variant| variant.cfg_attrs().next().is_none())
             .flat_map(|variant| variant.active_fields())
     }
 
+    /// Returns an expression evaluating to the index of the variant at the given declaration index,
+    /// accounting for any preceding `#[cfg]`-gated variants that may be compiled out.
+    pub fn variant_index_tokens(&self, index: usize) -> proc_macro2::TokenStream {
+        let gated = self.variants[..index]
+            .iter()
+            .filter_map(|variant| utility::cfg_predicate(&variant.data.attrs))
+            .collect::<Vec<_>>();
+
+        if gated.is_empty() {
+            quote!(#index)
+        } else {
+            let ungated = index - gated.len();
+            quote!((#ungated #(+ (#gated as usize))*))
+        }
+    }
+
     pub fn where_clause_options(&self) -> WhereClauseOptions {
         WhereClauseOptions::new_with_fields(self.meta(), self.active_types().into_boxed_slice())
     }
@@ -671,19 +1589,104 @@ impl<'a> ReflectEnum<'a> {
         crate::registration::impl_get_type_registration(
             self.meta(),
             where_clause_options,
-            None,
-            Some(self.active_fields().map(|field| &field.data.ty)),
+            self.serialization_data
+                .as_ref()
+                .map(|data| data.as_serialization_data(self.meta().bevy_reflect_path())),
+            Some(self.statically_typed_fields().map(|field| &field.data.ty)),
+            self.default_variant_registration(),
         )
     }
 
+    /// Returns the registration of `ReflectDefault` for the variant marked `#[reflect(default)]`, if any.
+    ///
+    /// In debug builds, this also asserts that the type's `Default` impl constructs that variant.
+    fn default_variant_registration(&self) -> Option<proc_macro2::TokenStream> {
+        let variant = self.declared_default_variant()?;
+        let bevy_reflect_path = self.meta().bevy_reflect_path();
+        let variant_name = variant.data.ident.to_string();
+
+        // Types with `#[reflect(Default)]` already register it along with the other type data
+        if self.meta().attrs().contains(REFLECT_DEFAULT) {
+            return Some(self.default_variant_assertion(&variant_name));
+        }
+
+        // Variants whose fields have their own default functions are built from those,
+        // so that fields without a `Default` impl do not require one for the whole enum
+        if let Some(constructor) = self.default_variant_constructor(variant) {
+            let message = format!(
+                "a default function of the `#[reflect(default)]` variant `{variant_name}` failed"
+            );
+            return Some(quote! {
+                registration.insert::<#bevy_reflect_path::std_traits::ReflectDefault>(
+                    #bevy_reflect_path::__macro_exports::reflect_default_from_fn(|| {
+                        let __variant = (|| -> #FQOption<Self> { #FQOption::Some(#constructor) })();
+                        #FQBox::new(#FQOption::expect(__variant, #message))
+                    }),
+                );
+            });
+        }
+
+        let assertion = self.default_variant_assertion(&variant_name);
+        Some(quote! {
+            #assertion
+            registration.insert::<#bevy_reflect_path::std_traits::ReflectDefault>(#bevy_reflect_path::FromType::<Self>::from_type());
+        })
+    }
+
+    /// Returns the constructor of the given `#[reflect(default)]` variant built from its field defaults,
+    /// if any of its fields has a `#[reflect(default = "...")]` or `#[reflect(try_default = "...")]` function.
+    ///
+    /// The constructor evaluates to `Self` and may return `None` early if a `try_default` function fails.
+    fn default_variant_constructor(
+        &self,
+        variant: &EnumVariant,
+    ) -> Option<proc_macro2::TokenStream> {
+        let has_default_functions = variant.fields().iter().any(|field| {
+            matches!(
+                field.attrs.default,
+                DefaultBehavior::Func(_) | DefaultBehavior::TryFunc(_)
+            )
+        });
+        if !has_default_functions {
+            return None;
+        }
+
+        let index = self
+            .active_variants()
+            .position(|other| other.index == variant.index)?;
+        let EnumVariantOutputData {
+            mut variant_constructors,
+            ..
+        } = DefaultVariantBuilder::new(self).build(&Ident::new("__unused", Span::call_site()));
+        Some(variant_constructors.swap_remove(index))
+    }
+
+    /// Returns a debug assertion that the type's `Default` impl constructs the `#[reflect(default)]` variant.
+    fn default_variant_assertion(&self, variant_name: &str) -> proc_macro2::TokenStream {
+        let bevy_reflect_path = self.meta().bevy_reflect_path();
+        quote! {
+            #[cfg(debug_assertions)]
+            {
+                let default = <Self as ::core::default::Default>::default();
+                ::core::debug_assert_eq!(
+                    #bevy_reflect_path::Enum::variant_name(&default),
+                    #variant_name,
+                    "the `Default` impl of `{}` does not construct its `#[reflect(default)]` variant",
+                    <Self as #bevy_reflect_path::TypePath>::type_path(),
+                );
+            }
+        }
+    }
+
     /// Generates a `TokenStream` for `TypeInfo::Enum` construction.
     pub fn to_info_tokens(&self) -> proc_macro2::TokenStream {
         let bevy_reflect_path = self.meta().bevy_reflect_path();
 
-        let variants = self
-            .variants
-            .iter()
-            .map(|variant| variant.to_info_tokens(bevy_reflect_path));
+        let variants = self.variants.iter().map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let info = variant.to_info_tokens(bevy_reflect_path);
+            quote!(#(#cfg_attrs)* #info)
+        });
 
         let custom_attributes = self
             .meta
@@ -714,6 +1717,16 @@ impl<'a> ReflectEnum<'a> {
 }
 
 impl<'a> EnumVariant<'a> {
+    /// The `#[cfg(...)]` attributes on this variant.
+    ///
+    /// These should be applied to any generated code that refers to the variant.
+    pub fn cfg_attrs(&self) -> impl Iterator<Item = &'a Attribute> {
+        self.data
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+    }
+
     /// Get an iterator of fields which are exposed to the reflection API
     pub fn active_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
         self.fields()
@@ -722,7 +1735,6 @@ impl<'a> EnumVariant<'a> {
     }
 
     /// The complete set of fields in this variant.
-    #[allow(dead_code)]
     pub fn fields(&self) -> &[StructField<'a>] {
         match &self.fields {
             EnumVariantFields::Named(fields) | EnumVariantFields::Unnamed(fields) => fields,
@@ -762,12 +1774,50 @@ impl<'a> EnumVariant<'a> {
 
         let custom_attributes = self.attrs.custom_attributes.to_tokens(bevy_reflect_path);
 
-        #[allow(unused_mut)] // Needs mutability for the feature gate
         let mut info = quote! {
             #bevy_reflect_path::#info_struct::new(#args)
                 .with_custom_attributes(#custom_attributes)
         };
 
+        if let Some(deprecation) = &self.attrs.deprecation {
+            let since = deprecation.since.iter();
+            let replaced_by = deprecation.replaced_by.iter();
+            let note = deprecation.note.iter();
+            info.extend(quote! {
+                .with_deprecation(
+                    #bevy_reflect_path::VariantDeprecation::new()
+                        #(.with_since(#since))*
+                        #(.with_replaced_by(#replaced_by))*
+                        #(.with_note(#note))*
+                )
+            });
+        }
+
+        if !self.attrs.aliases.is_empty() {
+            let aliases = &self.attrs.aliases;
+            info.extend(quote! {
+                .with_aliases(&[#(#aliases),*])
+            });
+        }
+
+        if self.attrs.hidden.is_some() {
+            info.extend(quote! {
+                .with_hidden(true)
+            });
+        }
+
+        let required_fields = self
+            .active_fields()
+            .enumerate()
+            .filter(|(_, field)| matches!(field.attrs.default, DefaultBehavior::Required))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        if !required_fields.is_empty() {
+            info.extend(quote! {
+                .with_required_fields(&[#(#required_fields),*])
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
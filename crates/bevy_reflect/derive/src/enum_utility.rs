@@ -1,15 +1,24 @@
-use crate::derive_data::StructField;
-use crate::field_attributes::DefaultBehavior;
-use crate::{derive_data::ReflectEnum, utility::ident_or_index};
-use bevy_macro_utils::fq_std::{FQDefault, FQOption};
-use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote};
+use crate::derive_data::{EnumVariant, EnumVariantFields, StructField};
+use crate::field_attributes::{DefaultBehavior, ReflectIgnoreBehavior};
+use crate::{
+    derive_data::ReflectEnum,
+    utility::{self, call_default_func, ident_or_index},
+};
+use bevy_macro_utils::fq_std::{FQDefault, FQOption, FQResult};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+use syn::LitStr;
 
 pub(crate) struct EnumVariantOutputData {
-    /// The names of each variant as a string.
+    /// The `#[cfg(...)]` attributes of each variant.
     ///
-    /// For example, `Some` and `None` for the `Option` enum.
-    pub variant_names: Vec<String>,
+    /// These must be placed on every match arm or statement generated for the variant.
+    pub variant_cfgs: Vec<TokenStream>,
+    /// The match pattern for each variant's name, including any aliases.
+    ///
+    /// For example, `"Circle" | "Round"` for a `Circle` variant marked `#[reflect(alias = "Round")]`.
+    pub variant_patterns: Vec<TokenStream>,
     /// The constructor portion of each variant.
     ///
     /// For example, `Option::Some { 0: value }` and `Option::None {}` for the `Option` enum.
@@ -21,6 +30,7 @@ pub(crate) struct VariantField<'a, 'b> {
     /// The alias for the field.
     ///
     /// This should be used whenever the field needs to be referenced in a token stream.
+    /// See [`field_alias`] for how it is named.
     pub alias: &'a Ident,
     /// The name of the variant that contains the field.
     pub variant_name: &'a str,
@@ -28,6 +38,225 @@ pub(crate) struct VariantField<'a, 'b> {
     pub field: &'a StructField<'b>,
 }
 
+/// Returns the path to the function with the given name in a field's `#[reflect(with = "...")]` module.
+///
+/// The path is spanned to the attribute, so that a missing function is reported there by name.
+pub(crate) fn with_function(module: &syn::Path, name: &str) -> TokenStream {
+    let function = Ident::new(name, module.span());
+    quote_spanned!(module.span()=> #module::#function)
+}
+
+/// Returns a token stream that constructs the enum's `#[reflect(catch_all)]` variant, if any,
+/// storing the unknown variant `name` in its only field.
+pub(crate) fn build_catch_all(reflect_enum: &ReflectEnum, name: &Ident) -> Option<TokenStream> {
+    let variant = reflect_enum.catch_all_variant()?;
+    let variant_path = reflect_enum.get_unit(&variant.data.ident);
+    let field = &variant.fields()[0];
+    let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+
+    Some(quote! {
+        #variant_path {
+            #member: ::core::convert::Into::into(#name)
+        }
+    })
+}
+
+/// Returns a match arm for each variant marked `#[reflect(ignore)]`, matching its name
+/// or any of its aliases and evaluating to the result of `on_ignored` for that variant name.
+///
+/// These variants cannot be constructed, but their names must not reach the catch-all variant either.
+pub(crate) fn build_ignored_variant_arms(
+    reflect_enum: &ReflectEnum,
+    on_ignored: impl Fn(&str) -> TokenStream,
+) -> Vec<TokenStream> {
+    reflect_enum
+        .ignored_variants()
+        .map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let pattern = variant_pattern(variant);
+            let result = on_ignored(&variant.data.ident.to_string());
+            quote!(#(#cfg_attrs)* #pattern => #result,)
+        })
+        .collect()
+}
+
+/// Builds a match arm for each active variant excluded by `#[reflect(from_reflect(only = "..."))]`,
+/// which evaluates to the result of `on_excluded` for that variant name instead of constructing the variant.
+pub(crate) fn build_excluded_variant_arms(
+    reflect_enum: &ReflectEnum,
+    on_excluded: impl Fn(&str) -> TokenStream,
+) -> Vec<TokenStream> {
+    reflect_enum
+        .active_variants()
+        .filter(|variant| {
+            !reflect_enum
+                .meta()
+                .from_reflect()
+                .includes_variant(&variant.data.ident)
+        })
+        .map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let pattern = variant_pattern(variant);
+            let result = on_excluded(&variant.data.ident.to_string());
+            quote!(#(#cfg_attrs)* #pattern => #result,)
+        })
+        .collect()
+}
+
+/// Returns the local holding the value of the given field while its variant is built.
+///
+/// It is named after the field's declaration index, such as `_field_0`, so that it is unique within its variant.
+/// It also resolves at the macro's definition site, so it can never shadow, or be shadowed by,
+/// the user's field names or the call-site locals of the generated code, such as the `this` parameter.
+fn field_alias(field: &StructField) -> Ident {
+    format_ident!(
+        "_field_{}",
+        field.declaration_index,
+        span = Span::mixed_site()
+    )
+}
+
+/// Returns the pattern matching the name of the given variant or any of its aliases.
+fn variant_pattern(variant: &EnumVariant) -> TokenStream {
+    let name = variant.data.ident.to_string();
+    let aliases = &variant.attrs.aliases;
+    quote!(#name #(| #aliases)*)
+}
+
+/// Returns an expression evaluating to the variant name of the enum `this`, to be matched against.
+///
+/// For types marked `#[reflect(from_reflect(case_insensitive))]`, a name that does not exactly match
+/// any variant or alias is replaced by the first one that matches it ASCII case-insensitively, if any.
+pub(crate) fn build_variant_name_lookup(reflect_enum: &ReflectEnum, this: &Ident) -> TokenStream {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let variant_name = quote!(#bevy_reflect_path::Enum::variant_name(#this));
+    if !reflect_enum
+        .meta()
+        .from_reflect()
+        .should_match_case_insensitive()
+        || reflect_enum.variants().is_empty()
+    {
+        return variant_name;
+    }
+
+    let known_names = reflect_enum
+        .variants()
+        .iter()
+        .flat_map(|variant| {
+            std::iter::once(variant.data.ident.to_string())
+                .chain(variant.attrs.aliases.iter().map(LitStr::value))
+        })
+        .collect::<Vec<_>>();
+
+    quote! {{
+        let __variant_name = #variant_name;
+        match __variant_name {
+            // Exact matches are always preferred
+            #(#known_names)|* => __variant_name,
+            _ => #FQOption::unwrap_or(
+                ::core::iter::Iterator::find(
+                    &mut ::core::iter::IntoIterator::into_iter([#(#known_names),*]),
+                    |__known| ::core::primitive::str::eq_ignore_ascii_case(__known, __variant_name),
+                ),
+                __variant_name,
+            ),
+        }
+    }}
+}
+
+/// Returns an expression evaluating to the reflected variant name closest to `name`, if any,
+/// to be suggested by an `ApplyError::UnknownVariant`.
+///
+/// Ignored and hidden variants are never suggested.
+pub(crate) fn build_variant_suggestion(reflect_enum: &ReflectEnum, name: &Ident) -> TokenStream {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let variant_names = reflect_enum
+        .active_variants()
+        .filter(|variant| variant.attrs.hidden.is_none())
+        .map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let variant_name = variant.data.ident.to_string();
+            quote!(#(#cfg_attrs)* #variant_name)
+        });
+
+    quote!(#bevy_reflect_path::__macro_exports::closest_variant_name(#name, &[#(#variant_names),*]))
+}
+
+/// Surrounds the constructor of the given variant with the builder's
+/// [`before_variant`](VariantBuilder::before_variant) and [`after_variant`](VariantBuilder::after_variant) hooks.
+///
+/// The variant's `#[reflect(default = "...")]` function, if any, is called before either hook,
+/// so that its fields are available to the constructor.
+///
+/// The constructor is returned as-is if neither hook emits anything and the variant has no default function.
+fn with_variant_hooks(
+    builder: &impl VariantBuilder,
+    this: &Ident,
+    variant: &EnumVariant,
+    constructor: TokenStream,
+) -> TokenStream {
+    let variant_default = build_variant_default(builder, variant);
+    let before = builder.before_variant(this, variant);
+    let after = builder.after_variant(variant);
+    if variant_default.is_empty() && before.is_empty() && after.is_empty() {
+        return constructor;
+    }
+
+    quote! {{
+        #variant_default
+        #before
+        let __variant = #constructor;
+        #after
+        __variant
+    }}
+}
+
+/// Returns the identifier bound to a field's value from its variant's default function.
+fn variant_default_alias(field: &StructField) -> Ident {
+    format_ident!("__variant_default_{}", field.declaration_index)
+}
+
+/// Returns a token stream that calls the variant's `#[reflect(default = "...")]` function once
+/// and binds each field taking its value from it (see [`DefaultBehavior::Variant`]).
+///
+/// If the function constructs a different variant, the builder's
+/// [`on_variant_default_mismatch`](VariantBuilder::on_variant_default_mismatch) result is returned instead.
+fn build_variant_default(builder: &impl VariantBuilder, variant: &EnumVariant) -> TokenStream {
+    let reflect_enum = builder.reflect_enum();
+    let DefaultBehavior::Func(func) = &variant.attrs.default else {
+        return TokenStream::new();
+    };
+
+    let fields = variant
+        .fields()
+        .iter()
+        .filter(|field| matches!(field.attrs.default, DefaultBehavior::Variant))
+        .collect::<Vec<_>>();
+    if fields.is_empty() {
+        return TokenStream::new();
+    }
+
+    let members = fields
+        .iter()
+        .map(|field| ident_or_index(field.data.ident.as_ref(), field.declaration_index));
+    let aliases = fields
+        .iter()
+        .map(|field| variant_default_alias(field))
+        .collect::<Vec<_>>();
+    let variant_ident = &variant.data.ident;
+    let variant_path = reflect_enum.get_unit(variant_ident);
+    let default_value = call_default_func(func, quote!(Self));
+    let mismatch = builder.on_variant_default_mismatch(variant);
+
+    quote! {
+        let (#(#aliases,)*) = match #default_value {
+            #variant_path { #(#members: #aliases,)* .. } => (#(#aliases,)*),
+            #[allow(unreachable_patterns)]
+            _ => #mismatch,
+        };
+    }
+}
+
 /// Trait used to control how enum variants are built.
 pub(crate) trait VariantBuilder: Sized {
     /// Returns the enum data.
@@ -38,18 +267,38 @@ pub(crate) trait VariantBuilder: Sized {
     /// The default implementation of this method will return a token stream
     /// which gets the field dynamically so as to support `dyn Enum`.
     ///
+    /// Unnamed fields are accessed by their _reflection_ index, which skips ignored fields,
+    /// whereas the generated constructor assigns them by their _declaration_ index.
+    /// Unnamed fields are first looked up by a name matching that index, such as `"0"`,
+    /// so a tuple variant can also be built from its struct-like representation.
+    /// Named fields marked `#[reflect(from = "...")]` fall back to their previous name.
+    ///
     /// # Parameters
     /// * `this`: The identifier of the enum
     /// * `field`: The field to access
     fn access_field(&self, this: &Ident, field: VariantField) -> TokenStream {
-        match &field.field.data.ident {
-            Some(field_ident) => {
-                let name = field_ident.to_string();
-                quote!(#this.field(#name))
+        match &field.field.name {
+            Some(name) => {
+                match &field.field.attrs.from {
+                    // Data written before the field was renamed only has it under its previous name
+                    Some(previous_name) => {
+                        quote!(#FQOption::or_else(#this.field(#name), || #this.field(#previous_name)))
+                    }
+                    None => quote!(#this.field(#name)),
+                }
             }
             None => {
                 if let Some(field_index) = field.field.reflection_index {
-                    quote!(#this.field_at(#field_index))
+                    // Tuple variants may also be given as struct variants with fields named "0", "1", ...,
+                    // whose order is not guaranteed, so those are only looked up by name
+                    let bevy_reflect_path = self.reflect_enum().meta().bevy_reflect_path();
+                    let name = field_index.to_string();
+                    quote! {
+                        match #this.variant_type() {
+                            #bevy_reflect_path::VariantType::Struct => #this.field(#name),
+                            _ => #this.field_at(#field_index),
+                        }
+                    }
                 } else {
                     quote!(::core::compile_error!(
                         "internal bevy_reflect error: field should be active"
@@ -59,13 +308,45 @@ pub(crate) trait VariantBuilder: Sized {
         }
     }
 
+    /// Returns the name used to refer to a field in error messages.
+    ///
+    /// Tuple fields are referred to by their reflection index, such as `.0`,
+    /// since that is the index used to access them through the reflection API.
+    /// All implementors should use this, so that errors name fields consistently.
+    ///
+    /// # Parameters
+    /// * `field`: The field to name
+    fn field_error_context(&self, field: VariantField) -> String {
+        match &field.field.name {
+            Some(name) => name.clone(),
+            None => format!(
+                ".{}",
+                field
+                    .field
+                    .reflection_index
+                    .unwrap_or(field.field.declaration_index)
+            ),
+        }
+    }
+
     /// Returns a token stream that unwraps a field of a variant as a `&dyn Reflect`
     /// (from an `Option<dyn Reflect>`).
     ///
+    /// The `Option` only describes the _presence_ of the field.
+    /// A field that is present but holds a `None` value (e.g. an `Option<T>` field)
+    /// is unwrapped like any other value and must not be treated as missing.
+    ///
     /// # Parameters
     /// * `field`: The field to access
     fn unwrap_field(&self, field: VariantField) -> TokenStream;
 
+    /// Returns a token stream that unwraps the `Result` returned by a field's
+    /// `#[reflect(try_default = "...")]` function into the field's concrete type.
+    ///
+    /// # Parameters
+    /// * `field`: The field to access
+    fn unwrap_default(&self, field: VariantField) -> TokenStream;
+
     /// Returns a token stream that constructs a field of a variant as a concrete type
     /// (from a `&dyn Reflect`).
     ///
@@ -73,6 +354,13 @@ pub(crate) trait VariantBuilder: Sized {
     /// * `field`: The field to access
     fn construct_field(&self, field: VariantField) -> TokenStream;
 
+    /// Returns a token stream that returns early from the generated function
+    /// when the variant's `#[reflect(default = "...")]` function constructed a different variant.
+    ///
+    /// # Parameters
+    /// * `variant`: The variant whose default function returned a different variant
+    fn on_variant_default_mismatch(&self, variant: &EnumVariant) -> TokenStream;
+
     /// Returns a token stream that constructs an instance of an active field.
     ///
     /// # Parameters
@@ -84,14 +372,29 @@ pub(crate) trait VariantBuilder: Sized {
         let alias = field.alias;
         let field_constructor = self.construct_field(field);
 
-        match &field.field.attrs.default {
-            DefaultBehavior::Func(path) => quote! {
-                if let #FQOption::Some(#alias) = #field_accessor {
-                    #field_constructor
-                } else {
-                    #path()
+        // Fields skipped during serialization are missing after deserialization, so they must fall back to a default
+        let default_behavior = match &field.field.attrs.default {
+            DefaultBehavior::Required
+                if field.field.attrs.ignore == ReflectIgnoreBehavior::IgnoreSerialization =>
+            {
+                &DefaultBehavior::Default
+            }
+            default_behavior => default_behavior,
+        };
+
+        let field_ty = &field.field.data.ty;
+        match default_behavior {
+            DefaultBehavior::Func(path) => {
+                let default_value = call_default_func(path, field_ty);
+
+                quote! {
+                    if let #FQOption::Some(#alias) = #field_accessor {
+                        #field_constructor
+                    } else {
+                        #default_value
+                    }
                 }
-            },
+            }
             DefaultBehavior::Default => quote! {
                 if let #FQOption::Some(#alias) = #field_accessor {
                     #field_constructor
@@ -99,6 +402,30 @@ pub(crate) trait VariantBuilder: Sized {
                     #FQDefault::default()
                 }
             },
+            DefaultBehavior::TryFunc(path) => {
+                let default_value = call_default_func(path, quote!(#FQResult<#field_ty, _>));
+                let default_unwrapper = self.unwrap_default(field);
+
+                quote! {
+                    if let #FQOption::Some(#alias) = #field_accessor {
+                        #field_constructor
+                    } else {
+                        let #alias = #default_value;
+                        #default_unwrapper
+                    }
+                }
+            }
+            DefaultBehavior::Variant => {
+                let default_value = variant_default_alias(field.field);
+
+                quote! {
+                    if let #FQOption::Some(#alias) = #field_accessor {
+                        #field_constructor
+                    } else {
+                        #default_value
+                    }
+                }
+            }
             DefaultBehavior::Required => {
                 let field_unwrapper = self.unwrap_field(field);
 
@@ -118,19 +445,70 @@ pub(crate) trait VariantBuilder: Sized {
     /// * `field`: The field to access
     fn on_ignored_field(&self, field: VariantField) -> TokenStream {
         match &field.field.attrs.default {
-            DefaultBehavior::Func(path) => quote! { #path() },
+            DefaultBehavior::Func(path) => call_default_func(path, &field.field.data.ty),
+            DefaultBehavior::Variant => variant_default_alias(field.field).into_token_stream(),
             _ => quote! { #FQDefault::default() },
         }
     }
 
+    /// Returns a token stream that runs before the given variant is constructed.
+    ///
+    /// This is useful for instrumenting each generated match arm, such as with logging or timing.
+    /// The default implementation emits nothing.
+    ///
+    /// # Parameters
+    /// * `this`: The identifier of the enum
+    /// * `variant`: The variant about to be constructed
+    fn before_variant(&self, _this: &Ident, _variant: &EnumVariant) -> TokenStream {
+        TokenStream::new()
+    }
+
+    /// Returns a token stream that runs after the given variant has been constructed,
+    /// right before it is produced by its match arm.
+    ///
+    /// It is skipped if constructing the variant returned early, such as from a failing field.
+    /// The default implementation emits nothing.
+    ///
+    /// # Parameters
+    /// * `variant`: The variant that was constructed
+    fn after_variant(&self, _variant: &EnumVariant) -> TokenStream {
+        TokenStream::new()
+    }
+
+    /// Returns true if output should be built for the given active variant.
+    ///
+    /// The default implementation includes every active variant.
+    ///
+    /// # Parameters
+    /// * `variant`: The variant to check
+    fn includes_variant(&self, _variant: &EnumVariant) -> bool {
+        true
+    }
+
     /// Builds the enum variant output data.
+    ///
+    /// Each constructor lists its fields in declaration order, which is also the order
+    /// Rust evaluates them in, so the first failing field in declaration order is the one reported.
+    /// Ignored fields are interleaved with active ones rather than evaluated separately,
+    /// so any functions the fields call run in declaration order too.
+    /// Builders overriding this method must keep that order, since it is documented to users.
+    ///
+    /// Variants marked `#[reflect(ignore)]` are skipped, so the output only covers
+    /// [active variants](ReflectEnum::active_variants), and only those
+    /// the builder [includes](Self::includes_variant).
     fn build(&self, this: &Ident) -> EnumVariantOutputData {
         let variants = self.reflect_enum().variants();
+        let has_ignored_fields = self.reflect_enum().has_ignored_fields();
 
-        let mut variant_names = Vec::with_capacity(variants.len());
+        let mut variant_cfgs = Vec::with_capacity(variants.len());
+        let mut variant_patterns = Vec::with_capacity(variants.len());
         let mut variant_constructors = Vec::with_capacity(variants.len());
 
-        for variant in variants {
+        for variant in self
+            .reflect_enum()
+            .active_variants()
+            .filter(|variant| self.includes_variant(variant))
+        {
             let variant_ident = &variant.data.ident;
             let variant_name = variant_ident.to_string();
             let variant_path = self.reflect_enum().get_unit(variant_ident);
@@ -139,7 +517,7 @@ pub(crate) trait VariantBuilder: Sized {
 
             let field_constructors = fields.iter().map(|field| {
                 let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
-                let alias = format_ident!("_{}", member);
+                let alias = field_alias(field);
 
                 let variant_field = VariantField {
                     alias: &alias,
@@ -147,7 +525,7 @@ pub(crate) trait VariantBuilder: Sized {
                     field,
                 };
 
-                let value = if field.attrs.ignore.is_ignored() {
+                let value = if has_ignored_fields && field.attrs.ignore.is_ignored() {
                     self.on_ignored_field(variant_field)
                 } else {
                     self.on_active_field(this, variant_field)
@@ -166,12 +544,15 @@ pub(crate) trait VariantBuilder: Sized {
                 }
             };
 
-            variant_names.push(variant_name);
-            variant_constructors.push(constructor);
+            let cfg_attrs = variant.cfg_attrs();
+            variant_cfgs.push(quote!(#(#cfg_attrs)*));
+            variant_patterns.push(variant_pattern(variant));
+            variant_constructors.push(with_variant_hooks(self, this, variant, constructor));
         }
 
         EnumVariantOutputData {
-            variant_names,
+            variant_cfgs,
+            variant_patterns,
             variant_constructors,
         }
     }
@@ -180,87 +561,918 @@ pub(crate) trait VariantBuilder: Sized {
 /// Generates the enum variant output data needed to build the `FromReflect::from_reflect` implementation.
 pub(crate) struct FromReflectVariantBuilder<'a> {
     reflect_enum: &'a ReflectEnum<'a>,
+    context: Option<&'a Ident>,
 }
 
 impl<'a> FromReflectVariantBuilder<'a> {
     pub fn new(reflect_enum: &'a ReflectEnum) -> Self {
-        Self { reflect_enum }
+        Self {
+            reflect_enum,
+            context: None,
+        }
+    }
+
+    /// Creates a builder for `from_reflect_with_context`, which passes the `context` variable
+    /// to the `from_reflect_with_context` function of fields marked `#[reflect(with = "...")]`.
+    pub fn with_context(reflect_enum: &'a ReflectEnum, context: &'a Ident) -> Self {
+        Self {
+            reflect_enum,
+            context: Some(context),
+        }
     }
 }
 
 impl<'a> VariantBuilder for FromReflectVariantBuilder<'a> {
-    fn reflect_enum(&self) -> &ReflectEnum {
+    fn reflect_enum(&self) -> &ReflectEnum<'_> {
         self.reflect_enum
     }
 
+    fn before_variant(&self, _this: &Ident, variant: &EnumVariant) -> TokenStream {
+        build_deprecation_warning(self.reflect_enum, variant)
+    }
+
+    fn includes_variant(&self, variant: &EnumVariant) -> bool {
+        self.reflect_enum
+            .meta()
+            .from_reflect()
+            .includes_variant(&variant.data.ident)
+    }
+
     fn unwrap_field(&self, field: VariantField) -> TokenStream {
         let alias = field.alias;
         quote!(#alias?)
     }
 
+    fn unwrap_default(&self, field: VariantField) -> TokenStream {
+        let alias = field.alias;
+        quote!(#FQResult::ok(#alias)?)
+    }
+
+    fn on_variant_default_mismatch(&self, _variant: &EnumVariant) -> TokenStream {
+        quote!(return #FQOption::None)
+    }
+
     fn construct_field(&self, field: VariantField) -> TokenStream {
         let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
         let field_ty = &field.field.data.ty;
         let alias = field.alias;
 
-        quote! {
-            <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#alias)?
+        let value = match &field.field.attrs.with {
+            Some(module) => match self.context {
+                Some(context) => {
+                    let from_reflect = with_function(module, "from_reflect_with_context");
+                    quote!(#from_reflect(#alias, #context))
+                }
+                None => {
+                    let from_reflect = with_function(module, "from_reflect");
+                    quote!(#from_reflect(#alias))
+                }
+            },
+            // The concrete type behind a boxed trait object can only be looked up in a registry from the context
+            None if field.field.attrs.apply_via_downcast.is_some() => match self.context {
+                Some(context) => {
+                    let registry = quote! {
+                        ::core::convert::AsRef::<#bevy_reflect_path::TypeRegistry>::as_ref(#context)
+                    };
+                    let boxed = quote!(#bevy_reflect_path::__macro_exports::from_reflect_boxed(#alias, #registry));
+                    match utility::reflect_trait_data_path(field_ty) {
+                        Some(data) => quote! {
+                            #FQOption::and_then(#boxed, |(registration, value)| {
+                                #FQResult::ok(#data::get_boxed(
+                                    #bevy_reflect_path::TypeRegistration::data::<#data>(registration)?,
+                                    value,
+                                ))
+                            })
+                        },
+                        None => quote!(#FQOption::map(#boxed, |(_, value)| value)),
+                    }
+                }
+                None => quote!(#FQOption::None::<#field_ty>),
+            },
+            // Span the call to the field type so an unsatisfied `FromReflect` bound points at the field
+            None => quote_spanned! {field_ty.span()=>
+                <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#alias)
+            },
+        };
+
+        if !self.reflect_enum.meta().attrs().debug_from_reflect() {
+            return quote!(#value?);
         }
+
+        let variant_name = field.variant_name;
+        let field_name = self.field_error_context(field);
+
+        quote! {{
+            let #alias = #value;
+            if #FQOption::is_none(&#alias) {
+                #bevy_reflect_path::__macro_exports::from_reflect_field_failed(
+                    <Self as #bevy_reflect_path::TypePath>::type_path(),
+                    #variant_name,
+                    #field_name,
+                );
+            }
+            #alias?
+        }}
     }
 }
 
 /// Generates the enum variant output data needed to build the `Reflect::try_apply` implementation.
 pub(crate) struct TryApplyVariantBuilder<'a> {
     reflect_enum: &'a ReflectEnum<'a>,
+    for_construction: bool,
 }
 
 impl<'a> TryApplyVariantBuilder<'a> {
     pub fn new(reflect_enum: &'a ReflectEnum) -> Self {
-        Self { reflect_enum }
+        Self {
+            reflect_enum,
+            for_construction: false,
+        }
+    }
+
+    /// Creates a builder for constructing a new value, such as for `TryFrom<&dyn Reflect>`,
+    /// which skips the variants excluded by `#[reflect(from_reflect(only = "..."))]`.
+    pub fn for_construction(reflect_enum: &'a ReflectEnum) -> Self {
+        Self {
+            reflect_enum,
+            for_construction: true,
+        }
     }
 }
 
 impl<'a> VariantBuilder for TryApplyVariantBuilder<'a> {
-    fn reflect_enum(&self) -> &ReflectEnum {
+    fn reflect_enum(&self) -> &ReflectEnum<'_> {
         self.reflect_enum
     }
 
+    fn before_variant(&self, this: &Ident, variant: &EnumVariant) -> TokenStream {
+        let deprecation_warning = build_deprecation_warning(self.reflect_enum, variant);
+        let missing_fields_check = build_missing_fields_check(self, this, variant);
+        quote! {
+            #deprecation_warning
+            #missing_fields_check
+        }
+    }
+
+    fn includes_variant(&self, variant: &EnumVariant) -> bool {
+        !self.for_construction
+            || self
+                .reflect_enum
+                .meta()
+                .from_reflect()
+                .includes_variant(&variant.data.ident)
+    }
+
     fn unwrap_field(&self, field: VariantField) -> TokenStream {
+        let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
+        let cold_path = cold_path(self.reflect_enum);
+        let field_name = self.field_error_context(field);
         let VariantField {
             alias,
             variant_name,
-            field,
             ..
         } = field;
 
+        quote! {
+            #alias.ok_or_else(|| {
+                #cold_path
+                #bevy_reflect_path::ApplyError::MissingEnumField {
+                    variant_name: ::core::convert::Into::into(#variant_name),
+                    field_name: ::core::convert::Into::into(#field_name)
+                }
+            })?
+        }
+    }
+
+    fn unwrap_default(&self, field: VariantField) -> TokenStream {
         let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
+        let cold_path = cold_path(self.reflect_enum);
+        let field_name = self.field_error_context(field);
+        let VariantField {
+            alias,
+            variant_name,
+            ..
+        } = field;
 
-        let field_name = match &field.data.ident {
-            Some(ident) => format!("{ident}"),
-            None => format!(".{}", field.declaration_index),
+        quote! {
+            #alias.map_err(|err| {
+                #cold_path
+                #bevy_reflect_path::ApplyError::FieldDefaultFailed {
+                    variant_name: ::core::convert::Into::into(#variant_name),
+                    field_name: ::core::convert::Into::into(#field_name),
+                    error: ::core::convert::Into::into(::std::string::ToString::to_string(&err)),
+                }
+            })?
+        }
+    }
+
+    fn on_variant_default_mismatch(&self, variant: &EnumVariant) -> TokenStream {
+        let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
+        let cold_path = cold_path(self.reflect_enum);
+        let variant_name = variant.data.ident.to_string();
+        quote! {{
+            #cold_path
+            return #FQResult::Err(#bevy_reflect_path::ApplyError::MismatchedVariantDefault {
+                variant_name: ::core::convert::Into::into(#variant_name),
+            });
+        }}
+    }
+
+    fn construct_field(&self, field: VariantField) -> TokenStream {
+        let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
+        let alias = field.alias;
+        let field_ty = &field.field.data.ty;
+
+        if let Some(module) = &field.field.attrs.with {
+            let apply = with_function(module, "apply");
+            return quote!(#apply(#alias)?);
+        }
+
+        let cold_path = cold_path(self.reflect_enum);
+
+        // The concrete type behind a boxed trait object is unknown, so it cannot be constructed
+        if field.field.attrs.apply_via_downcast.is_some() {
+            return quote! {
+                #FQResult::Err::<#field_ty, _>({
+                    #cold_path
+                    #bevy_reflect_path::ApplyError::MismatchedTypes {
+                        from_type: ::core::convert::Into::into(
+                            #bevy_reflect_path::DynamicTypePath::reflect_type_path(#alias)
+                        ),
+                        to_type: ::core::convert::Into::into(::core::any::type_name::<#field_ty>()),
+                    }
+                })?
+            };
+        }
+
+        // Span the call to the field type so an unsatisfied `FromReflect` bound points at the field
+        let from_reflect = if self.reflect_enum.meta().attrs().coerce_numeric() {
+            quote_spanned! {field_ty.span()=>
+                #bevy_reflect_path::__macro_exports::from_reflect_widened::<#field_ty>(#alias)
+            }
+        } else {
+            quote_spanned! {field_ty.span()=>
+                <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#alias)
+            }
         };
 
+        // The type paths are only looked up inside the error closure, keeping them off the success path
         quote! {
-            #alias.ok_or(#bevy_reflect_path::ApplyError::MissingEnumField {
+            #from_reflect
+                .ok_or_else(|| {
+                    #cold_path
+                    #bevy_reflect_path::ApplyError::MismatchedTypes {
+                        from_type: ::core::convert::Into::into(
+                            #bevy_reflect_path::DynamicTypePath::reflect_type_path(#alias)
+                        ),
+                        to_type: ::core::convert::Into::into(<#field_ty as #bevy_reflect_path::TypePath>::type_path())
+                    }
+                })?
+        }
+    }
+}
+
+/// Generates the enum variant output data needed to build the `Reflect::try_apply` implementation
+/// for types marked `#[reflect(collect_apply_errors)]`.
+///
+/// Unlike [`TryApplyVariantBuilder`], every active field is constructed before any error is returned,
+/// so that all failures are reported together as an `ApplyError::Multiple`.
+pub(crate) struct CollectingTryApplyVariantBuilder<'a> {
+    inner: TryApplyVariantBuilder<'a>,
+}
+
+impl<'a> CollectingTryApplyVariantBuilder<'a> {
+    pub fn new(reflect_enum: &'a ReflectEnum) -> Self {
+        Self {
+            inner: TryApplyVariantBuilder::new(reflect_enum),
+        }
+    }
+
+    /// Creates a builder for constructing a new value (see [`TryApplyVariantBuilder::for_construction`]).
+    pub fn for_construction(reflect_enum: &'a ReflectEnum) -> Self {
+        Self {
+            inner: TryApplyVariantBuilder::for_construction(reflect_enum),
+        }
+    }
+}
+
+impl<'a> VariantBuilder for CollectingTryApplyVariantBuilder<'a> {
+    fn reflect_enum(&self) -> &ReflectEnum<'_> {
+        self.inner.reflect_enum()
+    }
+
+    // Missing fields are already reported alongside every other error, so they are not batched separately
+    fn before_variant(&self, _this: &Ident, variant: &EnumVariant) -> TokenStream {
+        build_deprecation_warning(self.inner.reflect_enum, variant)
+    }
+
+    fn includes_variant(&self, variant: &EnumVariant) -> bool {
+        self.inner.includes_variant(variant)
+    }
+
+    fn unwrap_field(&self, field: VariantField) -> TokenStream {
+        self.inner.unwrap_field(field)
+    }
+
+    fn unwrap_default(&self, field: VariantField) -> TokenStream {
+        self.inner.unwrap_default(field)
+    }
+
+    fn on_variant_default_mismatch(&self, variant: &EnumVariant) -> TokenStream {
+        self.inner.on_variant_default_mismatch(variant)
+    }
+
+    fn construct_field(&self, field: VariantField) -> TokenStream {
+        self.inner.construct_field(field)
+    }
+
+    fn build(&self, this: &Ident) -> EnumVariantOutputData {
+        let bevy_reflect_path = self.reflect_enum().meta().bevy_reflect_path();
+        let cold_path = cold_path(self.reflect_enum());
+        let fqresult = FQResult.into_token_stream();
+        let variants = self.reflect_enum().variants();
+        let has_ignored_fields = self.reflect_enum().has_ignored_fields();
+
+        let mut variant_cfgs = Vec::with_capacity(variants.len());
+        let mut variant_patterns = Vec::with_capacity(variants.len());
+        let mut variant_constructors = Vec::with_capacity(variants.len());
+
+        for variant in self
+            .reflect_enum()
+            .active_variants()
+            .filter(|variant| self.includes_variant(variant))
+        {
+            let variant_ident = &variant.data.ident;
+            let variant_name = variant_ident.to_string();
+            let variant_path = self.reflect_enum().get_unit(variant_ident);
+
+            // Every field is evaluated in declaration order, so that field functions with side effects
+            // run in the same order as with the other builders
+            let mut members = Vec::new();
+            let mut aliases = Vec::new();
+            let mut values = Vec::new();
+            let mut ok_patterns = Vec::new();
+            let mut err_patterns = Vec::new();
+            let mut active_aliases = Vec::new();
+
+            for field in variant.fields() {
+                let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+                let alias = field_alias(field);
+
+                let variant_field = VariantField {
+                    alias: &alias,
+                    variant_name: &variant_name,
+                    field,
+                };
+
+                if has_ignored_fields && field.attrs.ignore.is_ignored() {
+                    values.push(self.on_ignored_field(variant_field));
+                    ok_patterns.push(quote!(#alias));
+                    err_patterns.push(quote!(_));
+                } else {
+                    let field_ty = &field.data.ty;
+                    let value = self.on_active_field(this, variant_field);
+                    // Each field gets its own closure so that `?` only aborts that field
+                    values.push(quote! {
+                        (|| -> #fqresult<#field_ty, #bevy_reflect_path::ApplyError> {
+                            #fqresult::Ok(#value)
+                        })()
+                    });
+                    ok_patterns.push(quote!(#fqresult::Ok(#alias)));
+                    err_patterns.push(quote!(#alias));
+                    active_aliases.push(alias.clone());
+                }
+                members.push(member);
+                aliases.push(alias);
+            }
+
+            let constructor = if active_aliases.is_empty() {
+                quote! {
+                    #variant_path {
+                        #( #members: #values ),*
+                    }
+                }
+            } else {
+                quote! {
+                    match ( #( #values, )* ) {
+                        ( #( #ok_patterns, )* ) => #variant_path {
+                            #( #members: #aliases, )*
+                        },
+                        ( #( #err_patterns, )* ) => {
+                            #cold_path
+                            return #fqresult::Err(#bevy_reflect_path::ApplyError::Multiple(
+                                ::core::iter::Iterator::collect(::core::iter::Iterator::flatten(
+                                    ::core::iter::IntoIterator::into_iter([
+                                        #( #fqresult::err(#active_aliases), )*
+                                    ])
+                                ))
+                            ));
+                        }
+                    }
+                }
+            };
+
+            let cfg_attrs = variant.cfg_attrs();
+            variant_cfgs.push(quote!(#(#cfg_attrs)*));
+            variant_patterns.push(variant_pattern(variant));
+            variant_constructors.push(with_variant_hooks(self, this, variant, constructor));
+        }
+
+        EnumVariantOutputData {
+            variant_cfgs,
+            variant_patterns,
+            variant_constructors,
+        }
+    }
+}
+
+/// Generates the enum variant output data needed to build the `from_dynamic_enum` function
+/// for types marked `#[reflect(from_dynamic_enum)]`.
+///
+/// Unlike [`FromReflectVariantBuilder`], failures are reported as a `FromReflectError`
+/// naming the variant and field that could not be constructed.
+pub(crate) struct FromDynamicVariantBuilder<'a> {
+    reflect_enum: &'a ReflectEnum<'a>,
+}
+
+impl<'a> FromDynamicVariantBuilder<'a> {
+    pub fn new(reflect_enum: &'a ReflectEnum) -> Self {
+        Self { reflect_enum }
+    }
+}
+
+impl<'a> VariantBuilder for FromDynamicVariantBuilder<'a> {
+    fn reflect_enum(&self) -> &ReflectEnum<'_> {
+        self.reflect_enum
+    }
+
+    fn includes_variant(&self, variant: &EnumVariant) -> bool {
+        self.reflect_enum
+            .meta()
+            .from_reflect()
+            .includes_variant(&variant.data.ident)
+    }
+
+    fn unwrap_field(&self, field: VariantField) -> TokenStream {
+        let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
+        let field_name = self.field_error_context(field);
+        let VariantField {
+            alias,
+            variant_name,
+            ..
+        } = field;
+
+        quote! {
+            #alias.ok_or(#bevy_reflect_path::FromReflectError::MissingField {
                 variant_name: ::core::convert::Into::into(#variant_name),
                 field_name: ::core::convert::Into::into(#field_name)
             })?
         }
     }
 
+    fn unwrap_default(&self, field: VariantField) -> TokenStream {
+        let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
+        let field_name = self.field_error_context(field);
+        let VariantField {
+            alias,
+            variant_name,
+            ..
+        } = field;
+
+        quote! {
+            #alias.map_err(|err| #bevy_reflect_path::FromReflectError::FieldDefaultFailed {
+                variant_name: ::core::convert::Into::into(#variant_name),
+                field_name: ::core::convert::Into::into(#field_name),
+                error: ::core::convert::Into::into(::std::string::ToString::to_string(&err)),
+            })?
+        }
+    }
+
+    fn on_variant_default_mismatch(&self, variant: &EnumVariant) -> TokenStream {
+        let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
+        let variant_name = variant.data.ident.to_string();
+        quote! {
+            return #FQResult::Err(#bevy_reflect_path::FromReflectError::MismatchedVariantDefault {
+                variant_name: ::core::convert::Into::into(#variant_name),
+            })
+        }
+    }
+
     fn construct_field(&self, field: VariantField) -> TokenStream {
         let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
-        let alias = field.alias;
-        let field_ty = &field.field.data.ty;
+        let field_name = self.field_error_context(field);
+        let VariantField {
+            alias,
+            variant_name,
+            field,
+        } = field;
+        let field_ty = &field.data.ty;
+
+        let (value, to_type) = match &field.attrs.with {
+            Some(module) => {
+                let from_reflect = with_function(module, "from_reflect");
+                (
+                    quote!(#from_reflect(#alias)),
+                    quote!(<#field_ty as #bevy_reflect_path::TypePath>::type_path()),
+                )
+            }
+            // The concrete type behind a boxed trait object is unknown, so it cannot be constructed
+            None if field.attrs.apply_via_downcast.is_some() => (
+                quote!(#FQOption::None::<#field_ty>),
+                quote!(::core::any::type_name::<#field_ty>()),
+            ),
+            // Span the call to the field type so an unsatisfied `FromReflect` bound points at the field
+            None => (
+                quote_spanned! {field_ty.span()=>
+                    <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#alias)
+                },
+                quote!(<#field_ty as #bevy_reflect_path::TypePath>::type_path()),
+            ),
+        };
 
         quote! {
-            <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#alias)
-                .ok_or(#bevy_reflect_path::ApplyError::MismatchedTypes {
-                    from_type: ::core::convert::Into::into(
-                        #bevy_reflect_path::DynamicTypePath::reflect_type_path(#alias)
-                    ),
-                    to_type: ::core::convert::Into::into(<#field_ty as #bevy_reflect_path::TypePath>::type_path())
-                })?
+            #value.ok_or_else(|| #bevy_reflect_path::FromReflectError::MismatchedTypes {
+                variant_name: ::core::convert::Into::into(#variant_name),
+                field_name: ::core::convert::Into::into(#field_name),
+                from_type: ::core::convert::Into::into(
+                    #bevy_reflect_path::DynamicTypePath::reflect_type_path(#alias)
+                ),
+                to_type: ::core::convert::Into::into(#to_type)
+            })?
+        }
+    }
+}
+
+/// Builds the enum variant output data for `Reflect::try_apply`,
+/// using a [`CollectingTryApplyVariantBuilder`] if the type opted into collecting all errors.
+///
+/// Each constructor first rejects data in `this` whose `VariantType` cannot describe the variant,
+/// so that a mismatched shape is reported before any field is accessed.
+/// For types marked `#[reflect(strict_apply)]` or `#[reflect(deny_unknown_fields)]`,
+/// it then rejects fields of `this` that the variant does not reflect.
+///
+/// If `for_construction` is true, such as for `TryFrom<&dyn Reflect>`, variants excluded by
+/// `#[reflect(from_reflect(only = "..."))]` are skipped, like they are for `FromReflect`.
+pub(crate) fn build_try_apply_variants(
+    reflect_enum: &ReflectEnum,
+    this: &Ident,
+    for_construction: bool,
+) -> EnumVariantOutputData {
+    let mut output = match (
+        reflect_enum.meta().attrs().collect_apply_errors(),
+        for_construction,
+    ) {
+        (true, false) => CollectingTryApplyVariantBuilder::new(reflect_enum).build(this),
+        (true, true) => {
+            CollectingTryApplyVariantBuilder::for_construction(reflect_enum).build(this)
+        }
+        (false, false) => TryApplyVariantBuilder::new(reflect_enum).build(this),
+        (false, true) => TryApplyVariantBuilder::for_construction(reflect_enum).build(this),
+    };
+
+    let check_fields = reflect_enum.meta().attrs().strict_apply()
+        || reflect_enum.meta().attrs().deny_unknown_fields();
+    let variants: Vec<_> = if for_construction {
+        reflect_enum.constructible_variants().collect()
+    } else {
+        reflect_enum.active_variants().collect()
+    };
+    for (constructor, variant) in output.variant_constructors.iter_mut().zip(variants) {
+        let variant_type_check = build_variant_type_check(reflect_enum, variant, this);
+        let unknown_field_check = if check_fields {
+            build_unknown_field_check(reflect_enum, variant, this)
+        } else {
+            TokenStream::new()
+        };
+        *constructor = quote! {{
+            #variant_type_check
+            #unknown_field_check
+            #constructor
+        }};
+    }
+
+    output
+}
+
+/// Returns a statement marking the current branch as unlikely to be taken,
+/// if the type opted into `#[reflect(cold_errors)]`.
+pub(crate) fn cold_path(reflect_enum: &ReflectEnum) -> TokenStream {
+    if !reflect_enum.meta().attrs().cold_errors() {
+        return TokenStream::new();
+    }
+
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    quote!(#bevy_reflect_path::__macro_exports::cold_path();)
+}
+
+/// Returns a token stream that warns once that the given variant is deprecated,
+/// if it is marked `#[reflect(deprecated = "...")]`.
+fn build_deprecation_warning(reflect_enum: &ReflectEnum, variant: &EnumVariant) -> TokenStream {
+    let Some(note) = variant
+        .attrs
+        .deprecation
+        .as_ref()
+        .and_then(|deprecation| deprecation.note.as_ref())
+    else {
+        return TokenStream::new();
+    };
+
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let variant_name = variant.data.ident.to_string();
+    quote! {
+        static __DEPRECATION_WARNING: ::std::sync::Once = ::std::sync::Once::new();
+        #bevy_reflect_path::__macro_exports::deprecated_variant_constructed(
+            &__DEPRECATION_WARNING,
+            <Self as #bevy_reflect_path::TypePath>::type_path(),
+            #variant_name,
+            #note,
+        );
+    }
+}
+
+/// Returns a token stream that fails with a single `ApplyError::Multiple` listing every missing field
+/// of the given struct variant, if more than one of them is missing,
+/// for types marked `#[reflect(batch_missing_fields)]`.
+///
+/// Only fields without a default are checked. A single missing field is still reported
+/// by the field's own unwrapper as a plain `ApplyError::MissingEnumField`.
+fn build_missing_fields_check(
+    builder: &impl VariantBuilder,
+    this: &Ident,
+    variant: &EnumVariant,
+) -> TokenStream {
+    let reflect_enum = builder.reflect_enum();
+    if !reflect_enum.meta().attrs().batch_missing_fields() {
+        return TokenStream::new();
+    }
+    let EnumVariantFields::Named(fields) = &variant.fields else {
+        return TokenStream::new();
+    };
+
+    let variant_name = variant.data.ident.to_string();
+    let required_fields = fields
+        .iter()
+        .filter(|field| {
+            field.attrs.ignore == ReflectIgnoreBehavior::None
+                && matches!(field.attrs.default, DefaultBehavior::Required)
+        })
+        .collect::<Vec<_>>();
+    if required_fields.len() < 2 {
+        return TokenStream::new();
+    }
+
+    let missing_fields = required_fields
+        .into_iter()
+        .map(|field| {
+            let alias = field_alias(field);
+            let variant_field = VariantField {
+                alias: &alias,
+                variant_name: &variant_name,
+                field,
+            };
+            let field_name = builder.field_error_context(variant_field);
+            let field_accessor = builder.access_field(this, variant_field);
+            quote!((#field_name, #FQOption::is_none(&#field_accessor)))
+        })
+        .collect::<Vec<_>>();
+
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let cold_path = cold_path(reflect_enum);
+    quote! {
+        let __missing_fields = [#(#missing_fields),*];
+        // `IntoIterator` is called explicitly, as the array would otherwise resolve to `Array::iter`
+        // if that trait is in scope
+        if ::core::iter::IntoIterator::into_iter(&__missing_fields)
+            .filter(|(_, is_missing)| *is_missing)
+            .count() > 1
+        {
+            #cold_path
+            return #FQResult::Err(#bevy_reflect_path::ApplyError::Multiple(
+                ::core::iter::IntoIterator::into_iter(__missing_fields)
+                    .filter(|(_, is_missing)| *is_missing)
+                    .map(|(field_name, _)| #bevy_reflect_path::ApplyError::MissingEnumField {
+                        variant_name: ::core::convert::Into::into(#variant_name),
+                        field_name: ::core::convert::Into::into(field_name),
+                    })
+                    .collect(),
+            ));
+        }
+    }
+}
+
+/// Returns a token stream that fails with `ApplyError::MismatchedVariantTypes`
+/// if the variant type of `this` cannot describe the given variant.
+///
+/// Unit variants accept any data, and tuple variants also accept struct data
+/// with fields named by their index.
+fn build_variant_type_check(
+    reflect_enum: &ReflectEnum,
+    variant: &EnumVariant,
+    this: &Ident,
+) -> TokenStream {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let variant_type = quote!(#bevy_reflect_path::VariantType);
+    let (expected, allowed) = match &variant.fields {
+        EnumVariantFields::Unit => return TokenStream::new(),
+        EnumVariantFields::Unnamed(_) => (
+            quote!(#variant_type::Tuple),
+            quote!(#variant_type::Tuple | #variant_type::Struct),
+        ),
+        EnumVariantFields::Named(_) => {
+            (quote!(#variant_type::Struct), quote!(#variant_type::Struct))
+        }
+    };
+    let cold_path = cold_path(reflect_enum);
+    let variant_name = variant.data.ident.to_string();
+
+    quote! {
+        let __found = #bevy_reflect_path::Enum::variant_type(#this);
+        if !::core::matches!(__found, #allowed) {
+            #cold_path
+            return #FQResult::Err(#bevy_reflect_path::ApplyError::MismatchedVariantTypes {
+                variant_name: ::core::convert::Into::into(#variant_name),
+                expected: #expected,
+                found: __found,
+            });
+        }
+    }
+}
+
+/// Returns a token stream that fails if `this` contains a field that the given variant does not reflect,
+/// as decided by [`build_field_rejection`].
+fn build_unknown_field_check(
+    reflect_enum: &ReflectEnum,
+    variant: &EnumVariant,
+    this: &Ident,
+) -> TokenStream {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let cold_path = cold_path(reflect_enum);
+    let variant_name = variant.data.ident.to_string();
+
+    let active_names = variant
+        .active_fields()
+        .filter_map(|field| field.name.as_deref());
+    let ignored_names = variant
+        .fields()
+        .iter()
+        .filter(|field| field.attrs.ignore.is_ignored())
+        .filter_map(|field| field.name.as_deref());
+    let (active_len, declared_len) = match &variant.fields {
+        EnumVariantFields::Unnamed(fields) => (variant.active_fields().count(), fields.len()),
+        _ => (0, 0),
+    };
+
+    let field_name = quote! {
+        match __field.name() {
+            #FQOption::Some(__name) => ::core::convert::Into::into(__name),
+            #FQOption::None => ::core::convert::Into::into(::std::format!(".{}", __index)),
+        }
+    };
+    let rejection = build_field_rejection(
+        reflect_enum,
+        &quote!(#variant_name),
+        &field_name,
+        &quote!(__is_ignored),
+        |error| {
+            quote! {
+                #cold_path
+                return #FQResult::Err(#error);
+            }
+        },
+    );
+
+    quote! {
+        let __active_names: &[&str] = &[#(#active_names),*];
+        let __ignored_names: &[&str] = &[#(#ignored_names),*];
+        for (__index, __field) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Enum::iter_fields(#this)) {
+            let (__is_active, __is_ignored) = match __field.name() {
+                #FQOption::Some(__name) => (__active_names.contains(&__name), __ignored_names.contains(&__name)),
+                #FQOption::None => (__index < #active_len, __index < #declared_len),
+            };
+
+            if !__is_active {
+                #rejection
+            }
+        }
+    }
+}
+
+/// Returns the statements handling a field of the applied value that the target variant does not reflect,
+/// or `None` if such fields are silently dropped.
+///
+/// For types marked `#[reflect(strict_apply)]`, any such field fails with `ApplyError::IgnoredFieldPresent`.
+/// For types marked `#[reflect(deny_unknown_fields)]`, a field the variant does not declare at all
+/// fails with `ApplyError::UnknownField` instead, while its ignored fields are still dropped
+/// unless `strict_apply` is also used.
+///
+/// # Parameters
+/// * `variant_name`: An expression evaluating to the name of the target variant
+/// * `field_name`: An expression evaluating to the name of the field
+/// * `is_ignored`: A boolean expression that is true if the field is declared but ignored by the variant
+/// * `fail`: Creates the statements that report the given `ApplyError` expression
+pub(crate) fn build_field_rejection(
+    reflect_enum: &ReflectEnum,
+    variant_name: &TokenStream,
+    field_name: &TokenStream,
+    is_ignored: &TokenStream,
+    fail: impl Fn(TokenStream) -> TokenStream,
+) -> Option<TokenStream> {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let attrs = reflect_enum.meta().attrs();
+
+    let error = |kind: TokenStream| {
+        fail(quote! {
+            #bevy_reflect_path::ApplyError::#kind {
+                variant_name: ::core::convert::Into::into(#variant_name),
+                field_name: #field_name,
+            }
+        })
+    };
+    let ignored_field_present = error(quote!(IgnoredFieldPresent));
+    let unknown_field = error(quote!(UnknownField));
+
+    match (attrs.strict_apply(), attrs.deny_unknown_fields()) {
+        (false, false) => None,
+        (true, false) => Some(ignored_field_present),
+        (false, true) => Some(quote! {
+            if !#is_ignored {
+                #unknown_field
+            }
+        }),
+        (true, true) => Some(quote! {
+            if #is_ignored {
+                #ignored_field_present
+            } else {
+                #unknown_field
+            }
+        }),
+    }
+}
+
+/// Generates the enum variant output data needed to construct each variant purely from its field defaults.
+///
+/// Every field, active or ignored, is built from its [`DefaultBehavior`],
+/// falling back to `Default::default()` for fields without a `#[reflect(default)]` attribute.
+pub(crate) struct DefaultVariantBuilder<'a> {
+    reflect_enum: &'a ReflectEnum<'a>,
+}
+
+impl<'a> DefaultVariantBuilder<'a> {
+    pub fn new(reflect_enum: &'a ReflectEnum) -> Self {
+        Self { reflect_enum }
+    }
+}
+
+impl<'a> VariantBuilder for DefaultVariantBuilder<'a> {
+    fn reflect_enum(&self) -> &ReflectEnum<'_> {
+        self.reflect_enum
+    }
+
+    fn unwrap_field(&self, _field: VariantField) -> TokenStream {
+        quote!(::core::compile_error!(
+            "internal bevy_reflect error: default variant fields are never unwrapped"
+        ))
+    }
+
+    fn unwrap_default(&self, field: VariantField) -> TokenStream {
+        let alias = field.alias;
+        quote!(#FQResult::ok(#alias)?)
+    }
+
+    fn on_variant_default_mismatch(&self, _variant: &EnumVariant) -> TokenStream {
+        quote!(return #FQOption::None)
+    }
+
+    fn construct_field(&self, _field: VariantField) -> TokenStream {
+        quote!(::core::compile_error!(
+            "internal bevy_reflect error: default variant fields are never reflected"
+        ))
+    }
+
+    fn on_active_field(&self, _this: &Ident, field: VariantField) -> TokenStream {
+        self.on_ignored_field(field)
+    }
+
+    fn on_ignored_field(&self, field: VariantField) -> TokenStream {
+        let field_ty = &field.field.data.ty;
+        match &field.field.attrs.default {
+            DefaultBehavior::Func(path) => call_default_func(path, field_ty),
+            DefaultBehavior::TryFunc(path) => {
+                let alias = field.alias;
+                let default_value = call_default_func(path, quote!(#FQResult<#field_ty, _>));
+                let default_unwrapper = self.unwrap_default(field);
+                quote! {{
+                    let #alias = #default_value;
+                    #default_unwrapper
+                }}
+            }
+            DefaultBehavior::Variant => variant_default_alias(field.field).into_token_stream(),
+            _ => {
+                // Span the call to the field type so a missing `Default` impl points at the field
+                quote_spanned! {field_ty.span()=>
+                    <#field_ty as #FQDefault>::default()
+                }
+            }
         }
     }
 }
@@ -7,19 +7,49 @@
 use crate::custom_attributes::CustomAttributes;
 use crate::utility::terminated_parser;
 use crate::REFLECT_ATTRIBUTE_NAME;
+use proc_macro2::Span;
 use syn::parse::ParseStream;
-use syn::{Attribute, LitStr, Meta, Token};
+use syn::spanned::Spanned;
+use syn::{parenthesized, token, Attribute, LitStr, Meta, Token};
 
 mod kw {
     syn::custom_keyword!(ignore);
     syn::custom_keyword!(skip_serializing);
     syn::custom_keyword!(default);
+    syn::custom_keyword!(try_default);
+    syn::custom_keyword!(deprecated);
+    syn::custom_keyword!(since);
+    syn::custom_keyword!(replaced_by);
+    syn::custom_keyword!(note);
+    syn::custom_keyword!(alias);
+    syn::custom_keyword!(with);
+    syn::custom_keyword!(catch_all);
+    syn::custom_keyword!(hidden);
+    syn::custom_keyword!(from);
+    syn::custom_keyword!(skip_if);
+    syn::custom_keyword!(eq);
+    syn::custom_keyword!(apply_via);
+    syn::custom_keyword!(apply);
+    syn::custom_keyword!(merge);
 }
 
 pub(crate) const IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
 pub(crate) const IGNORE_ALL_ATTR: &str = "ignore";
 
 pub(crate) const DEFAULT_ATTR: &str = "default";
+pub(crate) const TRY_DEFAULT_ATTR: &str = "try_default";
+pub(crate) const ALIAS_ATTR: &str = "alias";
+pub(crate) const WITH_ATTR: &str = "with";
+pub(crate) const CATCH_ALL_ATTR: &str = "catch_all";
+pub(crate) const HIDDEN_ATTR: &str = "hidden";
+pub(crate) const FROM_ATTR: &str = "from";
+pub(crate) const SKIP_IF_ATTR: &str = "skip_if";
+pub(crate) const EQ_ATTR: &str = "eq";
+pub(crate) const APPLY_VIA_ATTR: &str = "apply_via";
+pub(crate) const APPLY_ATTR: &str = "apply";
+pub(crate) const MERGE_ATTR: &str = "merge";
+
+pub(crate) const DEPRECATED_ATTR: &str = "deprecated";
 
 /// Stores data about if the field should be visible via the Reflect and serialization interfaces
 ///
@@ -60,11 +90,45 @@ pub(crate) enum DefaultBehavior {
     Required,
     /// Field can be defaulted using `Default::default()`.
     Default,
-    /// Field can be created using the given function name.
+    /// Field can be created using the given function name or inline closure.
     ///
     /// This assumes the function is in scope, is callable with zero arguments,
     /// and returns the expected type.
-    Func(syn::ExprPath),
+    /// Closures are stored parenthesized, so that both forms can be called as `#func()`.
+    Func(syn::Expr),
+    /// Field can be created using the given fallible function name.
+    ///
+    /// This assumes the function is in scope, is callable with zero arguments,
+    /// and returns a `Result` of the expected type whose error implements `Display`.
+    TryFunc(syn::ExprPath),
+    /// Field is taken from the value constructed by its variant's `#[reflect(default = "...")]` function.
+    ///
+    /// This is only valid on enum variant fields.
+    Variant,
+}
+
+/// Controls how a field is merged by the `reflect_merge` method generated by `#[reflect(merge)]`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MergePolicy {
+    /// The other value's field is applied to this one.
+    Replace,
+    /// This field is left unchanged.
+    Keep,
+    /// The other value's field is applied to this one only if it is `Some`.
+    ///
+    /// This is only valid on `Option` fields.
+    Combine,
+}
+
+/// Deprecation data for a variant, created via `#[reflect(deprecated(...))]`.
+#[derive(Default, Clone)]
+pub(crate) struct Deprecation {
+    /// The version in which the variant was deprecated.
+    pub since: Option<LitStr>,
+    /// The name of the variant replacing the deprecated one.
+    pub replaced_by: Option<LitStr>,
+    /// The message to warn with when the variant is constructed through reflection.
+    pub note: Option<LitStr>,
 }
 
 /// A container for attributes defined on a reflected type's field.
@@ -76,6 +140,52 @@ pub(crate) struct FieldAttributes {
     pub default: DefaultBehavior,
     /// Custom attributes created via `#[reflect(@...)]`.
     pub custom_attributes: CustomAttributes,
+    /// Deprecation data for this variant.
+    ///
+    /// This is only valid on enum variants.
+    pub deprecation: Option<Deprecation>,
+    /// Alternative names for this variant, created via `#[reflect(alias = "...")]`.
+    ///
+    /// This is only valid on enum variants.
+    pub aliases: Vec<LitStr>,
+    /// The module providing custom `from_reflect`, `apply`, and `clone` functions for this field,
+    /// created via `#[reflect(with = "...")]`.
+    ///
+    /// This is only valid on enum variant fields.
+    pub with: Option<syn::Path>,
+    /// The span of the `#[reflect(catch_all)]` attribute, if present.
+    ///
+    /// This is only valid on enum variants.
+    pub catch_all: Option<Span>,
+    /// The span of the `#[reflect(hidden)]` attribute, if present.
+    ///
+    /// This is only valid on enum variants.
+    pub hidden: Option<Span>,
+    /// The previous name of this field, created via `#[reflect(from = "...")]`.
+    ///
+    /// This is only valid on named enum variant fields.
+    pub from: Option<LitStr>,
+    /// The predicate deciding whether this field is skipped during serialization,
+    /// created via `#[reflect(skip_if = "...")]`.
+    ///
+    /// This is only valid on named fields that are serialized.
+    pub skip_if: Option<syn::ExprPath>,
+    /// The span of the `#[reflect(eq = "ptr")]` attribute, if present.
+    ///
+    /// This is only valid on enum variant fields.
+    pub ptr_eq: Option<Span>,
+    /// The span of the `#[reflect(apply_via = "downcast")]` attribute, if present.
+    ///
+    /// This is only valid on `Box<dyn Trait>` enum variant fields.
+    pub apply_via_downcast: Option<Span>,
+    /// The span of the `#[reflect(apply = "extend")]` attribute, if present.
+    ///
+    /// This is only valid on enum variant fields.
+    pub apply_extend: Option<Span>,
+    /// How this field is merged by the `reflect_merge` method, created via `#[reflect(merge = "...")]`.
+    ///
+    /// This is only valid on the active fields of enums marked `#[reflect(merge)]`.
+    pub merge: Option<(MergePolicy, Span)>,
 }
 
 impl FieldAttributes {
@@ -108,6 +218,22 @@ impl FieldAttributes {
             .map_or(Ok(args), Err)
     }
 
+    /// Returns the name and span of the first attribute that may only be used on enum variant fields,
+    /// if any was found.
+    pub fn enum_field_only_span(&self) -> Option<(&'static str, Span)> {
+        [
+            self.with.as_ref().map(|with| (WITH_ATTR, with.span())),
+            self.from.as_ref().map(|from| (FROM_ATTR, from.span())),
+            self.ptr_eq.map(|span| (EQ_ATTR, span)),
+            self.apply_via_downcast.map(|span| (APPLY_VIA_ATTR, span)),
+            self.merge.map(|(_, span)| (MERGE_ATTR, span)),
+            self.apply_extend.map(|span| (APPLY_ATTR, span)),
+        ]
+        .into_iter()
+        .flatten()
+        .next()
+    }
+
     /// Parses a single field attribute.
     fn parse_field_attribute(&mut self, input: ParseStream) -> syn::Result<()> {
         let lookahead = input.lookahead1();
@@ -119,6 +245,30 @@ impl FieldAttributes {
             self.parse_skip_serializing(input)
         } else if lookahead.peek(kw::default) {
             self.parse_default(input)
+        } else if lookahead.peek(kw::try_default) {
+            self.parse_try_default(input)
+        } else if lookahead.peek(kw::deprecated) {
+            self.parse_deprecated(input)
+        } else if lookahead.peek(kw::alias) {
+            self.parse_alias(input)
+        } else if lookahead.peek(kw::with) {
+            self.parse_with(input)
+        } else if lookahead.peek(kw::catch_all) {
+            self.parse_catch_all(input)
+        } else if lookahead.peek(kw::hidden) {
+            self.parse_hidden(input)
+        } else if lookahead.peek(kw::from) {
+            self.parse_from(input)
+        } else if lookahead.peek(kw::skip_if) {
+            self.parse_skip_if(input)
+        } else if lookahead.peek(kw::eq) {
+            self.parse_eq(input)
+        } else if lookahead.peek(kw::apply_via) {
+            self.parse_apply_via(input)
+        } else if lookahead.peek(kw::apply) {
+            self.parse_apply(input)
+        } else if lookahead.peek(kw::merge) {
+            self.parse_merge(input)
         } else {
             Err(lookahead.error())
         }
@@ -163,9 +313,13 @@ impl FieldAttributes {
     /// Examples:
     /// - `#[reflect(default)]`
     /// - `#[reflect(default = "path::to::func")]`
+    /// - `#[reflect(default = || Vec::with_capacity(8))]`
     fn parse_default(&mut self, input: ParseStream) -> syn::Result<()> {
         if !matches!(self.default, DefaultBehavior::Required) {
-            return Err(input.error(format!("only one of {:?} is allowed", [DEFAULT_ATTR])));
+            return Err(input.error(format!(
+                "only one of {:?} is allowed",
+                [DEFAULT_ATTR, TRY_DEFAULT_ATTR]
+            )));
         }
 
         input.parse::<kw::default>()?;
@@ -173,8 +327,19 @@ impl FieldAttributes {
         if input.peek(Token![=]) {
             input.parse::<Token![=]>()?;
 
-            let lit = input.parse::<LitStr>()?;
-            self.default = DefaultBehavior::Func(lit.parse()?);
+            let func = if input.peek(LitStr) {
+                let lit = input.parse::<LitStr>()?;
+                syn::Expr::Path(lit.parse()?)
+            } else {
+                let closure = input.parse::<syn::ExprClosure>()?;
+                syn::Expr::Paren(syn::ExprParen {
+                    attrs: Vec::new(),
+                    paren_token: token::Paren::default(),
+                    expr: Box::new(syn::Expr::Closure(closure)),
+                })
+            };
+
+            self.default = DefaultBehavior::Func(func);
         } else {
             self.default = DefaultBehavior::Default;
         }
@@ -182,6 +347,256 @@ impl FieldAttributes {
         Ok(())
     }
 
+    /// Parse `try_default` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(try_default = "path::to::func")]`
+    fn parse_try_default(&mut self, input: ParseStream) -> syn::Result<()> {
+        if !matches!(self.default, DefaultBehavior::Required) {
+            return Err(input.error(format!(
+                "only one of {:?} is allowed",
+                [DEFAULT_ATTR, TRY_DEFAULT_ATTR]
+            )));
+        }
+
+        input.parse::<kw::try_default>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+        self.default = DefaultBehavior::TryFunc(lit.parse()?);
+
+        Ok(())
+    }
+
+    /// Parse `deprecated` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(deprecated)]`
+    /// - `#[reflect(deprecated(since = "0.14", replaced_by = "NewVariant"))]`
+    /// - `#[reflect(deprecated = "use NewVariant instead")]`
+    /// - `#[reflect(deprecated(note = "use NewVariant instead", since = "0.14"))]`
+    fn parse_deprecated(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.deprecation.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [DEPRECATED_ATTR])));
+        }
+
+        input.parse::<kw::deprecated>()?;
+
+        let mut deprecation = Deprecation::default();
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            deprecation.note = Some(input.parse()?);
+        } else if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            terminated_parser(Token![,], |stream| {
+                let lookahead = stream.lookahead1();
+                if lookahead.peek(kw::since) {
+                    stream.parse::<kw::since>()?;
+                    stream.parse::<Token![=]>()?;
+                    deprecation.since = Some(stream.parse()?);
+                } else if lookahead.peek(kw::replaced_by) {
+                    stream.parse::<kw::replaced_by>()?;
+                    stream.parse::<Token![=]>()?;
+                    deprecation.replaced_by = Some(stream.parse()?);
+                } else if lookahead.peek(kw::note) {
+                    stream.parse::<kw::note>()?;
+                    stream.parse::<Token![=]>()?;
+                    deprecation.note = Some(stream.parse()?);
+                } else {
+                    return Err(lookahead.error());
+                }
+                Ok(())
+            })(&content)?;
+        }
+
+        self.deprecation = Some(deprecation);
+        Ok(())
+    }
+
+    /// Parse `alias` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(alias = "OldName")]`
+    fn parse_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::alias>()?;
+        input.parse::<Token![=]>()?;
+        self.aliases.push(input.parse()?);
+        Ok(())
+    }
+
+    /// Parse `with` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(with = "path::to::module")]`
+    fn parse_with(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.with.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [WITH_ATTR])));
+        }
+
+        input.parse::<kw::with>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+        self.with = Some(lit.parse()?);
+        Ok(())
+    }
+
+    /// Parse `catch_all` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(catch_all)]`
+    fn parse_catch_all(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.catch_all.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [CATCH_ALL_ATTR])));
+        }
+
+        let ident = input.parse::<kw::catch_all>()?;
+        self.catch_all = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `hidden` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(hidden)]`
+    fn parse_hidden(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.hidden.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [HIDDEN_ATTR])));
+        }
+
+        let ident = input.parse::<kw::hidden>()?;
+        self.hidden = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `from` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(from = "old_name")]`
+    fn parse_from(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.from.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [FROM_ATTR])));
+        }
+
+        input.parse::<kw::from>()?;
+        input.parse::<Token![=]>()?;
+        self.from = Some(input.parse()?);
+        Ok(())
+    }
+
+    /// Parse `skip_if` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(skip_if = "Option::is_none")]`
+    fn parse_skip_if(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.skip_if.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [SKIP_IF_ATTR])));
+        }
+
+        input.parse::<kw::skip_if>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+        self.skip_if = Some(lit.parse()?);
+        Ok(())
+    }
+
+    /// Parse `eq` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(eq = "ptr")]`
+    fn parse_eq(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.ptr_eq.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [EQ_ATTR])));
+        }
+
+        input.parse::<kw::eq>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+        if lit.value() != "ptr" {
+            return Err(syn::Error::new(lit.span(), "expected `\"ptr\"`"));
+        }
+
+        self.ptr_eq = Some(lit.span());
+        Ok(())
+    }
+
+    /// Parse `apply_via` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(apply_via = "downcast")]`
+    fn parse_apply_via(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.apply_via_downcast.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [APPLY_VIA_ATTR])));
+        }
+
+        input.parse::<kw::apply_via>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+        if lit.value() != "downcast" {
+            return Err(syn::Error::new(lit.span(), "expected `\"downcast\"`"));
+        }
+
+        self.apply_via_downcast = Some(lit.span());
+        Ok(())
+    }
+
+    /// Parse `apply` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(apply = "extend")]`
+    fn parse_apply(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.apply_extend.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [APPLY_ATTR])));
+        }
+
+        input.parse::<kw::apply>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+        if lit.value() != "extend" {
+            return Err(syn::Error::new(lit.span(), "expected `\"extend\"`"));
+        }
+
+        self.apply_extend = Some(lit.span());
+        Ok(())
+    }
+
+    /// Parse `merge` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(merge = "replace")]`
+    /// - `#[reflect(merge = "keep")]`
+    /// - `#[reflect(merge = "combine")]`
+    fn parse_merge(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.merge.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [MERGE_ATTR])));
+        }
+
+        input.parse::<kw::merge>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+        let policy = match lit.value().as_str() {
+            "replace" => MergePolicy::Replace,
+            "keep" => MergePolicy::Keep,
+            "combine" => MergePolicy::Combine,
+            _ => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "expected `\"replace\"`, `\"keep\"` or `\"combine\"`",
+                ))
+            }
+        };
+
+        self.merge = Some((policy, lit.span()));
+        Ok(())
+    }
+
     /// Parse `@` (custom attribute) attribute.
     ///
     /// Examples:
@@ -1,13 +1,18 @@
 use crate::container_attributes::REFLECT_DEFAULT;
-use crate::derive_data::ReflectEnum;
-use crate::enum_utility::{EnumVariantOutputData, FromReflectVariantBuilder, VariantBuilder};
+use crate::derive_data::{ReflectEnum, StructField};
+use crate::enum_utility::{
+    build_catch_all, build_excluded_variant_arms, build_ignored_variant_arms,
+    build_try_apply_variants, build_variant_name_lookup, build_variant_suggestion,
+    EnumVariantOutputData, FromDynamicVariantBuilder, FromReflectVariantBuilder, VariantBuilder,
+};
 use crate::field_attributes::DefaultBehavior;
-use crate::utility::{ident_or_index, WhereClauseOptions};
+use crate::utility::{call_default_func, ident_or_index, WhereClauseOptions};
 use crate::{ReflectMeta, ReflectStruct};
-use bevy_macro_utils::fq_std::{FQAny, FQClone, FQDefault, FQOption};
+use bevy_macro_utils::fq_std::{FQAny, FQClone, FQDefault, FQOption, FQResult};
 use proc_macro2::Span;
-use quote::{quote, ToTokens};
-use syn::{Field, Ident, Lit, LitInt, LitStr, Member};
+use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::{parse_quote, Field, Ident, Lit, LitInt, LitStr, Member};
 
 /// Implements `FromReflect` for the given struct
 pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
@@ -35,6 +40,16 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
 /// Implements `FromReflect` for the given enum type
 pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    if reflect_enum.meta().attrs().transparent().is_some() {
+        let variant = &reflect_enum.variants()[0];
+        return impl_transparent(
+            reflect_enum.meta(),
+            &reflect_enum.get_unit(&variant.data.ident),
+            &variant.fields()[0],
+            &reflect_enum.where_clause_options(),
+        );
+    }
+
     let fqoption = FQOption.into_token_stream();
 
     let enum_path = reflect_enum.meta().type_path();
@@ -43,11 +58,23 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
     let ref_value = Ident::new("__param0", Span::call_site());
 
     let EnumVariantOutputData {
-        variant_names,
-        variant_constructors,
+        variant_cfgs,
+        variant_patterns,
+        mut variant_constructors,
         ..
     } = FromReflectVariantBuilder::new(reflect_enum).build(&ref_value);
 
+    migrate_deprecated_variants(reflect_enum, &mut variant_constructors);
+
+    // Variants may also be identified by their discriminant (e.g. `"2"`) for compact formats,
+    // unless unknown names are stored in a catch-all variant instead
+    let has_catch_all = reflect_enum.catch_all_variant().is_some();
+    let discriminant_ty = reflect_enum.discriminant_ty();
+    let variant_discriminants = reflect_enum
+        .constructible_variants()
+        .map(|variant| variant.discriminant.to_tokens_as(&discriminant_ty))
+        .collect::<Vec<_>>();
+
     let (impl_generics, ty_generics, where_clause) = enum_path.generics().split_for_impl();
 
     // Add FromReflect bound for each active field
@@ -55,16 +82,440 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
         .where_clause_options()
         .extend_where_clause(where_clause);
 
+    let try_from_impl = reflect_enum
+        .meta()
+        .attrs()
+        .try_from()
+        .map(|_| impl_enum_try_from(reflect_enum));
+
+    let from_dynamic_enum_impl = reflect_enum
+        .meta()
+        .attrs()
+        .dynamic_enum_constructor_span()
+        .map(|_| impl_enum_from_dynamic_enum(reflect_enum));
+
+    // Variants may be looked up by their discriminant tag instead of their name.
+    // Each tag is compared against the variant's actual discriminant, so discriminants need not be contiguous.
+    let from_tag_impl = reflect_enum.meta().attrs().tag_constructor_span().map(|_| {
+        quote! {
+            impl #impl_generics #enum_path #ty_generics #where_from_reflect_clause {
+                /// Constructs the variant with the given discriminant from its reflected fields,
+                /// which may be given as a struct, tuple struct, tuple or enum.
+                ///
+                /// Returns `None` if no variant has that discriminant or one of its fields could not be constructed.
+                pub fn from_tag(tag: #discriminant_ty, fields: &dyn #bevy_reflect_path::Reflect) -> #fqoption<Self> {
+                    let #ref_value = #bevy_reflect_path::__macro_exports::VariantFields::new(fields);
+                    #(#variant_cfgs if tag == #variant_discriminants {
+                        return #fqoption::Some(#variant_constructors);
+                    })*
+                    #fqoption::None
+                }
+            }
+        }
+    });
+
+    // Ignored variants cannot be constructed from their reflected fields
+    let ignored_variants = build_ignored_variant_arms(reflect_enum, |_| quote!(#fqoption::None));
+
+    // Variants excluded by `from_reflect(only = "...")` are never constructed
+    let excluded_variants = build_excluded_variant_arms(reflect_enum, |_| quote!(#fqoption::None));
+
+    // Unknown variants are either stored in the catch-all variant or rejected
+    let variant_name = Ident::new("name", Span::call_site());
+    let unknown_variant = match build_catch_all(reflect_enum, &variant_name) {
+        Some(catch_all) => quote!(#fqoption::Some(#catch_all)),
+        None => quote! {
+            panic!("variant with name `{}` does not exist on enum `{}`", #variant_name, <Self as #bevy_reflect_path::TypePath>::type_path())
+        },
+    };
+
+    let variant_name_lookup = build_variant_name_lookup(reflect_enum, &ref_value);
+
+    // Externally tagged values are maps with a single entry of the variant name to its fields.
+    // Their constructors read the fields through `VariantFields`, so they cannot use the split variant helpers.
+    let build_externally_tagged = |variant_constructors: &[proc_macro2::TokenStream]| {
+        reflect_enum.meta().from_reflect().externally_tagged().map(|_| {
+            quote! {
+                else if let #bevy_reflect_path::ReflectRef::Map(__map) = #bevy_reflect_path::Reflect::reflect_ref(#ref_value) {
+                    if #bevy_reflect_path::Map::len(__map) != 1 {
+                        return #FQOption::None;
+                    }
+                    let (__key, __fields) = #bevy_reflect_path::Map::get_at(__map, 0)?;
+                    let #ref_value = #bevy_reflect_path::__macro_exports::VariantFields::new(__fields);
+                    match #bevy_reflect_path::__macro_exports::variant_name_from_key(__key)? {
+                        #(#variant_cfgs #variant_patterns => #fqoption::Some(#variant_constructors),)*
+                        _ => #FQOption::None,
+                    }
+                }
+            }
+        })
+    };
+
+    // Each variant result is an expression evaluating to the constructed `Option<Self>`
+    let build_from_reflect_body =
+        |variant_results: &[proc_macro2::TokenStream],
+         externally_tagged: &Option<proc_macro2::TokenStream>| {
+            let discriminant_fallback = (!has_catch_all).then(|| {
+                quote! {
+                    if let #FQResult::Ok(__discriminant) = #variant_name.parse::<#discriminant_ty>() {
+                        #(#variant_cfgs if __discriminant == #variant_discriminants {
+                            return #variant_results;
+                        })*
+                    }
+                }
+            });
+            quote! {
+                if let #bevy_reflect_path::ReflectRef::Enum(#ref_value) = #bevy_reflect_path::Reflect::reflect_ref(#ref_value) {
+                    match #variant_name_lookup {
+                        #(#variant_cfgs #variant_patterns => #variant_results,)*
+                        #(#ignored_variants)*
+                        #(#excluded_variants)*
+                        #variant_name => {
+                            #discriminant_fallback
+                            #unknown_variant
+                        }
+                    }
+                } #externally_tagged else {
+                    #FQOption::None
+                }
+            }
+        };
+
+    // Fields using `#[reflect(with = "...")]` may additionally be given a user-provided context
+    let from_reflect_with_context_impl = reflect_enum.meta().from_reflect().context().map(|context_ty| {
+        let context = Ident::new("__context", Span::call_site());
+        let EnumVariantOutputData {
+            mut variant_constructors,
+            ..
+        } = FromReflectVariantBuilder::with_context(reflect_enum, &context).build(&ref_value);
+        migrate_deprecated_variants(reflect_enum, &mut variant_constructors);
+
+        let variant_results = wrap_in_some(&variant_constructors);
+        let body = build_from_reflect_body(
+            &variant_results,
+            &build_externally_tagged(&variant_constructors),
+        );
+        quote! {
+            impl #impl_generics #enum_path #ty_generics #where_from_reflect_clause {
+                /// Constructs this enum from a reflected value the same way `FromReflect::from_reflect` does,
+                /// but passes `context` to the `from_reflect_with_context` function of every field
+                /// marked `#[reflect(with = "...")]` and constructs fields marked `#[reflect(apply_via = "downcast")]`
+                /// from the `TypeRegistry` it provides.
+                pub fn from_reflect_with_context(#ref_value: &dyn #bevy_reflect_path::Reflect, #context: &#context_ty) -> #FQOption<Self> {
+                    #body
+                }
+            }
+        }
+    });
+
+    let externally_tagged = build_externally_tagged(&variant_constructors);
+
+    // Each variant may be constructed by its own function to keep `from_reflect` small
+    let mut variant_results = wrap_in_some(&variant_constructors);
+    let split_variants_impl = if reflect_enum.meta().attrs().split_variants() {
+        let helpers = reflect_enum
+            .constructible_variants()
+            .map(|variant| format_ident!("__from_reflect_variant_{}", variant.data.ident))
+            .collect::<Vec<_>>();
+
+        let helper_impl = quote! {
+            impl #impl_generics #enum_path #ty_generics #where_from_reflect_clause {
+                #(
+                    #variant_cfgs
+                    #[allow(non_snake_case)]
+                    fn #helpers(#ref_value: &dyn #bevy_reflect_path::Enum) -> #fqoption<Self> {
+                        #fqoption::Some(#variant_constructors)
+                    }
+                )*
+            }
+        };
+
+        variant_results = helpers
+            .iter()
+            .map(|helper| quote!(Self::#helper(#ref_value)))
+            .collect();
+
+        Some(helper_impl)
+    } else {
+        None
+    };
+
+    let mut from_reflect_body = build_from_reflect_body(&variant_results, &externally_tagged);
+
+    // Reconstructing a value of `Self` must preserve its equality and hash, such as for map keys
+    if reflect_enum.meta().attrs().implements_hash_and_partial_eq() {
+        from_reflect_body = quote! {
+            let __output = (|| -> #FQOption<Self> { #from_reflect_body })();
+            if let #FQOption::Some(__output) = &__output {
+                #bevy_reflect_path::__macro_exports::verify_from_reflect(#ref_value, __output);
+            }
+            __output
+        };
+    }
+
     quote! {
         impl #impl_generics #bevy_reflect_path::FromReflect for #enum_path #ty_generics #where_from_reflect_clause  {
             fn from_reflect(#ref_value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<Self> {
+                #from_reflect_body
+            }
+        }
+
+        #split_variants_impl
+
+        #try_from_impl
+
+        #from_dynamic_enum_impl
+
+        #from_tag_impl
+
+        #from_reflect_with_context_impl
+    }
+}
+
+/// Replaces the constructor of each variant marked `#[reflect(deprecated(replaced_by = "..."))]`
+/// with the constructor of its replacement, as requested by `#[reflect(from_reflect(auto_migrate))]`.
+fn migrate_deprecated_variants(
+    reflect_enum: &ReflectEnum,
+    variant_constructors: &mut [proc_macro2::TokenStream],
+) {
+    if !reflect_enum.meta().from_reflect().should_auto_migrate() {
+        return;
+    }
+
+    // Deprecated variants are constructed as their replacement instead
+    let variants = reflect_enum.constructible_variants().collect::<Vec<_>>();
+    for (index, variant) in variants.iter().enumerate() {
+        let Some(replaced_by) = variant
+            .attrs
+            .deprecation
+            .as_ref()
+            .and_then(|deprecation| deprecation.replaced_by.as_ref())
+        else {
+            continue;
+        };
+
+        let replacement = reflect_enum
+            .variant_by_name(&replaced_by.value())
+            .expect("replacement variant should exist");
+        let replacement_index = variants
+            .iter()
+            .position(|other| other.index == replacement.index)
+            .expect("replacement variant should not be ignored or excluded");
+
+        variant_constructors[index] = variant_constructors[replacement_index].clone();
+    }
+}
+
+/// Wraps each variant constructor in `Some`, producing the `Option<Self>` returned for that variant.
+fn wrap_in_some(
+    variant_constructors: &[proc_macro2::TokenStream],
+) -> Vec<proc_macro2::TokenStream> {
+    let fqoption = FQOption.into_token_stream();
+    variant_constructors
+        .iter()
+        .map(|constructor| quote!(#fqoption::Some(#constructor)))
+        .collect()
+}
+
+/// Implements `FromReflect` for a type marked `#[reflect(transparent)]`,
+/// which is constructed around the value of its only field using `path`.
+fn impl_transparent(
+    meta: &ReflectMeta,
+    path: &proc_macro2::TokenStream,
+    field: &StructField,
+    where_clause_options: &WhereClauseOptions,
+) -> proc_macro2::TokenStream {
+    let type_path = meta.type_path();
+    let bevy_reflect_path = meta.bevy_reflect_path();
+
+    let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+    let field_ty = &field.data.ty;
+
+    let (impl_generics, ty_generics, where_clause) = type_path.generics().split_for_impl();
+
+    // Add FromReflect bound for the field
+    let where_from_reflect_clause = where_clause_options.extend_where_clause(where_clause);
+
+    quote! {
+        impl #impl_generics #bevy_reflect_path::FromReflect for #type_path #ty_generics #where_from_reflect_clause  {
+            fn from_reflect(__param0: &dyn #bevy_reflect_path::Reflect) -> #FQOption<Self> {
+                // Values of this type are unwrapped, since they are otherwise only seen as their field
+                let __param0 = match <dyn #FQAny>::downcast_ref::<Self>(#bevy_reflect_path::Reflect::as_any(__param0)) {
+                    #FQOption::Some(#path { #member: __field }) => __field as &dyn #bevy_reflect_path::Reflect,
+                    #FQOption::None => __param0,
+                };
+
+                #FQOption::Some(#path {
+                    #member: <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(__param0)?
+                })
+            }
+        }
+    }
+}
+
+/// Implements `TryFrom<&dyn Reflect>` for the given enum type.
+///
+/// Unlike `FromReflect::from_reflect`, this reports why the conversion failed
+/// using an `ApplyError` naming the offending variant and field.
+fn impl_enum_try_from(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let fqresult = FQResult.into_token_stream();
+
+    let enum_path = reflect_enum.meta().type_path();
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+
+    let ref_value = Ident::new("__param0", Span::call_site());
+
+    let EnumVariantOutputData {
+        variant_cfgs,
+        variant_patterns,
+        variant_constructors,
+        ..
+    } = build_try_apply_variants(reflect_enum, &ref_value, true);
+
+    let ignored_variants = build_ignored_variant_arms(reflect_enum, |name| {
+        quote! {
+            #FQResult::Err(#bevy_reflect_path::ApplyError::IgnoredVariant {
+                variant_name: ::core::convert::Into::into(#name),
+            })
+        }
+    });
+
+    // Variants excluded by `from_reflect(only = "...")` are never constructed
+    let excluded_variants = build_excluded_variant_arms(reflect_enum, |name| {
+        quote! {
+            #FQResult::Err(#bevy_reflect_path::ApplyError::ExcludedVariant {
+                variant_name: ::core::convert::Into::into(#name),
+            })
+        }
+    });
+
+    let variant_name = Ident::new("name", Span::call_site());
+    let unknown_variant = match build_catch_all(reflect_enum, &variant_name) {
+        Some(catch_all) => quote!(#fqresult::Ok(#catch_all)),
+        None => {
+            let suggestion = build_variant_suggestion(reflect_enum, &variant_name);
+            quote! {
+                #FQResult::Err(
+                    #bevy_reflect_path::ApplyError::UnknownVariant {
+                        enum_name: ::core::convert::Into::into(<Self as #bevy_reflect_path::TypePath>::type_path()),
+                        variant_name: ::core::convert::Into::into(#variant_name),
+                        suggestion: #suggestion,
+                    }
+                )
+            }
+        }
+    };
+
+    // `split_for_impl` leaves off any type parameter defaults, which are not allowed on impls,
+    // so the lifetime can be added to the declared generics as-is
+    let variant_name_lookup = build_variant_name_lookup(reflect_enum, &ref_value);
+
+    let mut generics = enum_path.generics().clone();
+    generics.params.insert(0, parse_quote!('__reflect));
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    let (_, ty_generics, where_clause) = enum_path.generics().split_for_impl();
+
+    let where_try_from_clause = reflect_enum
+        .where_clause_options()
+        .extend_where_clause(where_clause);
+
+    quote! {
+        impl #impl_generics ::core::convert::TryFrom<&'__reflect dyn #bevy_reflect_path::Reflect> for #enum_path #ty_generics #where_try_from_clause {
+            type Error = #bevy_reflect_path::ApplyError;
+
+            fn try_from(#ref_value: &'__reflect dyn #bevy_reflect_path::Reflect) -> #FQResult<Self, Self::Error> {
                 if let #bevy_reflect_path::ReflectRef::Enum(#ref_value) = #bevy_reflect_path::Reflect::reflect_ref(#ref_value) {
-                    match #bevy_reflect_path::Enum::variant_name(#ref_value) {
-                        #(#variant_names => #fqoption::Some(#variant_constructors),)*
-                        name => panic!("variant with name `{}` does not exist on enum `{}`", name, <Self as #bevy_reflect_path::TypePath>::type_path()),
+                    match #variant_name_lookup {
+                        #(#variant_cfgs #variant_patterns => #fqresult::Ok(#variant_constructors),)*
+                        #(#ignored_variants)*
+                        #(#excluded_variants)*
+                        #variant_name => #unknown_variant,
                     }
                 } else {
-                    #FQOption::None
+                    #FQResult::Err(
+                        #bevy_reflect_path::ApplyError::MismatchedKinds {
+                            from_kind: #bevy_reflect_path::Reflect::reflect_kind(#ref_value),
+                            to_kind: #bevy_reflect_path::ReflectKind::Enum,
+                        }
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Generates an inherent `from_dynamic_enum` function for the given enum type.
+///
+/// Unlike `FromReflect::from_reflect`, this reports why the conversion failed
+/// using a `FromReflectError` naming the offending variant and field.
+fn impl_enum_from_dynamic_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let fqresult = FQResult.into_token_stream();
+
+    let enum_path = reflect_enum.meta().type_path();
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+
+    let ref_value = Ident::new("__param0", Span::call_site());
+
+    let EnumVariantOutputData {
+        variant_cfgs,
+        variant_patterns,
+        variant_constructors,
+        ..
+    } = FromDynamicVariantBuilder::new(reflect_enum).build(&ref_value);
+
+    let ignored_variants = build_ignored_variant_arms(reflect_enum, |name| {
+        quote! {
+            #FQResult::Err(#bevy_reflect_path::FromReflectError::IgnoredVariant {
+                variant_name: ::core::convert::Into::into(#name),
+            })
+        }
+    });
+
+    // Variants excluded by `from_reflect(only = "...")` are never constructed
+    let excluded_variants = build_excluded_variant_arms(reflect_enum, |name| {
+        quote! {
+            #FQResult::Err(#bevy_reflect_path::FromReflectError::ExcludedVariant {
+                variant_name: ::core::convert::Into::into(#name),
+            })
+        }
+    });
+
+    let variant_name = Ident::new("name", Span::call_site());
+    let unknown_variant = match build_catch_all(reflect_enum, &variant_name) {
+        Some(catch_all) => quote!(#fqresult::Ok(#catch_all)),
+        None => quote! {
+            #FQResult::Err(
+                #bevy_reflect_path::FromReflectError::UnknownVariant {
+                    enum_name: ::core::convert::Into::into(<Self as #bevy_reflect_path::TypePath>::type_path()),
+                    variant_name: ::core::convert::Into::into(#variant_name),
+                }
+            )
+        },
+    };
+
+    let variant_name_lookup = build_variant_name_lookup(reflect_enum, &ref_value);
+
+    let (impl_generics, ty_generics, where_clause) = enum_path.generics().split_for_impl();
+
+    // Add FromReflect bound for each active field
+    let where_from_reflect_clause = reflect_enum
+        .where_clause_options()
+        .extend_where_clause(where_clause);
+
+    quote! {
+        impl #impl_generics #enum_path #ty_generics #where_from_reflect_clause {
+            /// Constructs this enum from the given `DynamicEnum`.
+            ///
+            /// Unlike `FromReflect::from_reflect`, this returns a `FromReflectError`
+            /// describing which variant or field could not be constructed, and why.
+            pub fn from_dynamic_enum(#ref_value: &#bevy_reflect_path::DynamicEnum) -> #FQResult<Self, #bevy_reflect_path::FromReflectError> {
+                let #ref_value: &dyn #bevy_reflect_path::Enum = #ref_value;
+                match #variant_name_lookup {
+                    #(#variant_cfgs #variant_patterns => #fqresult::Ok(#variant_constructors),)*
+                    #(#ignored_variants)*
+                    #(#excluded_variants)*
+                    #variant_name => #unknown_variant,
                 }
             }
         }
@@ -85,6 +536,15 @@ fn impl_struct_internal(
     reflect_struct: &ReflectStruct,
     is_tuple: bool,
 ) -> proc_macro2::TokenStream {
+    if reflect_struct.meta().attrs().transparent().is_some() {
+        return impl_transparent(
+            reflect_struct.meta(),
+            &reflect_struct.meta().type_path().to_token_stream(),
+            &reflect_struct.fields()[0],
+            &reflect_struct.where_clause_options(),
+        );
+    }
+
     let fqoption = FQOption.into_token_stream();
 
     let struct_path = reflect_struct.meta().type_path();
@@ -161,7 +621,7 @@ fn get_ignored_fields(reflect_struct: &ReflectStruct) -> MemberValuePair {
                 let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
 
                 let value = match &field.attrs.default {
-                    DefaultBehavior::Func(path) => quote! {#path()},
+                    DefaultBehavior::Func(path) => call_default_func(path, &field.data.ty),
                     _ => quote! {#FQDefault::default()},
                 };
 
@@ -200,15 +660,30 @@ fn get_active_fields(
                 };
 
                 let value = match &field.attrs.default {
-                    DefaultBehavior::Func(path) => quote! {
-                        (||
-                            if let #FQOption::Some(field) = #get_field {
-                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
-                            } else {
-                                #FQOption::Some(#path())
-                            }
-                        )
-                    },
+                    DefaultBehavior::Func(path) => {
+                        let default_value = call_default_func(path, &ty);
+                        quote! {
+                            (||
+                                if let #FQOption::Some(field) = #get_field {
+                                    <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                                } else {
+                                    #FQOption::Some(#default_value)
+                                }
+                            )
+                        }
+                    }
+                    DefaultBehavior::TryFunc(path) => {
+                        let default_value = call_default_func(path, quote!(#FQResult<#ty, _>));
+                        quote! {
+                            (||
+                                if let #FQOption::Some(field) = #get_field {
+                                    <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                                } else {
+                                    #FQResult::ok(#default_value)
+                                }
+                            )
+                        }
+                    }
                     DefaultBehavior::Default => quote! {
                         (||
                             if let #FQOption::Some(field) = #get_field {
@@ -218,7 +693,8 @@ fn get_active_fields(
                             }
                         )
                     },
-                    DefaultBehavior::Required => quote! {
+                    // Struct fields have no variant to take a default from
+                    DefaultBehavior::Required | DefaultBehavior::Variant => quote! {
                         (|| <#ty as #bevy_reflect_path::FromReflect>::from_reflect(#get_field?))
                     },
                 };
@@ -240,7 +716,7 @@ fn get_field_accessor(field: &Field, index: usize, is_tuple: bool) -> Lit {
         field
             .ident
             .as_ref()
-            .map(|ident| Lit::Str(LitStr::new(&ident.to_string(), Span::call_site())))
+            .map(|ident| Lit::Str(LitStr::new(&ident.unraw().to_string(), Span::call_site())))
             .unwrap_or_else(|| Lit::Str(LitStr::new(&index.to_string(), Span::call_site())))
     }
 }
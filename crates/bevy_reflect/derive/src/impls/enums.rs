@@ -1,12 +1,32 @@
+use crate::container_attributes::CommonField;
 use crate::derive_data::{EnumVariantFields, ReflectEnum, StructField};
-use crate::enum_utility::{EnumVariantOutputData, TryApplyVariantBuilder, VariantBuilder};
-use crate::impls::{impl_type_path, impl_typed};
+use crate::enum_utility::{
+    build_catch_all, build_field_rejection, build_ignored_variant_arms, build_try_apply_variants,
+    build_variant_name_lookup, build_variant_suggestion, cold_path, with_function,
+    DefaultVariantBuilder, EnumVariantOutputData, VariantBuilder,
+};
+use crate::field_attributes::{DefaultBehavior, MergePolicy};
+use crate::impls::{impl_transparent, impl_type_path, impl_typed};
+use crate::utility::{call_default_func, ident_or_index};
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQOption, FQResult};
 use proc_macro2::{Ident, Span};
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
 use syn::Fields;
 
 pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    if reflect_enum.meta().attrs().transparent().is_some() {
+        let variant = &reflect_enum.variants()[0];
+        let where_clause_options = reflect_enum.where_clause_options();
+        return impl_transparent(
+            reflect_enum.meta(),
+            &reflect_enum.get_unit(&variant.data.ident),
+            &variant.fields()[0],
+            &where_clause_options,
+            reflect_enum.get_type_registration(&where_clause_options),
+        );
+    }
+
     let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
     let enum_path = reflect_enum.meta().type_path();
 
@@ -18,20 +38,314 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
     let EnumImpls {
         enum_field,
+        enum_field_mut,
         enum_field_at,
+        enum_field_at_mut,
         enum_index_of,
         enum_name_at,
         enum_field_len,
         enum_variant_name,
         enum_variant_index,
         enum_variant_type,
+        enum_clone_dynamic,
     } = generate_impls(reflect_enum, &ref_index, &ref_name);
 
     let EnumVariantOutputData {
-        variant_names,
+        variant_cfgs,
+        variant_patterns,
         variant_constructors,
         ..
-    } = TryApplyVariantBuilder::new(reflect_enum).build(&ref_value);
+    } = build_try_apply_variants(reflect_enum, &ref_value, false);
+
+    let variant_name_lookup = build_variant_name_lookup(reflect_enum, &ref_value);
+    let cold_path = cold_path(reflect_enum);
+    let variant_name = Ident::new("name", Span::call_site());
+    let unknown_variant = match build_catch_all(reflect_enum, &variant_name) {
+        Some(catch_all) => quote! {
+            *self = #catch_all
+        },
+        None => {
+            let suggestion = build_variant_suggestion(reflect_enum, &variant_name);
+            quote! {
+                #cold_path
+                return #FQResult::Err(
+                    #bevy_reflect_path::ApplyError::UnknownVariant {
+                        enum_name: ::core::convert::Into::into(#bevy_reflect_path::DynamicTypePath::reflect_type_path(self)),
+                        variant_name: ::core::convert::Into::into(#variant_name),
+                        suggestion: #suggestion,
+                    }
+                );
+            }
+        }
+    };
+
+    // Ignored variants can neither be switched to nor have their fields applied
+    let ignored_variant_error = |name: &str| {
+        quote! {{
+            #cold_path
+            return #FQResult::Err(#bevy_reflect_path::ApplyError::IgnoredVariant {
+                variant_name: ::core::convert::Into::into(#name),
+            });
+        }}
+    };
+    let ignored_variants = build_ignored_variant_arms(reflect_enum, ignored_variant_error);
+    let reject_ignored_self = reflect_enum.ignored_variants().next().is_some().then(|| {
+        let arms = reflect_enum.ignored_variants().map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let unit = reflect_enum.get_unit(&variant.data.ident);
+            let error = ignored_variant_error(&variant.data.ident.to_string());
+            quote!(#(#cfg_attrs)* #unit{..} => #error)
+        });
+        quote! {
+            match self {
+                #(#arms)*
+                _ => {}
+            }
+        }
+    });
+
+    let collect_apply_errors = reflect_enum.meta().attrs().collect_apply_errors();
+    let apply_errors = Ident::new("__apply_errors", Span::call_site());
+    let extend_field = Ident::new("__extend_field", Span::call_site());
+    // Applies the given field `value` to the field `v` of `self`,
+    // extending rather than replacing it if `extend` is set and `#extend_field` holds
+    let apply_field = |value: proc_macro2::TokenStream, extend: bool| {
+        let try_apply_field = if reflect_enum.meta().attrs().coerce_numeric() {
+            quote!(#bevy_reflect_path::__macro_exports::try_apply_widened(v, #value))
+        } else {
+            quote!(#bevy_reflect_path::Reflect::try_apply(v, #value))
+        };
+        let try_apply_field = if extend {
+            quote! {
+                if #extend_field {
+                    #bevy_reflect_path::__macro_exports::try_apply_extend(v, #value)
+                } else {
+                    #try_apply_field
+                }
+            }
+        } else {
+            try_apply_field
+        };
+
+        if collect_apply_errors {
+            quote! {
+                if let #FQResult::Err(err) = #try_apply_field {
+                    #cold_path
+                    #apply_errors.push(err);
+                }
+            }
+        } else {
+            quote! {
+                #try_apply_field?;
+            }
+        }
+    };
+    // Whether the field is marked `#[reflect(apply = "extend")]` by the current variant,
+    // checked before the field is borrowed from `self`
+    let extend_fields = reflect_enum
+        .active_variants()
+        .flat_map(|variant| {
+            let variant_name = variant.data.ident.to_string();
+            variant
+                .active_fields()
+                .filter(|field| field.attrs.apply_extend.is_some())
+                .map(move |field| {
+                    let index = field.reflection_index.unwrap_or_default();
+                    let name = field.name.clone().unwrap_or_else(|| index.to_string());
+                    (
+                        quote!((#variant_name, #name)),
+                        quote!((#variant_name, #index)),
+                    )
+                })
+        })
+        .collect::<Vec<_>>();
+    let has_extend_fields = !extend_fields.is_empty();
+    let (check_extend_named_field, check_extend_unnamed_field) = if has_extend_fields {
+        let (named, unnamed): (Vec<_>, Vec<_>) = extend_fields.into_iter().unzip();
+        (
+            Some(quote! {
+                let #extend_field = ::core::matches!(
+                    (#bevy_reflect_path::Enum::variant_name(self), name),
+                    #(#named)|*
+                );
+            }),
+            Some(quote! {
+                let #extend_field = ::core::matches!(
+                    (#bevy_reflect_path::Enum::variant_name(self), index),
+                    #(#unnamed)|*
+                );
+            }),
+        )
+    } else {
+        (None, None)
+    };
+    let apply_variant_field = apply_field(quote!(field.value()), has_extend_fields);
+    // Fields the current variant does not reflect are only rejected when opted into
+    let reject_field = |field_name: proc_macro2::TokenStream,
+                        is_ignored: proc_macro2::TokenStream| {
+        let rejection = build_field_rejection(
+            reflect_enum,
+            &quote!(#bevy_reflect_path::Enum::variant_name(self)),
+            &quote!(::core::convert::Into::into(#field_name)),
+            &is_ignored,
+            |error| {
+                if collect_apply_errors {
+                    quote! {
+                        #cold_path
+                        #apply_errors.push(#error);
+                    }
+                } else {
+                    quote! {
+                        #cold_path
+                        return #FQResult::Err(#error);
+                    }
+                }
+            },
+        );
+
+        rejection.map(|rejection| {
+            quote! {
+                else {
+                    #rejection
+                }
+            }
+        })
+    };
+    // Whether the field is declared by the current variant but ignored by reflection
+    let ignored_named_fields = reflect_enum
+        .active_variants()
+        .flat_map(|variant| {
+            let variant_name = variant.data.ident.to_string();
+            variant
+                .fields()
+                .iter()
+                .filter(|field| field.attrs.ignore.is_ignored())
+                .filter_map(|field| field.name.as_deref())
+                .map(move |field_name| quote!((#variant_name, #field_name)))
+        })
+        .collect::<Vec<_>>();
+    let is_ignored_named_field = if ignored_named_fields.is_empty() {
+        quote!(false)
+    } else {
+        quote! {
+            ::core::matches!(
+                (#bevy_reflect_path::Enum::variant_name(self), name),
+                #(#ignored_named_fields)|*
+            )
+        }
+    };
+    let declared_unnamed_fields = reflect_enum
+        .active_variants()
+        .filter_map(|variant| match &variant.fields {
+            EnumVariantFields::Unnamed(fields)
+                if fields.iter().any(|field| field.attrs.ignore.is_ignored()) =>
+            {
+                let variant_name = variant.data.ident.to_string();
+                let declared_len = fields.len();
+                Some(quote!(#variant_name => #declared_len))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let is_ignored_unnamed_field = if declared_unnamed_fields.is_empty() {
+        quote!(false)
+    } else {
+        quote! {
+            index < match #bevy_reflect_path::Enum::variant_name(self) {
+                #(#declared_unnamed_fields,)*
+                _ => 0,
+            }
+        }
+    };
+    let reject_named_field = reject_field(quote!(name), is_ignored_named_field);
+
+    // Tuple variants may also be given as struct variants with fields named "0", "1", ...,
+    // so that only some of their fields need to be present
+    let has_tuple_variants = reflect_enum
+        .active_variants()
+        .any(|variant| matches!(variant.fields, EnumVariantFields::Unnamed(_)));
+    let named_field_mut = if has_tuple_variants {
+        quote! {
+            if #bevy_reflect_path::Enum::variant_type(self) == #bevy_reflect_path::VariantType::Tuple {
+                #FQOption::and_then(
+                    #FQResult::ok(::core::primitive::str::parse::<usize>(name)),
+                    |index| #bevy_reflect_path::Enum::field_at_mut(self, index),
+                )
+            } else {
+                #bevy_reflect_path::Enum::field_mut(self, name)
+            }
+        }
+    } else {
+        quote!(#bevy_reflect_path::Enum::field_mut(self, name))
+    };
+    let reject_unnamed_field = reject_field(
+        quote!(::std::format!(".{}", index)),
+        is_ignored_unnamed_field,
+    );
+
+    let (apply_errors_init, apply_errors_check) = if collect_apply_errors {
+        (
+            quote! {
+                let mut #apply_errors = ::std::vec::Vec::new();
+            },
+            quote! {
+                if !#apply_errors.is_empty() {
+                    #cold_path
+                    return #FQResult::Err(#bevy_reflect_path::ApplyError::Multiple(#apply_errors));
+                }
+            },
+        )
+    } else {
+        (quote!(), quote!())
+    };
+
+    // Structs are only applied to the fields of the active struct variant when opted into
+    let apply_struct = if reflect_enum.meta().attrs().apply_struct_to_variant() {
+        let apply_struct_field = apply_field(quote!(value), has_extend_fields);
+        Some(quote! {
+            else if let #bevy_reflect_path::ReflectRef::Struct(#ref_value) = #bevy_reflect_path::Reflect::reflect_ref(#ref_value) {
+                if #bevy_reflect_path::Enum::variant_type(self) != #bevy_reflect_path::VariantType::Struct {
+                    #cold_path
+                    return #FQResult::Err(
+                        #bevy_reflect_path::ApplyError::MismatchedKinds {
+                            from_kind: #bevy_reflect_path::ReflectKind::Struct,
+                            to_kind: #bevy_reflect_path::ReflectKind::Enum,
+                        }
+                    );
+                }
+
+                #apply_errors_init
+                for (index, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Struct::iter_fields(#ref_value)) {
+                    let name = #bevy_reflect_path::Struct::name_at(#ref_value, index).unwrap();
+                    #check_extend_named_field
+                    if let #FQOption::Some(v) = #bevy_reflect_path::Enum::field_mut(self, name) {
+                        #apply_struct_field
+                    } #reject_named_field
+                }
+                #apply_errors_check
+            }
+        })
+    } else {
+        None
+    };
+
+    // Other values are only applied to the field of the active newtype variant when opted into
+    let apply_scalar = if reflect_enum.meta().attrs().apply_scalar_to_newtype() {
+        let apply_scalar_field = apply_field(quote!(#ref_value), false);
+        Some(quote! {
+            else if #bevy_reflect_path::Enum::variant_type(self) == #bevy_reflect_path::VariantType::Tuple
+                && #bevy_reflect_path::Enum::field_len(self) == 1
+            {
+                #apply_errors_init
+                if let #FQOption::Some(v) = #bevy_reflect_path::Enum::field_at_mut(self, 0) {
+                    #apply_scalar_field
+                }
+                #apply_errors_check
+            }
+        })
+    } else {
+        None
+    };
 
     let hash_fn = reflect_enum
         .meta()
@@ -45,14 +359,17 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
             }
         });
     let debug_fn = reflect_enum.meta().attrs().get_debug_impl();
+    // A registered `PartialEq` is only usable for values of `Self`,
+    // so other values, such as a `DynamicEnum`, are still compared structurally
+    let structural_partial_eq = impl_structural_partial_eq(reflect_enum);
     let partial_eq_fn = reflect_enum
         .meta()
         .attrs()
-        .get_partial_eq_impl(bevy_reflect_path)
+        .get_partial_eq_impl_with_fallback(bevy_reflect_path, structural_partial_eq.clone())
         .unwrap_or_else(|| {
             quote! {
                 fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
-                    #bevy_reflect_path::enum_partial_eq(self, value)
+                    #structural_partial_eq
                 }
             }
         });
@@ -72,9 +389,65 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
     let where_reflect_clause = where_clause_options.extend_where_clause(where_clause);
 
+    let discriminant_names_impl = reflect_enum
+        .meta()
+        .attrs()
+        .discriminant_names()
+        .then(|| impl_discriminant_names(reflect_enum));
+
+    let variant_names_impl = reflect_enum
+        .meta()
+        .attrs()
+        .variant_names()
+        .then(|| impl_variant_names(reflect_enum));
+
+    let apply_counting_impl = reflect_enum
+        .meta()
+        .attrs()
+        .apply_counting()
+        .map(|_| impl_apply_counting(reflect_enum));
+
+    let merge_impl = reflect_enum
+        .meta()
+        .attrs()
+        .merge()
+        .map(|_| impl_merge(reflect_enum));
+
+    let repr_offsets_impl = reflect_enum
+        .meta()
+        .attrs()
+        .repr_offsets()
+        .map(|_| impl_repr_offsets(reflect_enum));
+
+    let default_variant_impl = reflect_enum
+        .meta()
+        .attrs()
+        .default_variant()
+        .map(|_| impl_default_variant(reflect_enum));
+
+    let common_field_impl = reflect_enum
+        .meta()
+        .attrs()
+        .common_field()
+        .map(|common_field| impl_common_field(reflect_enum, common_field));
+
     quote! {
         #get_type_registration_impl
 
+        #discriminant_names_impl
+
+        #variant_names_impl
+
+        #repr_offsets_impl
+
+        #apply_counting_impl
+
+        #merge_impl
+
+        #default_variant_impl
+
+        #common_field_impl
+
         #typed_impl
 
         #type_path_impl
@@ -96,14 +469,14 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             fn field_mut(&mut self, #ref_name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                  match self {
-                    #(#enum_field,)*
+                    #(#enum_field_mut,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at_mut(&mut self, #ref_index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match self {
-                    #(#enum_field_at,)*
+                    #(#enum_field_at_mut,)*
                     _ => #FQOption::None,
                 }
             }
@@ -159,7 +532,12 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
             }
 
             fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicEnum {
-                #bevy_reflect_path::DynamicEnum::from_ref::<Self>(self)
+                let mut dyn_enum = match self {
+                    #(#enum_clone_dynamic,)*
+                    _ => unreachable!(),
+                };
+                dyn_enum.set_represented_type(#bevy_reflect_path::Reflect::get_represented_type_info(self));
+                dyn_enum
             }
         }
 
@@ -212,44 +590,49 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn try_apply(&mut self, #ref_value: &dyn #bevy_reflect_path::Reflect) -> #FQResult<(), #bevy_reflect_path::ApplyError>  {
+                #reject_ignored_self
                 if let #bevy_reflect_path::ReflectRef::Enum(#ref_value) = #bevy_reflect_path::Reflect::reflect_ref(#ref_value) {
-                    if #bevy_reflect_path::Enum::variant_name(self) == #bevy_reflect_path::Enum::variant_name(#ref_value) {
+                    let __variant_name = #variant_name_lookup;
+                    if #bevy_reflect_path::Enum::variant_name(self) == __variant_name {
                         // Same variant -> just update fields
+                        #apply_errors_init
                         match #bevy_reflect_path::Enum::variant_type(#ref_value) {
                             #bevy_reflect_path::VariantType::Struct => {
                                 for field in #bevy_reflect_path::Enum::iter_fields(#ref_value) {
                                     let name = field.name().unwrap();
-                                    if let #FQOption::Some(v) = #bevy_reflect_path::Enum::field_mut(self, name) {
-                                       #bevy_reflect_path::Reflect::try_apply(v, field.value())?;
-                                    }
+                                    #check_extend_named_field
+                                    if let #FQOption::Some(v) = #named_field_mut {
+                                        #apply_variant_field
+                                    } #reject_named_field
                                 }
                             }
                             #bevy_reflect_path::VariantType::Tuple => {
                                 for (index, field) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Enum::iter_fields(#ref_value)) {
+                                    #check_extend_unnamed_field
                                     if let #FQOption::Some(v) = #bevy_reflect_path::Enum::field_at_mut(self, index) {
-                                        #bevy_reflect_path::Reflect::try_apply(v, field.value())?;
-                                    }
+                                        #apply_variant_field
+                                    } #reject_unnamed_field
                                 }
                             }
                             _ => {}
                         }
+                        #apply_errors_check
                     } else {
                         // New variant -> perform a switch
-                        match #bevy_reflect_path::Enum::variant_name(#ref_value) {
-                            #(#variant_names => {
+                        match __variant_name {
+                            #(#variant_cfgs #variant_patterns => {
                                 *self = #variant_constructors
                             })*
-                            name => {
-                                return #FQResult::Err(
-                                    #bevy_reflect_path::ApplyError::UnknownVariant {
-                                        enum_name: ::core::convert::Into::into(#bevy_reflect_path::DynamicTypePath::reflect_type_path(self)),
-                                        variant_name: ::core::convert::Into::into(name),
-                                    }
-                                );
+                            #(#ignored_variants)*
+                            // Variants are only ever matched by name, never by position,
+                            // so names from a newer version of this enum always end up here
+                            #variant_name => {
+                                #unknown_variant
                             }
                         }
                     }
-                } else {
+                } #apply_struct #apply_scalar else {
+                    #cold_path
                     return #FQResult::Err(
                         #bevy_reflect_path::ApplyError::MismatchedKinds {
                             from_kind: #bevy_reflect_path::Reflect::reflect_kind(#ref_value),
@@ -285,33 +668,450 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
     }
 }
 
+/// Generates an inherent `reflect_discriminant_names` function pairing each variant's
+/// discriminant with its name, skipping variants marked `#[reflect(hidden)]`.
+fn impl_discriminant_names(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let enum_path = reflect_enum.meta().type_path();
+    let (impl_generics, ty_generics, where_clause) = enum_path.generics().split_for_impl();
+
+    let discriminant_ty = reflect_enum.discriminant_ty();
+    let entries = reflect_enum
+        .variants()
+        .iter()
+        .filter(|variant| variant.attrs.hidden.is_none())
+        .map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let discriminant = variant.discriminant.to_tokens_as(&discriminant_ty);
+            let name = variant.data.ident.to_string();
+            quote!(#(#cfg_attrs)* (#discriminant, #name))
+        });
+
+    quote! {
+        impl #impl_generics #enum_path #ty_generics #where_clause {
+            /// Returns each variant's discriminant paired with its name, in declaration order.
+            ///
+            /// Hidden variants are not included.
+            pub fn reflect_discriminant_names() -> &'static [(#discriminant_ty, &'static str)] {
+                const DISCRIMINANT_NAMES: &[(#discriminant_ty, &str)] = &[#(#entries),*];
+                DISCRIMINANT_NAMES
+            }
+        }
+    }
+}
+
+/// Generates an inherent `reflect_variant_names` function listing the name of each variant
+/// as found in `EnumInfo::variant_names`, skipping variants marked `#[reflect(hidden)]`.
+fn impl_variant_names(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let enum_path = reflect_enum.meta().type_path();
+    let (impl_generics, ty_generics, where_clause) = enum_path.generics().split_for_impl();
+
+    let names = reflect_enum
+        .variants()
+        .iter()
+        .filter(|variant| variant.attrs.hidden.is_none())
+        .map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let name = variant.data.ident.to_string();
+            quote!(#(#cfg_attrs)* #name)
+        });
+
+    quote! {
+        impl #impl_generics #enum_path #ty_generics #where_clause {
+            /// Returns the name of each variant, in declaration order.
+            ///
+            /// These are the names used by serialization and listed by `EnumInfo::variant_names`,
+            /// so hidden variants are not included.
+            pub fn reflect_variant_names() -> &'static [&'static str] {
+                const VARIANT_NAMES: &[&str] = &[#(#names),*];
+                VARIANT_NAMES
+            }
+        }
+    }
+}
+
+/// Generates an inherent `apply_counting` method that applies a value like `Reflect::try_apply`,
+/// returning how many fields it changed.
+fn impl_apply_counting(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let enum_path = reflect_enum.meta().type_path();
+    let (impl_generics, ty_generics, where_clause) = enum_path.generics().split_for_impl();
+    let where_reflect_clause = reflect_enum
+        .where_clause_options()
+        .extend_where_clause(where_clause);
+
+    quote! {
+        impl #impl_generics #enum_path #ty_generics #where_reflect_clause {
+            /// Applies `value` to `self` like `Reflect::try_apply`, returning how many fields were changed.
+            ///
+            /// Fields are counted before they are applied, using `Reflect::reflect_partial_eq`,
+            /// so `Ok(0)` means the apply was a no-op.
+            /// Switching to another variant counts as a single change.
+            pub fn apply_counting(
+                &mut self,
+                value: &dyn #bevy_reflect_path::Reflect,
+            ) -> #FQResult<usize, #bevy_reflect_path::ApplyError> {
+                let changed = #bevy_reflect_path::__macro_exports::count_changed_fields(self, value);
+                #bevy_reflect_path::Reflect::try_apply(self, value)?;
+                #FQResult::Ok(changed)
+            }
+        }
+    }
+}
+
+/// Generates an inherent `reflect_merge` method that merges another value of the same variant
+/// into `self` field by field, following each field's `#[reflect(merge = "...")]` policy.
+fn impl_merge(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+    let enum_path = reflect_enum.meta().type_path();
+    let (impl_generics, ty_generics, where_clause) = enum_path.generics().split_for_impl();
+    let where_reflect_clause = reflect_enum
+        .where_clause_options()
+        .extend_where_clause(where_clause);
+
+    let variant_merges = reflect_enum.active_variants().filter_map(|variant| {
+        let field_merges = variant
+            .active_fields()
+            .filter_map(|field| {
+                let merge = match field.attrs.merge.map(|(policy, _)| policy) {
+                    None | Some(MergePolicy::Replace) => {
+                        quote!(#bevy_reflect_path::Reflect::try_apply(field, value)?;)
+                    }
+                    Some(MergePolicy::Keep) => return None,
+                    Some(MergePolicy::Combine) => {
+                        quote!(#bevy_reflect_path::__macro_exports::merge_option(field, value)?;)
+                    }
+                };
+                let (field_mut, other_field) = match &field.name {
+                    Some(name) => (
+                        quote!(#bevy_reflect_path::Enum::field_mut(self, #name)),
+                        quote!(#bevy_reflect_path::Enum::field(__other, #name)),
+                    ),
+                    None => {
+                        let index = field
+                            .reflection_index
+                            .expect("reflection index should exist for active field");
+                        (
+                            quote!(#bevy_reflect_path::Enum::field_at_mut(self, #index)),
+                            quote!(#bevy_reflect_path::Enum::field_at(__other, #index)),
+                        )
+                    }
+                };
+                Some(quote! {
+                    if let (#FQOption::Some(field), #FQOption::Some(value)) = (#field_mut, #other_field) {
+                        #merge
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        if field_merges.is_empty() {
+            return None;
+        }
+
+        let cfg_attrs = variant.cfg_attrs();
+        let variant_name = variant.data.ident.to_string();
+        Some(quote! {
+            #(#cfg_attrs)*
+            if #bevy_reflect_path::Enum::variant_name(self) == #variant_name {
+                #(#field_merges)*
+            }
+        })
+    });
+
+    quote! {
+        impl #impl_generics #enum_path #ty_generics #where_reflect_clause {
+            /// Merges `other` into `self` field by field.
+            ///
+            /// If `other` holds the same variant, each field is merged according to its
+            /// `#[reflect(merge = "...")]` policy, which defaults to replacing it.
+            /// Otherwise, `other` is applied like `Reflect::try_apply`, replacing the whole value.
+            pub fn reflect_merge(
+                &mut self,
+                other: &dyn #bevy_reflect_path::Reflect,
+            ) -> #FQResult<(), #bevy_reflect_path::ApplyError> {
+                let __other = match #bevy_reflect_path::Reflect::reflect_ref(other) {
+                    #bevy_reflect_path::ReflectRef::Enum(__other)
+                        if #bevy_reflect_path::Enum::variant_name(__other) == #bevy_reflect_path::Enum::variant_name(self) =>
+                    {
+                        __other
+                    }
+                    _ => return #bevy_reflect_path::Reflect::try_apply(self, other),
+                };
+                #(#variant_merges)*
+                #FQResult::Ok(())
+            }
+        }
+    }
+}
+
+/// Generates an inherent `reflect_field_offsets` function listing the byte offset of each
+/// reflected variant field within a `#[repr(C)]` enum.
+///
+/// Such an enum is laid out as a `#[repr(C)]` struct holding the tag followed by a `#[repr(C)]`
+/// union of one `#[repr(C)]` struct per variant, so this layout is mirrored by local items
+/// whose offsets are computed with `offset_of!`.
+fn impl_repr_offsets(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let enum_path = reflect_enum.meta().type_path();
+    let struct_ident = |variant: &Ident| format_ident!("__ReprVariant{}", variant);
+
+    let variant_structs = reflect_enum.variants().iter().map(|variant| {
+        let cfg_attrs = variant.cfg_attrs();
+        let ident = struct_ident(&variant.data.ident);
+        let types = variant.fields().iter().map(|field| &field.data.ty);
+        match &variant.fields {
+            EnumVariantFields::Named(fields) => {
+                let members = fields.iter().map(|field| &field.data.ident);
+                quote!(#(#cfg_attrs)* #[repr(C)] struct #ident { #(#members: #types,)* })
+            }
+            EnumVariantFields::Unnamed(_) => {
+                quote!(#(#cfg_attrs)* #[repr(C)] struct #ident(#(#types,)*);)
+            }
+            EnumVariantFields::Unit => quote!(#(#cfg_attrs)* #[repr(C)] struct #ident;),
+        }
+    });
+    let payload_fields = reflect_enum.variants().iter().map(|variant| {
+        let cfg_attrs = variant.cfg_attrs();
+        let variant_ident = &variant.data.ident;
+        let ident = struct_ident(variant_ident);
+        quote!(#(#cfg_attrs)* #variant_ident: ::core::mem::ManuallyDrop<#ident>,)
+    });
+
+    // Over-aligning the enum raises its size and alignment, but not the offsets of its fields
+    let align = reflect_enum.align().map(|align| quote!(, align(#align)));
+
+    // Without an integer repr, the tag has the size of the equivalent fieldless C enum
+    let (tag_def, tag_ty) = match reflect_enum.repr() {
+        Some(repr) => (None, quote!(#repr)),
+        None => {
+            let tag_variants = reflect_enum.variants().iter().map(|variant| {
+                let cfg_attrs = variant.cfg_attrs();
+                let variant_ident = &variant.data.ident;
+                let discriminant = variant
+                    .data
+                    .discriminant
+                    .as_ref()
+                    .map(|(_, expr)| quote!(= #expr));
+                quote!(#(#cfg_attrs)* #variant_ident #discriminant,)
+            });
+            (
+                Some(quote!(#[repr(C)] enum __ReprTag { #(#tag_variants)* })),
+                quote!(__ReprTag),
+            )
+        }
+    };
+
+    let entries = reflect_enum.active_variants().flat_map(|variant| {
+        let cfg_attrs = variant.cfg_attrs().collect::<Vec<_>>();
+        let variant_name = variant.data.ident.to_string();
+        let ident = struct_ident(&variant.data.ident);
+        variant
+            .active_fields()
+            .map(move |field| {
+                let name = field.name.clone().unwrap_or_else(|| {
+                    field
+                        .reflection_index
+                        .expect("reflection index should exist for active field")
+                        .to_string()
+                });
+                let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+                quote! {
+                    #(#cfg_attrs)* (#variant_name, #name, __PAYLOAD + ::core::mem::offset_of!(#ident, #member))
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    quote! {
+        impl #enum_path {
+            /// Returns the variant name, field name and byte offset of each reflected variant field,
+            /// in declaration order.
+            ///
+            /// Offsets are relative to the start of the enum and only point to a valid field
+            /// while the enum holds the named variant.
+            #[allow(dead_code, non_camel_case_types, non_snake_case)]
+            pub const fn reflect_field_offsets() -> &'static [(&'static str, &'static str, usize)] {
+                #tag_def
+                #(#variant_structs)*
+                #[repr(C)]
+                union __ReprPayload { #(#payload_fields)* }
+                #[repr(C #align)]
+                struct __Repr {
+                    tag: #tag_ty,
+                    payload: __ReprPayload,
+                }
+
+                // Offsets read through raw pointers are only sound if the mirror matches the enum exactly
+                const _: () = ::core::assert!(
+                    ::core::mem::size_of::<__Repr>() == ::core::mem::size_of::<#enum_path>()
+                        && ::core::mem::align_of::<__Repr>() == ::core::mem::align_of::<#enum_path>(),
+                    "the layout mirrored by `reflect_field_offsets` does not match the enum",
+                );
+
+                const __PAYLOAD: usize = ::core::mem::offset_of!(__Repr, payload);
+                const FIELD_OFFSETS: &[(&str, &str, usize)] = &[#(#entries),*];
+                FIELD_OFFSETS
+            }
+        }
+    }
+}
+
+/// Generates an inherent `reflect_common_field` method returning the field shared by every variant.
+///
+/// Each arm is spanned to its variant's field, so a field of the wrong type is reported there.
+fn impl_common_field(
+    reflect_enum: &ReflectEnum,
+    common_field: &CommonField,
+) -> proc_macro2::TokenStream {
+    let enum_path = reflect_enum.meta().type_path();
+    let (impl_generics, ty_generics, where_clause) = enum_path.generics().split_for_impl();
+
+    let CommonField { ident, ty } = common_field;
+    let arms = reflect_enum.variants().iter().map(|variant| {
+        let cfg_attrs = variant.cfg_attrs();
+        let variant_ident = &variant.data.ident;
+        let field = variant
+            .fields()
+            .iter()
+            .find(|field| field.data.ident.as_ref() == Some(ident))
+            .expect("variants should have been checked for the common field");
+        quote_spanned! {field.data.ty.span()=>
+            #(#cfg_attrs)* Self::#variant_ident { #ident: __common_field, .. } => __common_field
+        }
+    });
+    let doc = format!(" Returns the `{ident}` field shared by every variant.");
+
+    quote! {
+        impl #impl_generics #enum_path #ty_generics #where_clause {
+            #[doc = #doc]
+            pub fn reflect_common_field(&self) -> &#ty {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Generates an inherent `reflect_default_variant` function constructing a variant by name
+/// with all of its fields set to their defaults.
+fn impl_default_variant(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let enum_path = reflect_enum.meta().type_path();
+    let (impl_generics, ty_generics, where_clause) = enum_path.generics().split_for_impl();
+
+    let fqoption = FQOption.into_token_stream();
+
+    let ref_name = Ident::new("__name_param", Span::call_site());
+    let EnumVariantOutputData {
+        variant_cfgs,
+        variant_patterns,
+        variant_constructors,
+        ..
+    } = DefaultVariantBuilder::new(reflect_enum).build(&ref_name);
+
+    quote! {
+        impl #impl_generics #enum_path #ty_generics #where_clause {
+            /// Constructs the variant with the given name, with every field set to its default.
+            ///
+            /// Returns `None` if this enum has no variant with that name.
+            pub fn reflect_default_variant(#ref_name: &str) -> #fqoption<Self> {
+                match #ref_name {
+                    #(#variant_cfgs #variant_patterns => #fqoption::Some(#variant_constructors),)*
+                    _ => #fqoption::None,
+                }
+            }
+        }
+    }
+}
+
+/// Generates an expression comparing `self` to `value` field by field,
+/// as done by the default `Reflect::reflect_partial_eq` implementation.
+///
+/// Fields marked `#[reflect(eq = "ptr")]` are considered equal without being compared
+/// when both values point to the same data.
+fn impl_structural_partial_eq(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
+
+    let ptr_eq_arms = reflect_enum
+        .variants()
+        .iter()
+        .flat_map(|variant| {
+            let name = variant.data.ident.to_string();
+            let cfg = variant.cfg_attrs().collect::<Vec<_>>();
+            variant.active_fields().filter_map(move |field| {
+                field.attrs.ptr_eq?;
+                let index = field.reflection_index?;
+                let ty = &field.data.ty;
+                Some(quote! {
+                    #(#cfg)*
+                    (#name, #index) => {
+                        if let (#FQOption::Some(__a), #FQOption::Some(__b)) = (
+                            <dyn #bevy_reflect_path::Reflect>::downcast_ref::<#ty>(__a),
+                            <dyn #bevy_reflect_path::Reflect>::downcast_ref::<#ty>(__b),
+                        ) {
+                            if ::core::ptr::eq::<<#ty as ::core::ops::Deref>::Target>(
+                                ::core::ops::Deref::deref(__a),
+                                ::core::ops::Deref::deref(__b),
+                            ) {
+                                return #FQOption::Some(true);
+                            }
+                        }
+                    }
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if ptr_eq_arms.is_empty() {
+        return quote!(#bevy_reflect_path::enum_partial_eq(self, value));
+    }
+
+    quote! {
+        #bevy_reflect_path::enum_partial_eq_with(self, value, |__index, __a, __b| {
+            match (#bevy_reflect_path::Enum::variant_name(self), __index) {
+                #(#ptr_eq_arms)*
+                _ => {}
+            }
+            #bevy_reflect_path::Reflect::reflect_partial_eq(__b, __a)
+        })
+    }
+}
+
 struct EnumImpls {
     enum_field: Vec<proc_macro2::TokenStream>,
+    enum_field_mut: Vec<proc_macro2::TokenStream>,
     enum_field_at: Vec<proc_macro2::TokenStream>,
+    enum_field_at_mut: Vec<proc_macro2::TokenStream>,
     enum_index_of: Vec<proc_macro2::TokenStream>,
     enum_name_at: Vec<proc_macro2::TokenStream>,
     enum_field_len: Vec<proc_macro2::TokenStream>,
     enum_variant_name: Vec<proc_macro2::TokenStream>,
     enum_variant_index: Vec<proc_macro2::TokenStream>,
     enum_variant_type: Vec<proc_macro2::TokenStream>,
+    enum_clone_dynamic: Vec<proc_macro2::TokenStream>,
 }
 
 fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Ident) -> EnumImpls {
     let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
 
     let mut enum_field = Vec::new();
+    let mut enum_field_mut = Vec::new();
     let mut enum_field_at = Vec::new();
+    let mut enum_field_at_mut = Vec::new();
     let mut enum_index_of = Vec::new();
     let mut enum_name_at = Vec::new();
     let mut enum_field_len = Vec::new();
     let mut enum_variant_name = Vec::new();
     let mut enum_variant_index = Vec::new();
     let mut enum_variant_type = Vec::new();
+    let mut enum_clone_dynamic = Vec::new();
 
-    for (variant_index, variant) in reflect_enum.variants().iter().enumerate() {
+    for (declaration_index, variant) in reflect_enum.variants().iter().enumerate() {
         let ident = &variant.data.ident;
         let name = ident.to_string();
         let unit = reflect_enum.get_unit(ident);
+        let variant_index = reflect_enum.variant_index_tokens(declaration_index);
+        // Every arm must be gated behind the same `#[cfg]` attributes as the variant itself
+        let cfg = variant.cfg_attrs().collect::<Vec<_>>();
+        let cfg = quote!(#(#cfg)*);
 
         let variant_type_ident = match variant.data.fields {
             Fields::Unit => Ident::new("Unit", Span::call_site()),
@@ -320,13 +1120,13 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
         };
 
         enum_variant_name.push(quote! {
-            #unit{..} => #name
+            #cfg #unit{..} => #name
         });
         enum_variant_index.push(quote! {
-            #unit{..} => #variant_index
+            #cfg #unit{..} => #variant_index
         });
         enum_variant_type.push(quote! {
-            #unit{..} => #bevy_reflect_path::VariantType::#variant_type_ident
+            #cfg #unit{..} => #bevy_reflect_path::VariantType::#variant_type_ident
         });
 
         fn process_fields(
@@ -348,54 +1148,166 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             field_len
         }
 
+        // Fields marked `#[reflect(with = "...")]` are cloned by their module's `clone` function
+        let clone_field = |field: &StructField, alias: &Ident| match &field.attrs.with {
+            Some(module) => {
+                let clone = with_function(module, "clone");
+                quote!(#clone(#alias))
+            }
+            None if field.attrs.apply_via_downcast.is_some() => {
+                quote!(#bevy_reflect_path::Reflect::clone_value(&**#alias))
+            }
+            None => quote!(#bevy_reflect_path::Reflect::clone_value(#alias)),
+        };
+
+        // Fields with a default may be left out of `clone_dynamic` while they hold that default,
+        // since `FromReflect` restores them when missing
+        let default_field = |field: &StructField| {
+            if !reflect_enum.meta().attrs().omit_default_fields()
+                || field.attrs.with.is_some()
+                || field.attrs.apply_via_downcast.is_some()
+            {
+                return None;
+            }
+
+            let field_ty = &field.data.ty;
+            match &field.attrs.default {
+                DefaultBehavior::Default => {
+                    Some(quote!(<#field_ty as ::core::default::Default>::default()))
+                }
+                DefaultBehavior::Func(func) => Some(call_default_func(func, field_ty)),
+                DefaultBehavior::Required
+                | DefaultBehavior::TryFunc(_)
+                | DefaultBehavior::Variant => None,
+            }
+        };
+
+        // Boxed trait objects are accessed as the concrete value behind the box,
+        // so that it is applied to directly
+        let access_field = |field: &StructField, binding: &dyn ToTokens| {
+            if field.attrs.apply_via_downcast.is_some() {
+                (
+                    quote!(#bevy_reflect_path::Reflect::as_reflect(&**#binding)),
+                    quote!(#bevy_reflect_path::Reflect::as_reflect_mut(&mut **#binding)),
+                )
+            } else {
+                (quote!(#binding), quote!(#binding))
+            }
+        };
+
         match &variant.fields {
             EnumVariantFields::Unit => {
                 let field_len = process_fields(&[], |_| {});
 
                 enum_field_len.push(quote! {
-                    #unit{..} => #field_len
+                    #cfg #unit{..} => #field_len
+                });
+                enum_clone_dynamic.push(quote! {
+                    #cfg #unit{..} => #bevy_reflect_path::DynamicEnum::new_with_index(
+                        #variant_index,
+                        #name,
+                        #bevy_reflect_path::DynamicVariant::Unit,
+                    )
                 });
             }
             EnumVariantFields::Unnamed(fields) => {
+                let mut field_members = Vec::new();
+                let mut field_aliases = Vec::new();
+                let mut field_clones = Vec::new();
                 let field_len = process_fields(fields, |field: &StructField| {
                     let reflection_index = field
                         .reflection_index
                         .expect("reflection index should exist for active field");
 
                     let declare_field = syn::Index::from(field.declaration_index);
+                    let (value, value_mut) = access_field(field, &quote!(value));
                     enum_field_at.push(quote! {
-                        #unit { #declare_field : value, .. } if #ref_index == #reflection_index => #FQOption::Some(value)
+                        #cfg #unit { #declare_field : value, .. } if #ref_index == #reflection_index => #FQOption::Some(#value)
+                    });
+                    enum_field_at_mut.push(quote! {
+                        #cfg #unit { #declare_field : value, .. } if #ref_index == #reflection_index => #FQOption::Some(#value_mut)
                     });
+
+                    let field_alias = format_ident!("__field_{}", field.declaration_index);
+                    field_clones.push(clone_field(field, &field_alias));
+                    field_aliases.push(field_alias);
+                    field_members.push(declare_field);
                 });
 
                 enum_field_len.push(quote! {
-                    #unit{..} => #field_len
+                    #cfg #unit{..} => #field_len
+                });
+                enum_clone_dynamic.push(quote! {
+                    #cfg #unit{ #(#field_members: #field_aliases,)* .. } => {
+                        let mut data = #bevy_reflect_path::DynamicTuple::default();
+                        #(data.insert_boxed(#field_clones);)*
+                        #bevy_reflect_path::DynamicEnum::new_with_index(
+                            #variant_index,
+                            #name,
+                            #bevy_reflect_path::DynamicVariant::Tuple(data),
+                        )
+                    }
                 });
             }
             EnumVariantFields::Named(fields) => {
+                let mut field_idents = Vec::new();
+                let mut field_aliases = Vec::new();
+                let mut field_inserts = Vec::new();
                 let field_len = process_fields(fields, |field: &StructField| {
                     let field_ident = field.data.ident.as_ref().unwrap();
-                    let field_name = field_ident.to_string();
+                    let field_name = field.name.as_deref().unwrap();
                     let reflection_index = field
                         .reflection_index
                         .expect("reflection index should exist for active field");
 
+                    let (value, value_mut) = access_field(field, field_ident);
                     enum_field.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#field_ident)
+                        #cfg #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#value)
+                    });
+                    enum_field_mut.push(quote! {
+                        #cfg #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#value_mut)
                     });
                     enum_field_at.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_index == #reflection_index => #FQOption::Some(#field_ident)
+                        #cfg #unit{ #field_ident, .. } if #ref_index == #reflection_index => #FQOption::Some(#value)
+                    });
+                    enum_field_at_mut.push(quote! {
+                        #cfg #unit{ #field_ident, .. } if #ref_index == #reflection_index => #FQOption::Some(#value_mut)
                     });
                     enum_index_of.push(quote! {
-                        #unit{ .. } if #ref_name == #field_name => #FQOption::Some(#reflection_index)
+                        #cfg #unit{ .. } if #ref_name == #field_name => #FQOption::Some(#reflection_index)
                     });
                     enum_name_at.push(quote! {
-                        #unit{ .. } if #ref_index == #reflection_index => #FQOption::Some(#field_name)
+                        #cfg #unit{ .. } if #ref_index == #reflection_index => #FQOption::Some(#field_name)
                     });
+
+                    let field_alias = format_ident!("__field_{}", field.declaration_index);
+                    let field_clone = clone_field(field, &field_alias);
+                    let insert_field = quote!(data.insert_boxed(#field_name, #field_clone););
+                    field_inserts.push(match default_field(field) {
+                        Some(default_value) => quote! {
+                            if #bevy_reflect_path::Reflect::reflect_partial_eq(#field_alias, &#default_value) != #FQOption::Some(true) {
+                                #insert_field
+                            }
+                        },
+                        None => insert_field,
+                    });
+                    field_idents.push(field_ident.clone());
+                    field_aliases.push(field_alias);
                 });
 
                 enum_field_len.push(quote! {
-                    #unit{..} => #field_len
+                    #cfg #unit{..} => #field_len
+                });
+                enum_clone_dynamic.push(quote! {
+                    #cfg #unit{ #(#field_idents: #field_aliases,)* .. } => {
+                        let mut data = #bevy_reflect_path::DynamicStruct::default();
+                        #(#field_inserts)*
+                        #bevy_reflect_path::DynamicEnum::new_with_index(
+                            #variant_index,
+                            #name,
+                            #bevy_reflect_path::DynamicVariant::Struct(data),
+                        )
+                    }
                 });
             }
         };
@@ -403,12 +1315,15 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
 
     EnumImpls {
         enum_field,
+        enum_field_mut,
         enum_field_at,
+        enum_field_at_mut,
         enum_index_of,
         enum_name_at,
         enum_field_len,
         enum_variant_name,
         enum_variant_index,
         enum_variant_type,
+        enum_clone_dynamic,
     }
 }
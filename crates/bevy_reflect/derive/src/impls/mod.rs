@@ -1,11 +1,13 @@
 mod enums;
 mod structs;
+mod transparent;
 mod tuple_structs;
 mod typed;
 mod values;
 
 pub(crate) use enums::impl_enum;
 pub(crate) use structs::impl_struct;
+pub(crate) use transparent::impl_transparent;
 pub(crate) use tuple_structs::impl_tuple_struct;
 pub(crate) use typed::impl_type_path;
 pub(crate) use typed::impl_typed;
@@ -1,4 +1,4 @@
-use crate::impls::{impl_type_path, impl_typed};
+use crate::impls::{impl_transparent, impl_type_path, impl_typed};
 use crate::utility::ident_or_index;
 use crate::ReflectStruct;
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
@@ -6,6 +6,17 @@ use quote::{quote, ToTokens};
 
 /// Implements `Struct`, `GetTypeRegistration`, and `Reflect` for the given derive data.
 pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
+    if reflect_struct.meta().attrs().transparent().is_some() {
+        let where_clause_options = reflect_struct.where_clause_options();
+        return impl_transparent(
+            reflect_struct.meta(),
+            &reflect_struct.meta().type_path().to_token_stream(),
+            &reflect_struct.fields()[0],
+            &where_clause_options,
+            reflect_struct.get_type_registration(&where_clause_options),
+        );
+    }
+
     let fqoption = FQOption.into_token_stream();
 
     let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
@@ -15,10 +26,8 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
         .active_fields()
         .map(|field| {
             field
-                .data
-                .ident
-                .as_ref()
-                .map(|i| i.to_string())
+                .name
+                .clone()
                 .unwrap_or_else(|| field.declaration_index.to_string())
         })
         .collect::<Vec<String>>();
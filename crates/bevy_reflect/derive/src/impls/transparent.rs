@@ -0,0 +1,199 @@
+use crate::derive_data::{ReflectMeta, StructField};
+use crate::impls::{impl_type_path, impl_typed};
+use crate::utility::{ident_or_index, WhereClauseOptions};
+use bevy_macro_utils::fq_std::{FQAny, FQBox, FQOption, FQResult};
+use proc_macro2::{Ident, Span};
+use quote::quote;
+
+/// Implements `GetTypeRegistration` and `Reflect` for a type marked `#[reflect(transparent)]`.
+///
+/// Such a type has a single field, or a single variant with a single field for enums,
+/// and is reflected as that field.
+/// It does not implement `Struct`, `TupleStruct`, or `Enum`.
+///
+/// `path` is the path used to construct the type around its field,
+/// such as `Wrapper` for a struct or `Wrapper::Variant` for an enum.
+pub(crate) fn impl_transparent(
+    meta: &ReflectMeta,
+    path: &proc_macro2::TokenStream,
+    field: &StructField,
+    where_clause_options: &WhereClauseOptions,
+    get_type_registration_impl: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    let type_path = meta.type_path();
+    let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+
+    // Binds the only field, which is irrefutable
+    let pattern = |name: &str| {
+        let ident = Ident::new(name, Span::call_site());
+        quote!(#path { #member: #ident })
+    };
+    let this = pattern("__this");
+    let other = pattern("__other");
+
+    // Values of this type are unwrapped, so they can be given to the field like any other value
+    let unwrap_value = quote! {
+        let __value = match <dyn #FQAny>::downcast_ref::<Self>(#bevy_reflect_path::Reflect::as_any(__value)) {
+            #FQOption::Some(#other) => __other as &dyn #bevy_reflect_path::Reflect,
+            #FQOption::None => __value,
+        };
+    };
+
+    let hash_fn = meta
+        .attrs()
+        .get_hash_impl(bevy_reflect_path)
+        .unwrap_or_else(|| {
+            quote! {
+                fn reflect_hash(&self) -> #FQOption<u64> {
+                    let #this = self;
+                    #bevy_reflect_path::Reflect::reflect_hash(__this)
+                }
+            }
+        });
+    let partial_eq_fn = meta
+        .attrs()
+        .get_partial_eq_impl(bevy_reflect_path)
+        .unwrap_or_else(|| {
+            quote! {
+                fn reflect_partial_eq(&self, __value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
+                    let #this = self;
+                    #unwrap_value
+                    #bevy_reflect_path::Reflect::reflect_partial_eq(__this, __value)
+                }
+            }
+        });
+    let debug_fn = meta.attrs().get_debug_impl().unwrap_or_else(|| {
+        quote! {
+            fn debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let #this = self;
+                #bevy_reflect_path::Reflect::debug(__this, f)
+            }
+        }
+    });
+
+    #[cfg(feature = "documentation")]
+    let with_docs = {
+        let doc = quote::ToTokens::to_token_stream(meta.doc());
+        Some(quote!(.with_docs(#doc)))
+    };
+    #[cfg(not(feature = "documentation"))]
+    let with_docs: Option<proc_macro2::TokenStream> = None;
+
+    // Instances report the type info of their field, so this is only seen through the type registry
+    let typed_impl = impl_typed(
+        meta,
+        where_clause_options,
+        quote! {
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs;
+            #bevy_reflect_path::TypeInfo::Value(info)
+        },
+    );
+
+    let type_path_impl = impl_type_path(meta);
+
+    let (impl_generics, ty_generics, where_clause) = meta.type_path().generics().split_for_impl();
+
+    let where_reflect_clause = where_clause_options.extend_where_clause(where_clause);
+
+    quote! {
+        #get_type_registration_impl
+
+        #typed_impl
+
+        #type_path_impl
+
+        impl #impl_generics #bevy_reflect_path::Reflect for #type_path #ty_generics #where_reflect_clause {
+            #[inline]
+            fn get_represented_type_info(&self) -> #FQOption<&'static #bevy_reflect_path::TypeInfo> {
+                let #this = self;
+                #bevy_reflect_path::Reflect::get_represented_type_info(__this)
+            }
+
+            #[inline]
+            fn into_any(self: #FQBox<Self>) -> #FQBox<dyn #FQAny> {
+                self
+            }
+
+            #[inline]
+            fn as_any(&self) -> &dyn #FQAny {
+                self
+            }
+
+            #[inline]
+            fn as_any_mut(&mut self) -> &mut dyn #FQAny {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: #FQBox<Self>) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+                self
+            }
+
+            #[inline]
+            fn as_reflect(&self) -> &dyn #bevy_reflect_path::Reflect {
+                self
+            }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn #bevy_reflect_path::Reflect {
+                self
+            }
+
+            #[inline]
+            fn clone_value(&self) -> #FQBox<dyn #bevy_reflect_path::Reflect> {
+                let #this = self;
+                #bevy_reflect_path::Reflect::clone_value(__this)
+            }
+
+            #[inline]
+            fn try_apply(&mut self, __value: &dyn #bevy_reflect_path::Reflect) -> #FQResult<(), #bevy_reflect_path::ApplyError> {
+                let #this = self;
+                #unwrap_value
+                #bevy_reflect_path::Reflect::try_apply(__this, __value)
+            }
+
+            #[inline]
+            fn set(&mut self, __value: #FQBox<dyn #bevy_reflect_path::Reflect>) -> #FQResult<(), #FQBox<dyn #bevy_reflect_path::Reflect>> {
+                *self = <dyn #bevy_reflect_path::Reflect>::take(__value)?;
+                #FQResult::Ok(())
+            }
+
+            #[inline]
+            fn reflect_kind(&self) -> #bevy_reflect_path::ReflectKind {
+                let #this = self;
+                #bevy_reflect_path::Reflect::reflect_kind(__this)
+            }
+
+            #[inline]
+            fn reflect_ref(&self) -> #bevy_reflect_path::ReflectRef {
+                let #this = self;
+                #bevy_reflect_path::Reflect::reflect_ref(__this)
+            }
+
+            #[inline]
+            fn reflect_mut(&mut self) -> #bevy_reflect_path::ReflectMut {
+                let #this = self;
+                #bevy_reflect_path::Reflect::reflect_mut(__this)
+            }
+
+            #[inline]
+            fn reflect_owned(self: #FQBox<Self>) -> #bevy_reflect_path::ReflectOwned {
+                let #this = *self;
+                #bevy_reflect_path::Reflect::reflect_owned(#FQBox::new(__this))
+            }
+
+            #[inline]
+            fn serializable(&self) -> #FQOption<#bevy_reflect_path::serde::Serializable> {
+                let #this = self;
+                #bevy_reflect_path::Reflect::serializable(__this)
+            }
+
+            #hash_fn
+
+            #partial_eq_fn
+
+            #debug_fn
+        }
+    }
+}
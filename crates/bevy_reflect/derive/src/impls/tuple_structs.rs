@@ -1,4 +1,4 @@
-use crate::impls::{impl_type_path, impl_typed};
+use crate::impls::{impl_transparent, impl_type_path, impl_typed};
 use crate::ReflectStruct;
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
 use quote::{quote, ToTokens};
@@ -6,6 +6,17 @@ use syn::{Index, Member};
 
 /// Implements `TupleStruct`, `GetTypeRegistration`, and `Reflect` for the given derive data.
 pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenStream {
+    if reflect_struct.meta().attrs().transparent().is_some() {
+        let where_clause_options = reflect_struct.where_clause_options();
+        return impl_transparent(
+            reflect_struct.meta(),
+            &reflect_struct.meta().type_path().to_token_stream(),
+            &reflect_struct.fields()[0],
+            &where_clause_options,
+            reflect_struct.get_type_registration(&where_clause_options),
+        );
+    }
+
     let fqoption = FQOption.into_token_stream();
 
     let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
@@ -151,13 +151,22 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 ///   `(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result`.
 /// * `#[reflect(PartialEq)]` will force the implementation of `Reflect::reflect_partial_eq` to rely on
 ///   the type's [`PartialEq`] implementation.
+///   For enums, values that are not `Self`, such as a `DynamicEnum`, are still compared field by field.
 ///   A custom implementation may be provided using `#[reflect(PartialEq(my_partial_eq_func))]` where
 ///   `my_partial_eq_func` is the path to a function matching the signature:
 ///   `(&self, value: &dyn #bevy_reflect_path::Reflect) -> bool`.
 /// * `#[reflect(Hash)]` will force the implementation of `Reflect::reflect_hash` to rely on
 ///   the type's [`Hash`] implementation.
+///   The value is hashed with `Hash::hash` (after its `TypeId`) rather than field-by-field,
+///   so for enums the result agrees with [`Hash`] for every variant, including ignored fields.
 ///   A custom implementation may be provided using `#[reflect(Hash(my_hash_func))]` where
 ///   `my_hash_func` is the path to a function matching the signature: `(&self) -> u64`.
+///
+///   For enums registering both `#[reflect(Hash)]` and `#[reflect(PartialEq)]` without custom functions,
+///   the generated `FromReflect` implementation also checks that reconstructing a value of `Self`
+///   produces an equal value with the same hash, as required when the enum is used as a map key.
+///   This check panics on a mismatch, and is only performed in debug builds
+///   with the `verify_from_reflect` feature of `bevy_reflect` enabled.
 /// * `#[reflect(Default)]` will register the `ReflectDefault` type data as normal.
 ///   However, it will also affect how certain other operations are performed in order
 ///   to improve performance and/or robustness.
@@ -185,6 +194,398 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 ///
 /// Note that in the latter case, `ReflectFromReflect` will no longer be automatically registered.
 ///
+/// ## `#[reflect(from_reflect(auto_migrate))]`
+///
+/// This attribute makes the generated `FromReflect` implementation for an enum construct
+/// variants marked `#[reflect(deprecated(replaced_by = "..."))]` as their replacement variant.
+///
+/// ## `#[reflect(from_reflect(default))]`
+///
+/// This attribute makes every field without its own `#[reflect(default)]` or `#[reflect(try_default)]`
+/// attribute fall back to [`Default`] when it is missing, as if it were marked `#[reflect(default)]`.
+/// This applies to the fields of structs and of every enum variant, wherever they are constructed from reflected data.
+/// A field's own default attribute still takes precedence.
+///
+/// For enums, the indices of the fields that are still left without a default
+/// are listed by `VariantInfo::required_fields`, so that tools can tell which fields must be provided.
+///
+/// ## `#[reflect(from_reflect(default_options))]`
+///
+/// This attribute works like `#[reflect(from_reflect(default))]`, but only for fields whose type is
+/// (syntactically) an `Option`, such as `Option<T>` or `core::option::Option<T>`.
+/// A missing `Option` field without its own default attribute becomes `None`, as it would with serde,
+/// while other fields are still required.
+/// A field's own default attribute still takes precedence.
+///
+/// ## `#[reflect(from_reflect(case_insensitive))]`
+///
+/// This attribute makes the generated `FromReflect`, `Reflect::try_apply`, `TryFrom<&dyn Reflect>`
+/// and `from_dynamic_enum` implementations for an enum accept variant names that differ only in
+/// ASCII case, such as `"walking"` for a variant named `Walking`, which helps with human-authored data.
+/// An exact match of a variant name or alias is always preferred.
+///
+/// ## `#[reflect(from_reflect(context = ...))]`
+///
+/// This attribute generates an inherent
+/// `from_reflect_with_context(value: &dyn Reflect, context: &T) -> Option<Self>` function for an enum,
+/// where `T` is the given context type, such as a handle to an asset server.
+///
+/// It constructs the enum the same way `FromReflect` does, except that fields marked
+/// `#[reflect(with = "...")]` are constructed by the module's `from_reflect_with_context` function,
+/// which is given the context.
+/// If the context implements `AsRef<TypeRegistry>`, fields marked `#[reflect(apply_via = "downcast")]`
+/// are also constructed, by looking up their concrete type in that registry.
+/// The standard `FromReflect` implementation is still generated and keeps calling the module's `from_reflect` function,
+/// so both functions must be provided.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(from_reflect(context = AssetServer))]
+/// enum Material {
+///   Textured(#[reflect(with = "texture_reflect")] Handle<Image>),
+///   Color(Color),
+/// }
+///
+/// let material = Material::from_reflect_with_context(&value, &asset_server);
+/// ```
+///
+/// ## `#[reflect(from_reflect(only = "..."))]`
+///
+/// This attribute restricts which variants of an enum can be constructed from reflected data
+/// to the comma-separated list of variant names it is given.
+///
+/// The generated `FromReflect` implementation returns `None` for any other variant without generating code to construct it,
+/// as do the `from_reflect_with_context` and `from_tag` functions.
+/// The generated `TryFrom<&dyn Reflect>` implementation and `from_dynamic_enum` function
+/// return an `ExcludedVariant` error for them instead.
+/// This ensures those variants can never be created from untrusted data.
+/// `Reflect::try_apply` is not affected, since it applies to an existing value.
+///
+/// Each listed variant must exist and must not be ignored.
+/// The `#[reflect(catch_all)]` variant and the replacement of any variant migrated by
+/// `#[reflect(from_reflect(auto_migrate))]` must also be listed.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(from_reflect(only = "Guest, Member"))]
+/// enum Role {
+///   Guest,
+///   Member { id: u32 },
+///   Admin { id: u32 },
+/// }
+/// ```
+///
+/// ## `#[reflect(from_reflect(externally_tagged))]`
+///
+/// This attribute allows the generated `FromReflect` implementation of an enum to also construct it
+/// from an externally tagged value, as used by formats such as JSON:
+/// a map with a single entry whose key is the variant name and whose value holds the variant's fields.
+///
+/// The key may be a `String` or a `&'static str` holding the variant's name or one of its aliases.
+/// The fields may be given as a struct, tuple struct or tuple, and a unit variant may be given any value, such as `()`.
+/// Maps with any other number of entries and unknown variant names yield `None`.
+/// The `from_reflect_with_context` function generated by `#[reflect(from_reflect(context = ...))]` accepts the same values.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(from_reflect(externally_tagged))]
+/// enum Shape {
+///   Circle { radius: f32 },
+///   Rect(f32, f32),
+/// }
+///
+/// let mut fields = DynamicStruct::default();
+/// fields.insert("radius", 1.0_f32);
+/// let mut value = DynamicMap::default();
+/// value.insert(String::from("Circle"), fields);
+/// let shape = Shape::from_reflect(&value);
+/// ```
+///
+/// ## `#[reflect(try_from)]`
+///
+/// This attribute additionally generates a `TryFrom<&dyn Reflect>` implementation for an enum.
+///
+/// It constructs the enum the same way `FromReflect` does, but fails with an `ApplyError`
+/// describing the variant and field that could not be constructed rather than returning `None`.
+/// Since it is generated alongside `FromReflect`, it cannot be combined with `#[reflect(from_reflect = false)]`.
+///
+/// ## `#[reflect(from_dynamic_enum)]`
+///
+/// This attribute generates an inherent
+/// `from_dynamic_enum(value: &DynamicEnum) -> Result<Self, FromReflectError>` function for an enum.
+///
+/// It constructs the enum the same way `FromReflect` does, but fails with a `FromReflectError`
+/// describing the unknown variant, or the variant and field that was missing or mismatched,
+/// rather than returning `None`.
+///
+/// ## `#[reflect(discriminant_names)]`
+///
+/// This attribute generates an inherent `reflect_discriminant_names` function for an enum,
+/// returning a `&'static [(T, &'static str)]` that pairs each variant's discriminant
+/// (explicit or implicit) with its name.
+/// `T` is the integer type given by the enum's `#[repr(...)]` attribute, or `i64` if there is none.
+///
+/// ## `#[reflect(variant_names)]`
+///
+/// This attribute generates an inherent `reflect_variant_names` function for an enum,
+/// returning a `&'static [&'static str]` with the name of each variant in declaration order.
+/// This allows tools to list the variants without a `TypeRegistry` or `TypeInfo`.
+/// The names are the same ones used by serialization and listed by `EnumInfo::variant_names`,
+/// so variants marked `#[reflect(hidden)]` are left out, while aliases are not listed.
+///
+/// ## `#[reflect(common_field(name: Type))]`
+///
+/// This attribute generates an inherent `reflect_common_field(&self) -> &Type` method for an enum,
+/// returning the field called `name` of whichever variant is active.
+/// Every variant, including ignored ones, must be a struct variant with a field of that name,
+/// or the derive fails. A field of a different type is reported as a type error on that field.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(common_field(id: u32))]
+/// enum Entity {
+///   Player { id: u32, name: String },
+///   Monster { id: u32, health: f32 },
+/// }
+/// ```
+///
+/// ## `#[reflect(from_tag)]`
+///
+/// This attribute generates an inherent `from_tag(tag: T, fields: &dyn Reflect) -> Option<Self>`
+/// function for an enum with an integer `#[repr(T)]`, such as `#[repr(u8)]` or `#[repr(i128)]`,
+/// alongside its `FromReflect` implementation.
+///
+/// It constructs the variant whose discriminant is `tag` the same way `FromReflect` does,
+/// taking its fields from `fields`, which may be a struct, tuple struct, tuple or enum.
+/// Since the variant is found by its discriminant, no variant names are compared,
+/// which makes it well suited to compact formats keyed by a numeric tag.
+///
+/// ## `#[reflect(repr_offsets)]`
+///
+/// This attribute generates an inherent `const fn reflect_field_offsets()` for a `#[repr(C)]` enum,
+/// including ones with an integer tag such as `#[repr(C, u8)]` or an alignment such as `#[repr(C, align(16))]`.
+/// It returns a `&'static [(&'static str, &'static str, usize)]` holding the variant name,
+/// field name and byte offset of each reflected variant field, so that performance-critical
+/// tools can read and write fields directly instead of going through `Enum::field`.
+/// Field names are the ones used by reflection, so tuple fields are named by their index, like `"0"`.
+///
+/// The offsets are computed with `core::mem::offset_of!`, which requires Rust 1.77 or later.
+/// The derive fails on enums without `#[repr(C)]`, whose layout is unspecified,
+/// and on generic enums.
+/// The layout the offsets are computed from is checked against the enum's size and alignment at compile time.
+///
+/// ### Safety
+///
+/// Getting the offsets is safe, but using them is not.
+/// An offset only points to a valid field while the enum holds the variant it was listed for,
+/// so the active variant must be checked first, for example with `Enum::variant_name`.
+/// The pointer must then be cast to the field's exact type, and writes must keep the value valid
+/// for that type, since no reflection checks take place.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(repr_offsets)]
+/// #[repr(C)]
+/// enum Shape {
+///   Circle { radius: f32 },
+///   Rect(f32, f32),
+/// }
+///
+/// let shape = Shape::Circle { radius: 1.0 };
+/// let (_, _, offset) = Shape::reflect_field_offsets()[0];
+/// // SAFETY: `shape` holds the `Circle` variant, whose `radius` field is an `f32` at `offset`
+/// let radius = unsafe { (&shape as *const Shape).cast::<u8>().add(offset).cast::<f32>().read() };
+/// ```
+///
+/// ## `#[reflect(transparent)]`
+///
+/// This attribute makes a struct with a single field, or an enum with a single variant containing
+/// a single field, reflect as that field, so the type itself is invisible in its reflected representation.
+/// For example, the `ReflectRef`, `Reflect::clone_value` and `Reflect::get_represented_type_info`
+/// of such a type are those of its field, and `Reflect::try_apply` and `FromReflect`
+/// accept any value the field accepts.
+/// The type does not implement `Struct`, `TupleStruct`, or `Enum`.
+///
+/// This pairs well with `#[repr(transparent)]` newtypes, such as a wrapper around an enum,
+/// whose reflected data is then identical to that of the wrapped value.
+///
+/// Using this attribute on a struct with multiple fields, or an enum with multiple variants or fields,
+/// is a compile error.
+///
+/// ## `#[reflect(collect_apply_errors)]`
+///
+/// By default, the generated `Reflect::try_apply` implementation for an enum returns
+/// as soon as a single field fails to apply.
+/// This attribute makes it keep going, and report every failing field together
+/// as an `ApplyError::Multiple`.
+/// This also applies to the `TryFrom` implementation generated by `#[reflect(try_from)]`.
+///
+/// The errors are only allocated once a field has actually failed,
+/// so successful applies remain allocation-free.
+///
+/// ## `#[reflect(strict_apply)]`
+///
+/// By default, the generated `Reflect::try_apply` implementation for an enum silently drops
+/// any field of the applied value that the target variant does not reflect,
+/// such as a field marked `#[reflect(ignore)]` or one the variant does not have at all.
+/// This attribute instead returns an `ApplyError::IgnoredFieldPresent` naming the variant and field,
+/// which helps catch data authored against an incompatible version of the type.
+/// This also applies to the `TryFrom` implementation generated by `#[reflect(try_from)]`.
+///
+/// ## `#[reflect(deny_unknown_fields)]`
+///
+/// Similar to serde's attribute of the same name, this makes the generated `Reflect::try_apply`
+/// implementation for an enum return an `ApplyError::UnknownField` when the applied value
+/// contains a field the target variant does not declare.
+/// Fields marked `#[reflect(ignore)]` are still declared, so they continue to be dropped,
+/// unless `#[reflect(strict_apply)]` is also used.
+/// This also applies to the `TryFrom` implementation generated by `#[reflect(try_from)]`.
+///
+/// ## `#[reflect(omit_default_fields)]`
+///
+/// By default, `Enum::clone_dynamic` (and so `Reflect::clone_value`) includes every reflected field.
+/// With this attribute, a struct variant's field with a `#[reflect(default)]`
+/// or `#[reflect(default = "...")]` attribute is left out while it is equal to that default,
+/// as determined by `Reflect::reflect_partial_eq`.
+/// The resulting `DynamicEnum` is a minimal representation, such as for scene overrides,
+/// which the generated `FromReflect` implementation turns back into the same value.
+///
+/// Note that applying such a value to the same variant only overwrites the fields it contains,
+/// so omitted fields keep their current values rather than being reset to their defaults.
+/// Fields of tuple variants are never omitted, as they are identified by their position.
+///
+/// ## `#[reflect(debug_from_reflect)]`
+///
+/// By default, the generated `FromReflect::from_reflect` implementation for an enum
+/// silently returns `None` when one of a variant's fields fails to convert.
+/// With this attribute, the failing variant and field are logged with `tracing::debug!` first,
+/// provided the `debug_from_reflect` feature of `bevy_reflect` is enabled.
+/// Types without this attribute are unaffected.
+///
+/// ## `#[reflect(coerce_numeric)]`
+///
+/// By default, the generated `Reflect::try_apply` implementation for an enum
+/// fails with `ApplyError::MismatchedTypes` when a field is given a different numeric type.
+/// This attribute allows such a field to be widened into its declared type first,
+/// so a scene authored with an `i32` can still be applied to a field since widened to `i64`.
+/// Only lossless widenings (such as `i32` to `i64` or `f32` to `f64`) are performed;
+/// lossy conversions still fail.
+///
+/// ## `#[reflect(apply_struct_to_variant)]`
+///
+/// By default, the generated `Reflect::try_apply` implementation for an enum only accepts
+/// other enums, so a `DynamicStruct` fails with `ApplyError::MismatchedKinds`.
+/// This attribute instead applies the struct's fields by name to the fields of the
+/// currently active variant, which must be a struct variant.
+/// The variant itself is never changed, since a struct carries no variant name,
+/// so this is only useful when the target variant is already known.
+///
+/// ## `#[reflect(apply_scalar_to_newtype)]`
+///
+/// By default, the generated `Reflect::try_apply` implementation for an enum only accepts
+/// other enums, so applying a bare `u32` to `Value(u32)` fails with `ApplyError::MismatchedKinds`.
+/// This attribute instead applies any value that is not an enum directly to the field of the
+/// currently active variant, as long as it is a tuple variant with a single reflected field.
+/// The field's own `try_apply` decides whether the value is accepted,
+/// so a value of the wrong type is reported by the field, such as with `ApplyError::MismatchedTypes`.
+/// Other variants, including tuple variants with several fields, still fail as before.
+///
+/// Like `#[reflect(apply_struct_to_variant)]`, the variant itself is never changed.
+///
+/// ## `#[reflect(apply_counting)]`
+///
+/// This attribute generates an inherent
+/// `apply_counting(&mut self, value: &dyn Reflect) -> Result<usize, ApplyError>` method for an enum.
+/// It applies `value` exactly like `Reflect::try_apply`, but also returns how many fields were changed,
+/// which helps undo and diffing tools skip applies that change nothing.
+///
+/// Each field is compared with `Reflect::reflect_partial_eq` before it is applied,
+/// so fields that already hold an equal value are not counted and `Ok(0)` means a no-op.
+/// Switching to another variant counts as a single change.
+///
+/// ## `#[reflect(merge)]`
+///
+/// This attribute generates an inherent
+/// `reflect_merge(&mut self, other: &dyn Reflect) -> Result<(), ApplyError>` method for an enum,
+/// which is useful for layering one configuration over another.
+///
+/// If `other` holds the same variant as `self`, its fields are merged into `self` one by one,
+/// according to the policy each field is given with `#[reflect(merge = "...")]`.
+/// Fields missing from `other` are left unchanged.
+/// Otherwise, `other` is applied exactly like `Reflect::try_apply`, replacing the whole value.
+///
+/// ## `#[reflect(cold_errors)]`
+///
+/// This attribute marks every branch of the generated `Reflect::try_apply` implementation
+/// for an enum that builds an `ApplyError` as cold, hinting to the optimizer that it is rarely taken.
+/// This keeps the error handling out of the hot path of large enums, without changing behavior.
+///
+/// ## `#[reflect(batch_missing_fields)]`
+///
+/// By default, the generated `Reflect::try_apply` implementation for an enum stops at the first
+/// missing field when switching to a struct variant, returning a single `ApplyError::MissingEnumField`.
+/// This attribute instead checks every field without a default up front, so that a partial value
+/// missing several fields reports all of them at once as an `ApplyError::Multiple`
+/// holding one `ApplyError::MissingEnumField` per field.
+/// A single missing field is still reported on its own, as are all other errors.
+///
+/// This also applies to `TryFrom<&dyn Reflect>`, if derived.
+/// It has no effect with `#[reflect(collect_apply_errors)]`, which already reports every missing field.
+///
+/// ## `#[reflect(rename_all = "...")]`
+///
+/// This attribute renames the fields of every struct variant of an enum to the given naming convention,
+/// one of `"snake_case"`, `"camelCase"`, `"PascalCase"` or `"kebab-case"`.
+/// Field names are expected to be written in `snake_case`, as is usual in Rust.
+///
+/// The new names are used everywhere reflection names a field: in the generated `TypeInfo`,
+/// when looking up fields through `Enum::field`, in apply and conversion errors,
+/// and so also when serializing and deserializing.
+/// Variant names and tuple variants are left as they are,
+/// and previous names given with `#[reflect(from = "...")]` are used as written.
+/// The derive fails if two fields of a variant end up with the same name.
+///
+/// This attribute is only supported on enums.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(rename_all = "camelCase")]
+/// enum Shape {
+///   // Reflected with the fields `"sideLength"` and `"cornerRadius"`
+///   Square { side_length: f32, corner_radius: f32 },
+/// }
+/// ```
+///
+/// ## `#[reflect(split_variants)]`
+///
+/// By default, the generated `FromReflect::from_reflect` implementation for an enum
+/// constructs every variant inline within a single `match`.
+/// For enums with many large variants, this attribute instead generates a private
+/// helper function per variant, which the `match` then dispatches to.
+/// This keeps each generated function small, without changing its behavior.
+///
+/// ## `#[reflect(default_variant)]`
+///
+/// This attribute generates an inherent `reflect_default_variant(name: &str) -> Option<Self>`
+/// function for an enum, which constructs the named variant with every field set to its default.
+///
+/// Fields marked `#[reflect(default = "...")]` use the given function,
+/// while all other fields (active or ignored) use `Default::default()`
+/// and so must implement [`Default`].
+/// Unknown variant names return `None`.
+///
 /// ## `#[reflect(type_path = false)]`
 ///
 /// This attribute will opt-out of the default `TypePath` implementation.
@@ -274,6 +675,35 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// // {/* ... */}
 /// ```
 ///
+/// ## `#[reflect(bound = "...")]`
+///
+/// This attribute replaces the bounds generated for the active fields with the given predicates,
+/// similar to serde's attribute of the same name.
+/// An empty string removes them entirely, like `#[reflect(no_field_bounds)]`.
+///
+/// This is useful for generic types where the automatically inferred field bounds are wrong,
+/// such as recursive types whose field bounds would never resolve.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(bound = "T: FromReflect + TypePath + GetTypeRegistration")]
+/// enum Tree<T> {
+///   Leaf(T),
+///   Node(Vec<Tree<T>>),
+/// }
+///
+/// // Generates a where clause like:
+/// //
+/// // impl<T> bevy_reflect::Reflect for Tree<T>
+/// // where
+/// //   Self: Any + Send + Sync,
+/// //   T: TypePath,
+/// //   T: FromReflect + TypePath + GetTypeRegistration,
+/// // {/* ... */}
+/// ```
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the type's `TypeInfo`.
@@ -317,6 +747,14 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// which may be useful for maintaining invariants, keeping certain data private,
 /// or allowing the use of types that do not implement `Reflect` within the container.
 ///
+/// `PhantomData` fields are always ignored, without needing this attribute,
+/// and are constructed directly since they hold no data.
+///
+/// For enums, applying a value of the same variant with `Reflect::apply` or `Reflect::try_apply`
+/// updates the active fields in place and never touches ignored fields,
+/// so an ignored `Arc` keeps pointing to the same allocation.
+/// Ignored fields are only constructed anew when switching to a different variant.
+///
 /// ## `#[reflect(skip_serializing)]`
 ///
 /// This works similar to `#[reflect(ignore)]`, but rather than opting out of _all_ of reflection,
@@ -327,9 +765,30 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// For enums, the `EnumSerializationData` type is registered instead, containing the `SerializationData` of each variant.
+/// Since skipped fields are never present after deserialization, the generated `FromReflect` and `Reflect::apply`
+/// implementations of enums fall back to the field's default value (see `#[reflect(default)]`) when they are missing.
+///
+/// ## `#[reflect(skip_if = "...")]`
+///
+/// This attribute is the reflection equivalent of serde's `skip_serializing_if`.
+/// It takes the path to a function of type `fn(&T) -> bool`, where `T` is the field's type,
+/// and only serializes the field when that function returns false.
+///
+/// A skipped field is absent from the serialized data, so the deserializer, the generated `FromReflect`
+/// and the generated `Reflect::apply` fall back to the field's default value (see `#[reflect(default)]`),
+/// using `Default::default()` if no other default is given.
+/// For example, a field marked `#[reflect(skip_if = "Option::is_none")]` that is `None`
+/// is not serialized at all, and deserializes back to `None`.
+///
+/// Like serde's `skip_serializing_if`, this is only supported on named fields,
+/// and only round-trips with self-describing formats that serialize structs as maps.
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the field's `TypeInfo`.
+/// It may also be placed on enum variants, in which case the attributes are registered
+/// to the variant's `VariantInfo`.
 ///
 /// It accepts any expression after the `@` symbol that resolves to a value which implements `Reflect`.
 ///
@@ -356,6 +815,343 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// }
 /// ```
 ///
+/// ## `#[reflect(deprecated(...))]`
+///
+/// This attribute may only be used on enum variants.
+/// It registers a `VariantDeprecation` to the variant's `VariantInfo`,
+/// optionally containing the version the variant was deprecated in and the variant replacing it.
+///
+/// A message may also be given, either as `#[reflect(deprecated = "...")]`
+/// or as `note = "..."` alongside the other options.
+/// When the `warn_deprecated_variants` feature of `bevy_reflect` is enabled,
+/// the generated `FromReflect::from_reflect` and `Reflect::try_apply` implementations
+/// log this message with `tracing::warn!` the first time they construct the variant,
+/// to help migrate data away from it.
+/// Variants without a message, and builds without the feature, do no extra work.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Shape {
+///   #[reflect(deprecated(since = "0.14", replaced_by = "Circle"))]
+///   Round(f32),
+///   #[reflect(deprecated = "squares are now rectangles")]
+///   Square(f32),
+///   Circle(f32),
+/// }
+/// ```
+///
+/// ## `#[reflect(alias = "...")]`
+///
+/// This attribute may only be used on enum variants.
+/// It registers an additional name that the variant will be matched by,
+/// such as its name before being renamed, so that previously serialized data can still be loaded.
+///
+/// Aliases are accepted by the generated `FromReflect` and `Reflect::try_apply` implementations,
+/// are listed in `VariantInfo::aliases`, and are recognized by the reflection deserializer.
+/// A variant may have any number of aliases, but no two variants may share a name or alias.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Shape {
+///   #[reflect(alias = "Round", alias = "Sphere")]
+///   Circle(f32),
+/// }
+/// ```
+///
+/// ## `#[reflect(hidden)]`
+///
+/// This attribute may only be used on enum variants.
+/// It keeps an internal variant out of the list of variants the enum advertises:
+/// the variant is left out of `EnumInfo::variant_names`, which the reflection deserializer
+/// reports to the format and in its errors, and out of `reflect_discriminant_names`.
+///
+/// The variant is otherwise reflected as normal, so existing data containing it
+/// still serializes, deserializes and converts with `FromReflect`.
+/// It can be detected with `VariantInfo::is_hidden`.
+///
+/// Note that `#[doc(hidden)]` alone does not hide a variant from reflection.
+///
+/// ## `#[reflect(with = "...")]`
+///
+/// This attribute may only be used on the active fields of enum variants.
+/// It points at a module providing the custom reflection logic for the field,
+/// which must contain the following functions:
+///
+/// - `fn from_reflect(value: &dyn Reflect) -> Option<T>`, used by `FromReflect::from_reflect`
+/// - `fn apply(value: &dyn Reflect) -> Result<T, ApplyError>`, used by `Reflect::try_apply`
+///   when switching variants (and by `TryFrom<&dyn Reflect>`, if derived)
+/// - `fn clone(value: &T) -> Box<dyn Reflect>`, used by `Enum::clone_dynamic`
+///
+/// If the enum is marked `#[reflect(from_reflect(context = ...))]`, the module must also contain
+/// `fn from_reflect_with_context(value: &dyn Reflect, context: &C) -> Option<T>`,
+/// used by the generated `from_reflect_with_context` function.
+///
+/// If one of these functions is missing, the compiler error names it and points at this attribute.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Transform {
+///   Matrix(#[reflect(with = "mat_reflect")] Mat4),
+///   Identity,
+/// }
+/// ```
+///
+/// ## `#[reflect(eq = "ptr")]`
+///
+/// This attribute may only be used on the active fields of enum variants whose type implements `Deref`,
+/// such as `Arc<T>`.
+/// The generated `Reflect::reflect_partial_eq` implementation then treats the field as equal
+/// when both values point to the same data, without descending into it.
+/// Otherwise, the field is still compared using its own `Reflect::reflect_partial_eq`.
+///
+/// This is useful for shared immutable data, which is often cheaper to compare by pointer.
+/// If `PartialEq` is registered with `#[reflect(PartialEq)]`, this only applies when comparing
+/// to values that are not `Self`, and it has no effect with a custom `PartialEq` function.
+///
+/// ## `#[reflect(apply_via = "downcast")]`
+///
+/// This attribute may only be used on the active fields of enum variants whose type is a boxed trait object,
+/// such as `Box<dyn MyTrait>`, where `MyTrait` has `Reflect` as a supertrait.
+/// The field is then reflected as the concrete value behind the box,
+/// so `Reflect::try_apply` applies updates to that value in place through its own `Reflect` implementation.
+/// The field type itself needs no `Reflect` or `FromReflect` implementation, and its type info describes it as a `dyn Reflect`.
+///
+/// Since the concrete type is only known at runtime, `FromReflect` cannot construct the field and fails for its variant,
+/// and switching to its variant with `Reflect::try_apply` returns an error.
+///
+/// The field can instead be constructed by the `from_reflect_with_context` function generated by
+/// `#[reflect(from_reflect(context = ...))]`, where the context type implements `AsRef<TypeRegistry>`
+/// (as `TypeRegistry` itself does).
+/// The concrete type is then looked up in that registry, which requires the type to be registered
+/// with both `ReflectFromReflect` and the type data of the trait, such as `#[reflect(Shape)]`.
+/// If either is missing, the variant cannot be constructed and the function returns `None`.
+///
+/// Serializing the field likewise relies on its concrete type being registered in the `TypeRegistry`,
+/// and the field cannot be deserialized.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[reflect_trait]
+/// trait Shape: Reflect {}
+///
+/// #[derive(Reflect)]
+/// #[reflect(Shape)]
+/// struct Circle {
+///   radius: f32,
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(from_reflect(context = TypeRegistry))]
+/// enum Slot {
+///   Empty,
+///   Filled(#[reflect(apply_via = "downcast")] Box<dyn Shape>),
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Circle>();
+/// let slot = Slot::from_reflect_with_context(&value, &registry);
+/// ```
+///
+/// ## `#[reflect(apply = "extend")]`
+///
+/// This attribute may only be used on the active fields of enum variants.
+/// When `Reflect::try_apply` updates the field in place, because the value is of the same variant,
+/// the elements of a list value are appended to the field instead of overwriting its elements from the start.
+/// Any other value is applied as usual, which already inserts the entries of a map value
+/// while keeping the entries the field has.
+///
+/// Switching to the field's variant still constructs the field from the value alone.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Log {
+///   Lines(#[reflect(apply = "extend")] Vec<String>),
+/// }
+///
+/// let mut log = Log::Lines(vec![String::from("a")]);
+/// log.apply(&Log::Lines(vec![String::from("b")]));
+/// assert!(matches!(log, Log::Lines(lines) if lines == ["a", "b"]));
+/// ```
+///
+/// ## `#[reflect(merge = "...")]`
+///
+/// This attribute may only be used on the active fields of enum variants whose enum is marked `#[reflect(merge)]`.
+/// It chooses how the generated `reflect_merge` method merges the field:
+///
+/// * `"replace"`, the default, applies the other value's field to this one.
+/// * `"keep"` leaves this field unchanged.
+/// * `"combine"` applies the other value's field only if it is `Some`, keeping this field if it is `None`.
+///   It may only be used on `Option` fields.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(merge)]
+/// enum Config {
+///   Window {
+///     title: String,
+///     #[reflect(merge = "keep")]
+///     id: u32,
+///     #[reflect(merge = "combine")]
+///     icon: Option<String>,
+///   },
+/// }
+///
+/// base.reflect_merge(&overrides)?;
+/// ```
+///
+/// ## `#[reflect(from = "...")]`
+///
+/// This attribute may only be used on the named fields of enum variants.
+/// It gives the previous name of a renamed field, so that previously written data can still be loaded.
+///
+/// When the field is missing under its current name, the generated `FromReflect`, `Reflect::try_apply`,
+/// and `TryFrom<&dyn Reflect>` implementations read it under its previous name instead.
+/// If it is missing under both, the field falls back to its default as usual (see `#[reflect(default)]`).
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Shape {
+///   Circle {
+///     #[reflect(from = "radius", default)]
+///     size: f32,
+///   },
+/// }
+/// ```
+///
+/// ## `#[reflect(default)]` (on enum variants)
+///
+/// When placed on a single enum variant, this attribute declares it as the enum's default variant.
+/// The enum must implement [`Default`], and a `ReflectDefault` is registered for it,
+/// allowing tooling to instantiate the enum through reflection without picking a variant.
+///
+/// In debug builds, registering the type asserts that `Default::default()` constructs the declared variant.
+///
+/// If any field of the variant has its own `#[reflect(default = "...")]` or `#[reflect(try_default = "...")]`
+/// function, the enum does not need to implement [`Default`].
+/// The registered `ReflectDefault` instead constructs the variant from its field defaults,
+/// as `#[reflect(default_variant)]` does, so fields whose types do not implement [`Default`]
+/// can be given a function instead.
+/// The remaining fields fall back to [`Default`], and registering with `#[reflect(Default)]`
+/// still uses the enum's own `Default` impl.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Brush {
+///   #[reflect(default)]
+///   Solid {
+///     #[reflect(default = "Color::white")]
+///     color: Color,
+///     size: f32,
+///   },
+///   Pattern(Handle<Image>),
+/// }
+/// ```
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect, Default)]
+/// enum Weather {
+///   #[default]
+///   #[reflect(default)]
+///   Sunny,
+///   Rainy,
+/// }
+/// ```
+///
+/// ## `#[reflect(default = "...")]` (on enum variants)
+///
+/// When given a function, such as `#[reflect(default = "Shape::unit_rect")]`, this instead provides
+/// the defaults for the variant's own fields.
+/// The function takes no arguments and returns `Self`, and must construct this same variant.
+/// Whenever the generated `FromReflect`, `Reflect::try_apply` or `TryFrom<&dyn Reflect>`
+/// implementations construct the variant, the function is called once,
+/// and each field missing from the reflected value is taken from its result,
+/// while present fields are overwritten as usual.
+/// Ignored fields are taken from it too.
+///
+/// If the function constructs a different variant, its fields cannot be used,
+/// so the conversion fails instead: `FromReflect` returns `None`, while `Reflect::try_apply`,
+/// `TryFrom<&dyn Reflect>` and `from_dynamic_enum` return a `MismatchedVariantDefault` error.
+///
+/// A field's own `#[reflect(default)]`, `#[reflect(default = "...")]` or `#[reflect(try_default = "...")]`
+/// attribute takes precedence over the variant's function for that field.
+/// Such variants do not list any fields in `VariantInfo::required_fields`.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Shape {
+///   #[reflect(default = "Shape::unit_rect")]
+///   Rect { width: f32, height: f32, #[reflect(default)] rounded: bool },
+/// }
+///
+/// impl Shape {
+///   fn unit_rect() -> Self {
+///     Shape::Rect { width: 1.0, height: 1.0, rounded: true }
+///   }
+/// }
+/// ```
+///
+/// ## `#[reflect(ignore)]` (on enum variants)
+///
+/// When placed on an enum variant, none of its fields are reflected, so they need not implement `Reflect`.
+/// The variant is still reported by the `Enum` trait and its type info, but without any fields,
+/// and it is never constructed through reflection: `FromReflect` returns `None` for it,
+/// while `Reflect::try_apply`, `TryFrom<&dyn Reflect>`, and `from_dynamic_enum` return an `IgnoredVariant` error.
+/// `Reflect::try_apply` also returns this error when the current value is an ignored variant.
+///
+/// Ignored variants cannot be marked `#[reflect(default)]` or `#[reflect(catch_all)]`,
+/// nor be the replacement of a deprecated variant.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Callback {
+///   Named(String),
+///   #[reflect(ignore)]
+///   Native(Box<dyn Fn() + Send + Sync>),
+/// }
+/// ```
+///
+/// ## `#[reflect(catch_all)]`
+///
+/// This attribute may only be used on a single enum variant, which must have exactly one active field.
+/// When the generated `FromReflect`, `Reflect::try_apply`, and `TryFrom<&dyn Reflect>` implementations
+/// encounter a variant name that does not exist on the enum, they construct this variant instead,
+/// storing the unknown name in its field (via `Into<T>` from `&str`) rather than failing.
+///
+/// This allows data written by newer versions of a type to be loaded without losing the variant name.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// enum Weather {
+///   Sunny,
+///   Rainy,
+///   #[reflect(catch_all)]
+///   Other(String),
+/// }
+/// ```
+///
 /// [`reflect_trait`]: macro@reflect_trait
 #[proc_macro_derive(Reflect, attributes(reflect, reflect_value, type_path, type_name))]
 pub fn derive_reflect(input: TokenStream) -> TokenStream {
@@ -365,6 +1161,13 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
 
 /// Derives the `FromReflect` trait.
 ///
+/// For enums, the variant is looked up by the name reported by the reflected enum.
+/// If no variant has that name, the name is parsed as an integer and matched against
+/// each variant's discriminant (explicit or implicit), so that formats which identify
+/// variants by discriminant (e.g. a variant named `"2"`) can still be converted.
+/// Enums with a `#[reflect(catch_all)]` variant skip this fallback,
+/// so any unknown name is stored in that variant instead.
+///
 /// # Field Attributes
 ///
 /// ## `#[reflect(ignore)]`
@@ -384,11 +1187,30 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
 /// However, it can also take in a path string to a user-defined function that will return the default value.
 /// This takes the form: `#[reflect(default = "path::to::my_function")]` where `my_function` is a parameterless
 /// function that must return some default value for the type.
+/// Trivial defaults may instead be given as an inline closure taking no arguments,
+/// such as `#[reflect(default = || Vec::with_capacity(8))]`.
 ///
 /// Specifying a custom default can be used to give different fields their own specialized defaults,
 /// or to remove the `Default` requirement on fields marked with `#[reflect(ignore)]`.
 /// Additionally, either form of this attribute can be used to fill in fields that are simply missing,
 /// such as when converting a partially-constructed dynamic type to a concrete one.
+///
+/// When an enum variant is constructed, its fields are evaluated in declaration order,
+/// whether they are active or ignored, so default and `#[reflect(with = "...")]` functions
+/// with side effects, such as drawing from a shared counter, are always called in that order.
+/// A variant's own `#[reflect(default = "...")]` function is called before any of its fields.
+/// Evaluation stops at the first field that fails, unless the enum is marked `#[reflect(collect_apply_errors)]`.
+///
+/// ## `#[reflect(try_default = "...")]`
+///
+/// This works like `#[reflect(default = "...")]`, except that the given function is fallible
+/// and returns a `Result<T, E>` where `E` implements `Display`.
+///
+/// If the function returns an error, `FromReflect::from_reflect` returns `None`.
+/// Enum `try_apply` returns an `ApplyError::FieldDefaultFailed` naming the variant and field.
+///
+/// This attribute cannot be combined with `#[reflect(ignore)]` or `#[reflect(skip_serializing)]`,
+/// since those fields must always have a default.
 #[proc_macro_derive(FromReflect, attributes(reflect))]
 pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
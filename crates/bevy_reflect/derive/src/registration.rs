@@ -1,18 +1,21 @@
 //! Contains code related specifically to Bevy's type registration.
 
 use crate::derive_data::ReflectMeta;
-use crate::serialization::SerializationDataDef;
 use crate::utility::WhereClauseOptions;
 use quote::quote;
 use syn::Type;
 
 /// Creates the `GetTypeRegistration` impl for the given type data.
+///
+/// The `serialization_data` is an expression evaluating to the type's serialization type data, if any.
+/// The `additional_registrations` are statements run against the `registration`, if any.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn impl_get_type_registration<'a>(
     meta: &ReflectMeta,
     where_clause_options: &WhereClauseOptions,
-    serialization_data: Option<&SerializationDataDef>,
+    serialization_data: Option<proc_macro2::TokenStream>,
     type_dependencies: Option<impl Iterator<Item = &'a Type>>,
+    additional_registrations: Option<proc_macro2::TokenStream>,
 ) -> proc_macro2::TokenStream {
     let type_path = meta.type_path();
     let bevy_reflect_path = meta.bevy_reflect_path();
@@ -38,10 +41,9 @@ pub(crate) fn impl_get_type_registration<'a>(
         None
     };
 
-    let serialization_data = serialization_data.map(|data| {
-        let serialization_data = data.as_serialization_data(bevy_reflect_path);
+    let serialization_data = serialization_data.map(|serialization_data| {
         quote! {
-            registration.insert::<#bevy_reflect_path::serde::SerializationData>(#serialization_data);
+            registration.insert(#serialization_data);
         }
     });
 
@@ -53,6 +55,7 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #additional_registrations
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
@@ -1,10 +1,11 @@
-use crate::derive_data::StructField;
+use crate::derive_data::{EnumVariant, StructField};
 use crate::field_attributes::{DefaultBehavior, ReflectIgnoreBehavior};
-use bevy_macro_utils::fq_std::{FQBox, FQDefault};
-use quote::quote;
+use crate::utility::call_default_func;
+use bevy_macro_utils::fq_std::{FQBox, FQDefault, FQOption};
+use quote::{quote, quote_spanned};
 use std::collections::HashMap;
 use syn::spanned::Spanned;
-use syn::Path;
+use syn::{Attribute, Path};
 
 type ReflectionIndex = usize;
 
@@ -12,6 +13,8 @@ type ReflectionIndex = usize;
 pub(crate) struct SerializationDataDef {
     /// Maps a field's _reflection_ index to its [`SkippedFieldDef`] if marked as `#[reflect(skip_serializing)]`.
     skipped: HashMap<ReflectionIndex, SkippedFieldDef>,
+    /// Maps a field's _reflection_ index to its [`SkipIfFieldDef`] if marked as `#[reflect(skip_if = "...")]`.
+    skip_if: HashMap<ReflectionIndex, SkipIfFieldDef>,
 }
 
 impl SerializationDataDef {
@@ -21,8 +24,22 @@ impl SerializationDataDef {
     /// Otherwise, returns `Ok(None)`.
     pub fn new(fields: &[StructField<'_>]) -> Result<Option<Self>, syn::Error> {
         let mut skipped = HashMap::default();
+        let mut skip_if = HashMap::default();
 
         for field in fields {
+            if let Some(predicate) = &field.attrs.skip_if {
+                skip_if.insert(
+                    field.reflection_index.ok_or_else(|| {
+                        syn::Error::new(
+                            field.data.span(),
+                            "internal error: field is missing a reflection index",
+                        )
+                    })?,
+                    SkipIfFieldDef::new(field, predicate)?,
+                );
+                continue;
+            }
+
             match field.attrs.ignore {
                 ReflectIgnoreBehavior::IgnoreSerialization => {
                     skipped.insert(
@@ -39,15 +56,30 @@ impl SerializationDataDef {
             }
         }
 
-        if skipped.is_empty() {
+        if skipped.is_empty() && skip_if.is_empty() {
             Ok(None)
         } else {
-            Ok(Some(Self { skipped }))
+            Ok(Some(Self { skipped, skip_if }))
         }
     }
 
     /// Returns a `TokenStream` containing an initialized `SerializationData` type.
     pub fn as_serialization_data(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
+        let skip_if = (!self.skip_if.is_empty()).then(|| {
+            let fields = self.skip_if.iter().map(|(reflection_index, field)| {
+                let SkipIfFieldDef {
+                    predicate_fn,
+                    default_fn,
+                } = field;
+                quote! {(
+                    #reflection_index,
+                    #bevy_reflect_path::serde::SkipIfField::new(#predicate_fn, #default_fn)
+                )}
+            });
+            quote! {
+                .with_skip_if(::core::iter::IntoIterator::into_iter([#(#fields),*]))
+            }
+        });
         let fields =
             self.skipped
                 .iter()
@@ -61,6 +93,59 @@ impl SerializationDataDef {
             #bevy_reflect_path::serde::SerializationData::new(
                 ::core::iter::IntoIterator::into_iter([#(#fields),*])
             )
+            #skip_if
+        }
+    }
+}
+
+/// Collected serialization data used to generate an `EnumSerializationData` type.
+pub(crate) struct EnumSerializationDataDef {
+    /// The name, `#[cfg]` attributes, and [`SerializationDataDef`] of each variant with skipped fields.
+    variants: Vec<(String, Vec<Attribute>, SerializationDataDef)>,
+}
+
+impl EnumSerializationDataDef {
+    /// Attempts to create a new `EnumSerializationDataDef` from the given collection of variants.
+    ///
+    /// Returns `Ok(Some(data))` if any variant has fields needing to be skipped during serialization.
+    /// Otherwise, returns `Ok(None)`.
+    pub fn new(variants: &[EnumVariant<'_>]) -> Result<Option<Self>, syn::Error> {
+        let mut skipped = Vec::new();
+
+        // Ignored variants are never serialized, so they need no serialization data
+        for variant in variants
+            .iter()
+            .filter(|variant| variant.attrs.ignore.is_active())
+        {
+            if let Some(data) = SerializationDataDef::new(variant.fields())? {
+                skipped.push((
+                    variant.data.ident.to_string(),
+                    variant.cfg_attrs().cloned().collect(),
+                    data,
+                ));
+            }
+        }
+
+        if skipped.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(Self { variants: skipped }))
+        }
+    }
+
+    /// Returns a `TokenStream` containing an initialized `EnumSerializationData` type.
+    pub fn as_serialization_data(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
+        let variants = self.variants.iter().map(|(name, cfg_attrs, data)| {
+            let data = data.as_serialization_data(bevy_reflect_path);
+            quote! {
+                #(#cfg_attrs)*
+                (#name, #data)
+            }
+        });
+        quote! {
+            #bevy_reflect_path::serde::EnumSerializationData::new(
+                ::core::iter::IntoIterator::into_iter([#(#variants),*])
+            )
         }
     }
 }
@@ -78,9 +163,12 @@ impl SkippedFieldDef {
         let ty = &field.data.ty;
 
         let default_fn = match &field.attrs.default {
-            DefaultBehavior::Func(func) => quote! {
-              || { #FQBox::new(#func()) }
-            },
+            DefaultBehavior::Func(func) => {
+                let default_value = call_default_func(func, ty);
+                quote! {
+                  || { #FQBox::new(#default_value) }
+                }
+            }
             _ => quote! {
               || { #FQBox::new(<#ty as #FQDefault>::default()) }
             },
@@ -89,3 +177,39 @@ impl SkippedFieldDef {
         Ok(Self { default_fn })
     }
 }
+
+/// Collected field data used to generate a `SkipIfField` type.
+pub(crate) struct SkipIfFieldDef {
+    /// The predicate deciding whether this field is skipped.
+    ///
+    /// This is of type `fn(&dyn Reflect) -> bool`.
+    predicate_fn: proc_macro2::TokenStream,
+    /// The default function for this field, used when it was skipped.
+    ///
+    /// This is of type `fn() -> Box<dyn Reflect>`.
+    default_fn: proc_macro2::TokenStream,
+}
+
+impl SkipIfFieldDef {
+    pub fn new(field: &StructField<'_>, predicate: &syn::ExprPath) -> Result<Self, syn::Error> {
+        let ty = &field.data.ty;
+
+        // Checked against the field's type, so a mismatched predicate is reported on the attribute
+        let predicate_fn = quote_spanned! {predicate.span()=>
+            |__value| -> bool {
+                let __skip_if: fn(&#ty) -> bool = #predicate;
+                match __value.downcast_ref::<#ty>() {
+                    #FQOption::Some(__value) => __skip_if(__value),
+                    #FQOption::None => false,
+                }
+            }
+        };
+
+        let SkippedFieldDef { default_fn } = SkippedFieldDef::new(field)?;
+
+        Ok(Self {
+            predicate_fn,
+            default_fn,
+        })
+    }
+}
@@ -6,10 +6,11 @@ use bevy_macro_utils::{
     BevyManifest,
 };
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::{quote, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
+use std::collections::HashSet;
 use syn::parse::{Parse, ParseStream, Peek};
 use syn::punctuated::Punctuated;
-use syn::{spanned::Spanned, LitStr, Member, Path, Token, Type, WhereClause};
+use syn::{spanned::Spanned, Attribute, LitStr, Member, Meta, Path, Token, Type, WhereClause};
 
 /// Returns the correct path for `bevy_reflect`.
 pub(crate) fn get_bevy_reflect_path() -> Path {
@@ -68,6 +69,201 @@ pub(crate) fn ident_or_index(ident: Option<&Ident>, index: usize) -> Member {
     )
 }
 
+/// Returns a token stream that calls a field's `#[reflect(default = "...")]` or
+/// `#[reflect(try_default = "...")]` function, which is expected to return `return_ty`.
+///
+/// The function is first coerced to a `fn() -> return_ty` pointer spanned to the attribute,
+/// so that a function with the wrong signature is reported there rather than deep within the generated code.
+pub(crate) fn call_default_func(func: &impl ToTokens, return_ty: impl ToTokens) -> TokenStream {
+    quote_spanned! {func.span()=>
+        {
+            let __default_fn: fn() -> #return_ty = #func;
+            __default_fn()
+        }
+    }
+}
+
+/// Returns the integer type named by a `#[repr(...)]` attribute in the given attributes, if any,
+/// such as `u8` for `#[repr(u8)]` or `#[repr(C, u8)]`.
+pub(crate) fn int_repr(attrs: &[Attribute]) -> Option<Ident> {
+    const INT_REPRS: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::List(list) if list.path.is_ident("repr") => {
+            list.tokens
+                .clone()
+                .into_iter()
+                .find_map(|token| match token {
+                    proc_macro2::TokenTree::Ident(ident)
+                        if INT_REPRS.iter().any(|repr| ident == repr) =>
+                    {
+                        Some(ident)
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    })
+}
+
+/// Returns true if the given attributes contain a `#[repr(C)]` attribute,
+/// including combined ones such as `#[repr(C, u8)]`.
+pub(crate) fn has_c_repr(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| match &attr.meta {
+        Meta::List(list) if list.path.is_ident("repr") => list
+            .tokens
+            .clone()
+            .into_iter()
+            .any(|token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "C")),
+        _ => false,
+    })
+}
+
+/// Returns the alignment given by a `#[repr(align(N))]` attribute in the given attributes, if any,
+/// including combined ones such as `#[repr(C, align(16))]`.
+pub(crate) fn align_repr(attrs: &[Attribute]) -> Option<TokenStream> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::List(list) if list.path.is_ident("repr") => {
+            let mut tokens = list.tokens.clone().into_iter();
+            while let Some(token) = tokens.next() {
+                if matches!(&token, proc_macro2::TokenTree::Ident(ident) if ident == "align") {
+                    if let Some(proc_macro2::TokenTree::Group(group)) = tokens.next() {
+                        return Some(group.stream());
+                    }
+                }
+            }
+            None
+        }
+        _ => None,
+    })
+}
+
+/// Returns true if the given type is (syntactically) an `UnsafeCell`.
+///
+/// Since macros only see tokens, this matches any path whose last segment is `UnsafeCell`,
+/// such as `UnsafeCell<T>`, `core::cell::UnsafeCell<T>` or `std::cell::UnsafeCell<T>`.
+pub(crate) fn is_unsafe_cell(ty: &Type) -> bool {
+    is_type_named(ty, "UnsafeCell")
+}
+
+/// Returns true if the given type is (syntactically) a `PhantomData`.
+///
+/// Like [`is_unsafe_cell`], this matches any path whose last segment is `PhantomData`.
+pub(crate) fn is_phantom_data(ty: &Type) -> bool {
+    is_type_named(ty, "PhantomData")
+}
+
+/// Returns true if the given type is (syntactically) an `Option`.
+///
+/// Like [`is_unsafe_cell`], this matches any path whose last segment is `Option`.
+pub(crate) fn is_option(ty: &Type) -> bool {
+    is_type_named(ty, "Option")
+}
+
+/// Returns true if the given type is (syntactically) a boxed trait object, such as `Box<dyn Trait>`.
+///
+/// Like [`is_unsafe_cell`], this matches any path whose last segment is `Box`.
+pub(crate) fn is_boxed_trait_object(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.path.segments.last().is_some_and(|segment| {
+            segment.ident == "Box"
+                && matches!(
+                    &segment.arguments,
+                    syn::PathArguments::AngleBracketed(args)
+                        if matches!(args.args.first(), Some(syn::GenericArgument::Type(Type::TraitObject(_))))
+                )
+        }),
+        Type::Group(group) => is_boxed_trait_object(&group.elem),
+        Type::Paren(paren) => is_boxed_trait_object(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Returns the path to the type data generated by `#[reflect_trait]` for the trait of a boxed trait object,
+/// such as `my_crate::ReflectShape` for `Box<dyn my_crate::Shape>`.
+///
+/// Returns `None` for `Box<dyn Reflect>`, which needs no conversion, or if the type is not a boxed trait object.
+pub(crate) fn reflect_trait_data_path(ty: &Type) -> Option<Path> {
+    match ty {
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            let Some(syn::GenericArgument::Type(Type::TraitObject(trait_object))) =
+                args.args.first()
+            else {
+                return None;
+            };
+            let mut path = trait_object.bounds.iter().find_map(|bound| match bound {
+                syn::TypeParamBound::Trait(bound) => Some(bound.path.clone()),
+                _ => None,
+            })?;
+
+            let trait_segment = path.segments.last_mut()?;
+            if trait_segment.ident == "Reflect" {
+                return None;
+            }
+            trait_segment.ident = get_reflect_ident(&trait_segment.ident.to_string());
+            trait_segment.arguments = syn::PathArguments::None;
+            Some(path)
+        }
+        Type::Group(group) => reflect_trait_data_path(&group.elem),
+        Type::Paren(paren) => reflect_trait_data_path(&paren.elem),
+        _ => None,
+    }
+}
+
+/// Returns true if the given type is a path whose last segment is `name`.
+fn is_type_named(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name),
+        Type::Group(group) => is_type_named(&group.elem, name),
+        Type::Paren(paren) => is_type_named(&paren.elem, name),
+        _ => false,
+    }
+}
+
+/// Returns true if the given type is a reference that is not a shared `'static` reference.
+///
+/// Reflected types must be `'static`, so only references such as `&'static str` can be reflected.
+pub(crate) fn is_non_static_reference(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => {
+            let is_static =
+                matches!(&reference.lifetime, Some(lifetime) if lifetime.ident == "static");
+            reference.mutability.is_some() || !is_static
+        }
+        Type::Group(group) => is_non_static_reference(&group.elem),
+        Type::Paren(paren) => is_non_static_reference(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Returns a `cfg!(...)` expression that is true when an item with the given attributes is compiled in,
+/// or `None` if the item is not gated behind any `#[cfg]` attributes.
+pub(crate) fn cfg_predicate(attrs: &[Attribute]) -> Option<TokenStream> {
+    let predicates = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::List(list) if list.path.is_ident("cfg") => Some(&list.tokens),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if predicates.is_empty() {
+        None
+    } else {
+        Some(quote!(::core::cfg!(all(#(#predicates),*))))
+    }
+}
+
 /// Options defining how to extend the `where` clause for reflection.
 pub(crate) struct WhereClauseOptions<'a, 'b> {
     meta: &'a ReflectMeta<'b>,
@@ -82,7 +278,18 @@ impl<'a, 'b> WhereClauseOptions<'a, 'b> {
         }
     }
 
+    /// Creates options bounding each distinct type in `active_fields`.
+    ///
+    /// Fields are often of the same type, such as a generic `T` shared by several enum variants,
+    /// so repeated types are only bounded once.
     pub fn new_with_fields(meta: &'a ReflectMeta<'b>, active_fields: Box<[Type]>) -> Self {
+        let mut seen = HashSet::new();
+        let active_fields = active_fields
+            .into_vec()
+            .into_iter()
+            .filter(|ty| seen.insert(ty.to_token_stream().to_string()))
+            .collect();
+
         Self {
             meta,
             active_fields,
@@ -98,6 +305,8 @@ impl<'a, 'b> WhereClauseOptions<'a, 'b> {
     ///   or `FromReflect` otherwise (or no bounds at all if `#[reflect(no_field_bounds)]` is present)
     ///
     /// When the derive is used with `#[reflect(where)]`, the bounds specified in the attribute are added as well.
+    /// When the derive is used with `#[reflect(bound = "...")]`, the bounds specified in the attribute
+    /// are used in place of the active field bounds.
     ///
     /// # Example
     ///
@@ -180,7 +389,10 @@ impl<'a, 'b> WhereClauseOptions<'a, 'b> {
             predicates.extend(type_param_predicates);
         }
 
-        if let Some(field_predicates) = self.active_field_predicates() {
+        if let Some(custom_bound) = self.meta.attrs().custom_bound() {
+            // `#[reflect(bound = "...")]` replaces the bounds inferred from the active fields
+            predicates.extend(custom_bound.iter().map(ToTokens::to_token_stream));
+        } else if let Some(field_predicates) = self.active_field_predicates() {
             predicates.extend(field_predicates);
         }
 
@@ -178,7 +178,7 @@ mod tests {
     use super::*;
     use crate as bevy_reflect;
     use crate::type_info::Typed;
-    use crate::{TypeInfo, VariantInfo};
+    use crate::{TypeInfo, TypeRegistry, VariantInfo};
     use std::ops::RangeInclusive;
 
     #[derive(Reflect, PartialEq, Debug)]
@@ -368,6 +368,48 @@ mod tests {
         assert_eq!(&Display::Picker, display);
     }
 
+    #[test]
+    fn should_get_custom_attributes_from_registered_variant_info() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Category(&'static str);
+
+        #[derive(Reflect)]
+        enum Collider {
+            #[reflect(@Category("physics"))]
+            Sphere(f32),
+            #[reflect(@Category("physics"))]
+            Cuboid {
+                half_size: f32,
+            },
+            #[reflect(@Category("debug"))]
+            Wireframe,
+            None,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Collider>();
+
+        let TypeInfo::Enum(info) = registry.get_type_info(TypeId::of::<Collider>()).unwrap() else {
+            panic!("expected enum info");
+        };
+
+        let physics = info
+            .iter()
+            .filter(|variant| variant.get_attribute::<Category>() == Some(&Category("physics")))
+            .map(VariantInfo::name)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["Sphere", "Cuboid"], physics);
+
+        let debug = info
+            .iter()
+            .filter(|variant| variant.get_attribute::<Category>() == Some(&Category("debug")))
+            .map(VariantInfo::name)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["Wireframe"], debug);
+
+        assert!(!info.variant("None").unwrap().has_attribute::<Category>());
+    }
+
     #[test]
     fn should_derive_custom_attributes_on_enum_variant_fields() {
         #[derive(Reflect)]
@@ -27,6 +27,48 @@ impl Clone for DynamicVariant {
     }
 }
 
+impl DynamicVariant {
+    /// Returns the fields of this variant as a [`DynamicStruct`].
+    ///
+    /// Returns `None` if this is not a struct variant.
+    pub fn as_struct(&self) -> Option<&DynamicStruct> {
+        match self {
+            DynamicVariant::Struct(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields of this variant as a mutable [`DynamicStruct`].
+    ///
+    /// Returns `None` if this is not a struct variant.
+    pub fn as_struct_mut(&mut self) -> Option<&mut DynamicStruct> {
+        match self {
+            DynamicVariant::Struct(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields of this variant as a [`DynamicTuple`].
+    ///
+    /// Returns `None` if this is not a tuple variant.
+    pub fn as_tuple(&self) -> Option<&DynamicTuple> {
+        match self {
+            DynamicVariant::Tuple(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields of this variant as a mutable [`DynamicTuple`].
+    ///
+    /// Returns `None` if this is not a tuple variant.
+    pub fn as_tuple_mut(&mut self) -> Option<&mut DynamicTuple> {
+        match self {
+            DynamicVariant::Tuple(data) => Some(data),
+            _ => None,
+        }
+    }
+}
+
 impl From<DynamicTuple> for DynamicVariant {
     fn from(dyn_tuple: DynamicTuple) -> Self {
         Self::Tuple(dyn_tuple)
@@ -152,6 +194,20 @@ impl DynamicEnum {
         self.variant = variant.into();
     }
 
+    /// Returns the data of the current variant.
+    pub fn variant(&self) -> &DynamicVariant {
+        &self.variant
+    }
+
+    /// Returns the data of the current variant mutably.
+    ///
+    /// Only struct and tuple variants have fields that can be edited in place,
+    /// using [`DynamicVariant::as_struct_mut`] and [`DynamicVariant::as_tuple_mut`] respectively.
+    /// To change the variant itself, use [`DynamicEnum::set_variant`] instead.
+    pub fn variant_mut(&mut self) -> &mut DynamicVariant {
+        &mut self.variant
+    }
+
     /// Create a [`DynamicEnum`] from an existing one.
     ///
     /// This is functionally the same as [`DynamicEnum::from_ref`] except it takes an owned value.
@@ -218,19 +274,11 @@ impl Enum for DynamicEnum {
     }
 
     fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
-        if let DynamicVariant::Struct(data) = &mut self.variant {
-            data.field_mut(name)
-        } else {
-            None
-        }
+        self.variant.as_struct_mut()?.field_mut(name)
     }
 
     fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
-        if let DynamicVariant::Tuple(data) = &mut self.variant {
-            data.field_mut(index)
-        } else {
-            None
-        }
+        self.variant.as_tuple_mut()?.field_mut(index)
     }
 
     fn index_of(&self, name: &str) -> Option<usize> {
@@ -328,18 +376,18 @@ impl Reflect for DynamicEnum {
         if let ReflectRef::Enum(value) = value.reflect_ref() {
             if Enum::variant_name(self) == value.variant_name() {
                 // Same variant -> just update fields
-                match value.variant_type() {
-                    VariantType::Struct => {
+                match (value.variant_type(), &mut self.variant) {
+                    (VariantType::Struct, DynamicVariant::Struct(data)) => {
                         for field in value.iter_fields() {
                             let name = field.name().unwrap();
-                            if let Some(v) = Enum::field_mut(self, name) {
+                            if let Some(v) = data.field_mut(name) {
                                 v.try_apply(field.value())?;
                             }
                         }
                     }
-                    VariantType::Tuple => {
+                    (VariantType::Tuple, DynamicVariant::Tuple(data)) => {
                         for (index, field) in value.iter_fields().enumerate() {
-                            if let Some(v) = Enum::field_at_mut(self, index) {
+                            if let Some(v) = data.field_mut(index) {
                                 v.try_apply(field.value())?;
                             }
                         }
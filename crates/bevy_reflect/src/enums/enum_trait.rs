@@ -120,7 +120,13 @@ pub trait Enum: Reflect {
     fn variant_index(&self) -> usize;
     /// The type of the current variant.
     fn variant_type(&self) -> VariantType;
-    // Clones the enum into a [`DynamicEnum`].
+    /// Clones the enum into a [`DynamicEnum`].
+    ///
+    /// The dynamic enum holds the current variant with a [`clone_value`] of each of its fields,
+    /// and is detached from `self`.
+    /// Fields ignored by reflection are not included.
+    ///
+    /// [`clone_value`]: crate::Reflect::clone_value
     fn clone_dynamic(&self) -> DynamicEnum;
     /// Returns true if the current variant's type matches the given one.
     fn is_variant(&self, variant_type: VariantType) -> bool {
@@ -140,6 +146,7 @@ pub struct EnumInfo {
     variants: Box<[VariantInfo]>,
     variant_names: Box<[&'static str]>,
     variant_indices: HashMap<&'static str, usize>,
+    variant_aliases: HashMap<&'static str, usize>,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
@@ -159,7 +166,17 @@ impl EnumInfo {
             .map(|(index, variant)| (variant.name(), index))
             .collect::<HashMap<_, _>>();
 
-        let variant_names = variants.iter().map(|variant| variant.name()).collect();
+        let variant_aliases = variants
+            .iter()
+            .enumerate()
+            .flat_map(|(index, variant)| variant.aliases().iter().map(move |alias| (*alias, index)))
+            .collect::<HashMap<_, _>>();
+
+        let variant_names = variants
+            .iter()
+            .filter(|variant| !variant.is_hidden())
+            .map(|variant| variant.name())
+            .collect();
 
         Self {
             type_path: TypePathTable::of::<TEnum>(),
@@ -167,6 +184,7 @@ impl EnumInfo {
             variants: variants.to_vec().into_boxed_slice(),
             variant_names,
             variant_indices,
+            variant_aliases,
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -188,6 +206,9 @@ impl EnumInfo {
     }
 
     /// A slice containing the names of all variants in order.
+    ///
+    /// [Hidden](VariantInfo::is_hidden) variants are not included,
+    /// though they can still be found with [`EnumInfo::variant`] and [`EnumInfo::iter`].
     pub fn variant_names(&self) -> &[&'static str] {
         &self.variant_names
     }
@@ -199,6 +220,15 @@ impl EnumInfo {
             .map(|index| &self.variants[*index])
     }
 
+    /// Get a variant by one of its [aliases](VariantInfo::aliases).
+    ///
+    /// This does _not_ match the variant's primary name, for that use [`EnumInfo::variant`].
+    pub fn variant_by_alias(&self, alias: &str) -> Option<&VariantInfo> {
+        self.variant_aliases
+            .get(alias)
+            .map(|index| &self.variants[*index])
+    }
+
     /// Get a variant at the given index.
     pub fn variant_at(&self, index: usize) -> Option<&VariantInfo> {
         self.variants.get(index)
@@ -25,6 +25,20 @@ pub fn enum_hash<TEnum: Enum>(value: &TEnum) -> Option<u64> {
 ///   values.
 #[inline]
 pub fn enum_partial_eq<TEnum: Enum>(a: &TEnum, b: &dyn Reflect) -> Option<bool> {
+    enum_partial_eq_with(a, b, |_, a, b| b.reflect_partial_eq(a))
+}
+
+/// Compares an [`Enum`] with a [`Reflect`] value, using `field_eq` to compare fields.
+///
+/// This behaves like [`enum_partial_eq`], except that each pair of field values is compared
+/// by calling `field_eq` with the field's index in the active variant, the field of `a`,
+/// and the corresponding field of `b`.
+#[inline]
+pub fn enum_partial_eq_with<TEnum: Enum>(
+    a: &TEnum,
+    b: &dyn Reflect,
+    mut field_eq: impl FnMut(usize, &dyn Reflect, &dyn Reflect) -> Option<bool>,
+) -> Option<bool> {
     // Both enums?
     let ReflectRef::Enum(b) = b.reflect_ref() else {
         return Some(false);
@@ -43,10 +57,10 @@ pub fn enum_partial_eq<TEnum: Enum>(a: &TEnum, b: &dyn Reflect) -> Option<bool>
     match a.variant_type() {
         VariantType::Struct => {
             // Same struct fields?
-            for field in a.iter_fields() {
+            for (i, field) in a.iter_fields().enumerate() {
                 let field_name = field.name().unwrap();
                 if let Some(field_value) = b.field(field_name) {
-                    if let Some(false) | None = field_value.reflect_partial_eq(field.value()) {
+                    if let Some(false) | None = field_eq(i, field.value(), field_value) {
                         // Fields failed comparison
                         return Some(false);
                     }
@@ -61,7 +75,7 @@ pub fn enum_partial_eq<TEnum: Enum>(a: &TEnum, b: &dyn Reflect) -> Option<bool>
             // Same tuple fields?
             for (i, field) in a.iter_fields().enumerate() {
                 if let Some(field_value) = b.field_at(i) {
-                    if let Some(false) | None = field_value.reflect_partial_eq(field.value()) {
+                    if let Some(false) | None = field_eq(i, field.value(), field_value) {
                         // Fields failed comparison
                         return Some(false);
                     }
@@ -396,6 +396,68 @@ mod tests {
         assert_eq!(TestEnum::C { value: 1.23 }, value);
     }
 
+    #[test]
+    fn enum_should_allow_generic_parameter_defaults() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum<T: FromReflect = usize> {
+            A,
+            B(T),
+        }
+
+        // `<TestEnum>` without parameters is `TestEnum<usize>`
+        let TypeInfo::Enum(info) = <TestEnum>::type_info() else {
+            panic!("expected `TypeInfo::Enum`");
+        };
+        let VariantInfo::Tuple(variant) = info.variant("B").unwrap() else {
+            panic!("expected `VariantInfo::Tuple`");
+        };
+        assert!(variant.field_at(0).unwrap().is::<usize>());
+
+        let mut value: TestEnum = TestEnum::A;
+        let mut data = DynamicTuple::default();
+        data.insert(123_usize);
+        let dyn_enum = DynamicEnum::new("B", data);
+        value.apply(&dyn_enum);
+        assert_eq!(TestEnum::B(123), value);
+
+        let cloned = value.clone_value();
+        assert_eq!(Some(&value), <TestEnum>::from_reflect(&*cloned).as_ref());
+
+        // The default can still be overridden
+        let value = TestEnum::<f32>::B(1.23);
+        let cloned = value.clone_value();
+        assert_eq!(
+            Some(&value),
+            TestEnum::<f32>::from_reflect(&*cloned).as_ref()
+        );
+    }
+
+    #[test]
+    fn enum_should_allow_const_generics() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Buffer<const N: usize> {
+            Full([u8; N]),
+            Empty,
+        }
+
+        assert!(Buffer::<4>::type_path().ends_with("::Buffer<4>"));
+        assert!(Buffer::<8>::type_path().ends_with("::Buffer<8>"));
+
+        let value = Buffer::Full([1, 2, 3, 4]);
+        let cloned = value.clone_value();
+        assert_eq!(Some(&value), Buffer::<4>::from_reflect(&*cloned).as_ref());
+
+        let mut value = Buffer::<4>::Empty;
+        value.apply(&*cloned);
+        assert_eq!(Buffer::Full([1, 2, 3, 4]), value);
+
+        // An array of a different length cannot be applied
+        let mut data = DynamicTuple::default();
+        data.insert([1_u8, 2, 3]);
+        let dyn_enum = DynamicEnum::new("Full", data);
+        assert!(Buffer::<4>::from_reflect(&dyn_enum).is_none());
+    }
+
     #[test]
     fn enum_should_allow_struct_fields() {
         #[derive(Reflect, Debug, PartialEq)]
@@ -666,4 +728,3963 @@ mod tests {
             "expected TestEnum::C{{value: 123}} != TestEnum::C2{{value: 1.23}}"
         );
     }
+
+    #[test]
+    fn should_register_variant_deprecation() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            #[reflect(deprecated(since = "0.14", replaced_by = "New"))]
+            Old(usize),
+            #[reflect(deprecated)]
+            Unused,
+            New(usize),
+        }
+
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected `TypeInfo::Enum`");
+        };
+
+        let deprecation = info.variant("Old").unwrap().deprecation().unwrap();
+        assert_eq!(Some("0.14"), deprecation.since());
+        assert_eq!(Some("New"), deprecation.replaced_by());
+
+        let deprecation = info.variant("Unused").unwrap().deprecation().unwrap();
+        assert_eq!(None, deprecation.since());
+        assert_eq!(None, deprecation.replaced_by());
+
+        assert!(info.variant("New").unwrap().deprecation().is_none());
+
+        // Without `auto_migrate`, deprecated variants are constructed as-is
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(123_usize);
+        let dyn_enum = DynamicEnum::new("Old", dyn_tuple);
+        assert_eq!(Some(TestEnum::Old(123)), TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn should_auto_migrate_deprecated_variants() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_reflect(auto_migrate))]
+        enum TestEnum {
+            #[reflect(deprecated(since = "0.14", replaced_by = "New"))]
+            Old(usize),
+            New(usize),
+        }
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(123_usize);
+        let dyn_enum = DynamicEnum::new("Old", dyn_tuple);
+        assert_eq!(Some(TestEnum::New(123)), TestEnum::from_reflect(&dyn_enum));
+
+        // The deprecated variant can still be reflected normally
+        let value: &dyn Reflect = &TestEnum::Old(321);
+        let ReflectRef::Enum(value) = value.reflect_ref() else {
+            panic!("expected `ReflectRef::Enum`");
+        };
+        assert_eq!("Old", value.variant_name());
+    }
+
+    #[test]
+    fn enum_try_apply_should_accept_present_none_field() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A,
+            B { value: Option<u32> },
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", Option::<u32>::None);
+        let dyn_enum = DynamicEnum::new("B", dyn_struct);
+
+        // === A -> B === //
+        let mut value = TestEnum::A;
+        assert!(value.try_apply(&dyn_enum).is_ok());
+        assert_eq!(TestEnum::B { value: None }, value);
+
+        // === B -> B === //
+        let mut value = TestEnum::B { value: Some(123) };
+        assert!(value.try_apply(&dyn_enum).is_ok());
+        assert_eq!(TestEnum::B { value: None }, value);
+
+        // A field that is absent entirely is still an error
+        let dyn_enum = DynamicEnum::new("B", DynamicStruct::default());
+        let mut value = TestEnum::A;
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(result, Err(ApplyError::MissingEnumField { .. })),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    fn enum_should_derive_try_from() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(try_from)]
+        enum TestEnum {
+            A,
+            B(usize),
+            C { value: f32 },
+        }
+
+        let value: &dyn Reflect = &TestEnum::B(123);
+        assert_eq!(TestEnum::B(123), TestEnum::try_from(value).unwrap());
+
+        let value: &dyn Reflect = &TestEnum::C { value: 1.23 };
+        assert_eq!(
+            TestEnum::C { value: 1.23 },
+            TestEnum::try_from(value).unwrap()
+        );
+
+        // === Missing Field === //
+        let dyn_enum = DynamicEnum::new("C", DynamicStruct::default());
+        let result = TestEnum::try_from(&dyn_enum as &dyn Reflect);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::MissingEnumField { variant_name, field_name })
+                    if &**variant_name == "C" && &**field_name == "value"
+            ),
+            "`result` was {result:?}"
+        );
+
+        // === Mismatched Type === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(1.23_f32);
+        let dyn_enum = DynamicEnum::new("B", dyn_tuple);
+        let result = TestEnum::try_from(&dyn_enum as &dyn Reflect);
+        assert!(
+            matches!(result, Err(ApplyError::MismatchedTypes { .. })),
+            "`result` was {result:?}"
+        );
+
+        // === Unknown Variant === //
+        let dyn_enum = DynamicEnum::new("D", DynamicVariant::Unit);
+        let result = TestEnum::try_from(&dyn_enum as &dyn Reflect);
+        assert!(
+            matches!(result, Err(ApplyError::UnknownVariant { .. })),
+            "`result` was {result:?}"
+        );
+
+        // === Mismatched Kind === //
+        let result = TestEnum::try_from(&123_usize as &dyn Reflect);
+        assert!(
+            matches!(result, Err(ApplyError::MismatchedKinds { .. })),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    fn enum_should_allow_inline_string_fields() {
+        /// A fixed-capacity string stored inline.
+        #[derive(Reflect, Clone, Debug, PartialEq)]
+        #[reflect_value(from_reflect = false, Debug, PartialEq)]
+        struct InlineString<const N: usize> {
+            len: usize,
+            bytes: [u8; N],
+        }
+
+        impl<const N: usize> InlineString<N> {
+            fn new(value: &str) -> Option<Self> {
+                let len = value.len();
+                if len > N {
+                    return None;
+                }
+
+                let mut bytes = [0; N];
+                bytes[..len].copy_from_slice(value.as_bytes());
+                Some(Self { len, bytes })
+            }
+        }
+
+        impl<const N: usize> FromReflect for InlineString<N> {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                if let Some(value) = reflect.downcast_ref::<Self>() {
+                    Some(value.clone())
+                } else {
+                    Self::new(reflect.downcast_ref::<String>()?)
+                }
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A,
+            B(InlineString<4>),
+        }
+
+        // === Round Trip === //
+        let value = TestEnum::B(InlineString::new("abcd").unwrap());
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+
+        // === From Reflected String === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(String::from("abc"));
+        let dyn_enum = DynamicEnum::new("B", dyn_tuple);
+        assert_eq!(
+            Some(TestEnum::B(InlineString::new("abc").unwrap())),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        // === Exceeds Capacity === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(String::from("abcde"));
+        let dyn_enum = DynamicEnum::new("B", dyn_tuple);
+        assert_eq!(None, TestEnum::from_reflect(&dyn_enum));
+
+        let mut value = TestEnum::A;
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(result, Err(ApplyError::MismatchedTypes { .. })),
+            "`result` was {result:?}"
+        );
+        assert_eq!(TestEnum::A, value);
+    }
+
+    #[test]
+    fn enum_should_list_discriminant_names() {
+        const BASE: i8 = 10;
+
+        #[derive(Reflect)]
+        #[reflect(discriminant_names)]
+        #[repr(i8)]
+        enum TestEnum {
+            A,
+            B = 5,
+            C(usize),
+            D { value: f32 } = -3,
+            E,
+            F = BASE,
+        }
+
+        assert_eq!(
+            &[
+                (0, "A"),
+                (5, "B"),
+                (6, "C"),
+                (-3, "D"),
+                (-2, "E"),
+                (10, "F"),
+            ],
+            TestEnum::reflect_discriminant_names()
+        );
+    }
+
+    #[test]
+    fn enum_should_list_variant_names() {
+        #[derive(Reflect)]
+        #[reflect(variant_names)]
+        enum TestEnum {
+            A,
+            B(usize),
+            #[reflect(hidden)]
+            C,
+            #[reflect(alias = "Old")]
+            D {
+                value: f32,
+            },
+            #[reflect(ignore)]
+            #[allow(dead_code)]
+            E,
+        }
+
+        assert_eq!(&["A", "B", "D", "E"], TestEnum::reflect_variant_names());
+
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        assert_eq!(info.variant_names(), TestEnum::reflect_variant_names());
+    }
+
+    #[test]
+    fn enum_should_allow_ignored_unsafe_cell_fields() {
+        use std::cell::UnsafeCell;
+
+        #[derive(Reflect, Debug)]
+        enum TestEnum {
+            Cached {
+                key: String,
+                #[reflect(ignore)]
+                cache: UnsafeCell<u32>,
+            },
+            Empty,
+        }
+
+        // SAFETY: The cache is only ever accessed through `&mut self`.
+        #[allow(unsafe_code)]
+        unsafe impl Sync for TestEnum {}
+
+        impl TestEnum {
+            fn cache_mut(&mut self) -> Option<&mut u32> {
+                match self {
+                    TestEnum::Cached { cache, .. } => Some(cache.get_mut()),
+                    TestEnum::Empty => None,
+                }
+            }
+        }
+
+        let mut value = TestEnum::Cached {
+            key: String::from("foo"),
+            cache: UnsafeCell::new(123),
+        };
+        *value.cache_mut().unwrap() += 1;
+        assert_eq!(Some(&mut 124), value.cache_mut());
+
+        // The ignored cell is never read, so reconstruction falls back to its default
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(1, dyn_enum.field_len());
+        let mut reconstructed = TestEnum::from_reflect(&dyn_enum).unwrap();
+        assert_eq!(
+            Some("foo"),
+            reconstructed
+                .field("key")
+                .and_then(|field| field.downcast_ref::<String>())
+                .map(String::as_str)
+        );
+        assert_eq!(Some(&mut 0), reconstructed.cache_mut());
+
+        // Applying the same variant leaves the existing cell untouched
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("key", String::from("bar"));
+        value.apply(&DynamicEnum::new("Cached", dyn_struct));
+        assert_eq!(Some(&mut 124), value.cache_mut());
+    }
+
+    #[test]
+    fn enum_should_construct_default_variant() {
+        fn get_name() -> String {
+            String::from("unnamed")
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(default_variant)]
+        enum TestEnum {
+            A,
+            B(usize, #[reflect(ignore)] Option<f32>),
+            C {
+                #[reflect(default = "get_name")]
+                name: String,
+                #[reflect(default)]
+                value: i32,
+            },
+        }
+
+        assert_eq!(Some(TestEnum::A), TestEnum::reflect_default_variant("A"));
+        assert_eq!(
+            Some(TestEnum::B(0, None)),
+            TestEnum::reflect_default_variant("B")
+        );
+        assert_eq!(
+            Some(TestEnum::C {
+                name: String::from("unnamed"),
+                value: 0,
+            }),
+            TestEnum::reflect_default_variant("C")
+        );
+        assert_eq!(None, TestEnum::reflect_default_variant("D"));
+    }
+
+    #[test]
+    fn enum_try_apply_should_collect_all_errors() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(collect_apply_errors, try_from)]
+        enum TestEnum {
+            A,
+            B {
+                foo: usize,
+                bar: String,
+                #[reflect(ignore)]
+                baz: u8,
+            },
+            C(usize, f32),
+        }
+
+        // === Variant Switch === //
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 123_i32);
+        let dyn_enum = DynamicEnum::new("B", dyn_struct);
+
+        let mut value = TestEnum::A;
+        let Err(ApplyError::Multiple(errors)) = value.try_apply(&dyn_enum) else {
+            panic!("expected multiple errors");
+        };
+        assert_eq!(2, errors.len());
+        assert!(matches!(errors[0], ApplyError::MismatchedTypes { .. }));
+        assert!(
+            matches!(&errors[1], ApplyError::MissingEnumField { field_name, .. } if &**field_name == "bar")
+        );
+        assert_eq!(TestEnum::A, value);
+
+        assert!(matches!(
+            TestEnum::try_from(&dyn_enum as &dyn Reflect),
+            Err(ApplyError::Multiple(errors)) if errors.len() == 2
+        ));
+
+        // === Same Variant === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(String::from("foo"));
+        dyn_tuple.insert(String::from("bar"));
+        let dyn_enum = DynamicEnum::new("C", dyn_tuple);
+
+        let mut value = TestEnum::C(1, 2.0);
+        let Err(ApplyError::Multiple(errors)) = value.try_apply(&dyn_enum) else {
+            panic!("expected multiple errors");
+        };
+        assert_eq!(2, errors.len());
+
+        // === Success === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(3_usize);
+        dyn_tuple.insert(4.0_f32);
+        let dyn_enum = DynamicEnum::new("C", dyn_tuple);
+
+        let mut value = TestEnum::A;
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(TestEnum::C(3, 4.0), value);
+    }
+
+    #[test]
+    fn enum_should_from_reflect_by_discriminant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[repr(u8)]
+        enum TestEnum {
+            A,
+            B(usize) = 5,
+            C { value: f32 },
+        }
+
+        let dyn_enum = DynamicEnum::new("0", DynamicVariant::Unit);
+        assert_eq!(Some(TestEnum::A), TestEnum::from_reflect(&dyn_enum));
+
+        let dyn_enum = DynamicEnum::new("5", {
+            let mut dyn_tuple = DynamicTuple::default();
+            dyn_tuple.insert(123_usize);
+            dyn_tuple
+        });
+        assert_eq!(Some(TestEnum::B(123)), TestEnum::from_reflect(&dyn_enum));
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 1.5_f32);
+        let dyn_enum = DynamicEnum::new("6", dyn_struct);
+        assert_eq!(
+            Some(TestEnum::C { value: 1.5 }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        // Names still take precedence
+        let dyn_enum = DynamicEnum::new("B", {
+            let mut dyn_tuple = DynamicTuple::default();
+            dyn_tuple.insert(1_usize);
+            dyn_tuple
+        });
+        assert_eq!(Some(TestEnum::B(1)), TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_from_reflect_by_declaration_index_without_repr() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(i32),
+            B,
+        }
+
+        let dyn_enum = DynamicEnum::new("0", {
+            let mut dyn_tuple = DynamicTuple::default();
+            dyn_tuple.insert(1_i32);
+            dyn_tuple
+        });
+        assert_eq!(Some(TestEnum::A(1)), TestEnum::from_reflect(&dyn_enum));
+
+        let dyn_enum = DynamicEnum::new("1", DynamicVariant::Unit);
+        assert_eq!(Some(TestEnum::B), TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    #[should_panic(expected = "variant with name `2` does not exist")]
+    fn enum_from_reflect_should_panic_on_unknown_discriminant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A,
+            B,
+        }
+
+        let dyn_enum = DynamicEnum::new("2", DynamicVariant::Unit);
+        let _ = TestEnum::from_reflect(&dyn_enum);
+    }
+
+    #[test]
+    fn enum_clone_dynamic_should_omit_ignored_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A,
+            B(usize, #[reflect(ignore)] u8, String),
+            C {
+                #[reflect(ignore)]
+                ignored: u8,
+                data: f32,
+            },
+        }
+
+        let dyn_enum = TestEnum::A.clone_dynamic();
+        assert_eq!("A", dyn_enum.variant_name());
+        assert_eq!(0, dyn_enum.variant_index());
+        assert_eq!(VariantType::Unit, dyn_enum.variant_type());
+
+        let dyn_enum = TestEnum::B(1, 2, String::from("foo")).clone_dynamic();
+        assert_eq!("B", dyn_enum.variant_name());
+        assert_eq!(1, dyn_enum.variant_index());
+        assert_eq!(2, dyn_enum.field_len());
+        assert_eq!(
+            Some(&1),
+            dyn_enum.field_at(0).unwrap().downcast_ref::<usize>()
+        );
+        assert_eq!(
+            Some(&String::from("foo")),
+            dyn_enum.field_at(1).unwrap().downcast_ref::<String>()
+        );
+
+        let mut dyn_enum = TestEnum::C {
+            ignored: 3,
+            data: 4.0,
+        }
+        .clone_dynamic();
+        assert_eq!(2, dyn_enum.variant_index());
+        assert_eq!(1, dyn_enum.field_len());
+        assert!(dyn_enum.field("ignored").is_none());
+        assert!(dyn_enum
+            .get_represented_type_info()
+            .is_some_and(|info| info.is::<TestEnum>()));
+
+        // The dynamic copy is detached from the original
+        *dyn_enum
+            .field_mut("data")
+            .unwrap()
+            .downcast_mut::<f32>()
+            .unwrap() = 5.0;
+        assert_eq!(
+            Some(TestEnum::C {
+                ignored: 0,
+                data: 5.0,
+            }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+    }
+
+    #[test]
+    fn enum_should_use_fallible_default() {
+        fn parse_port() -> Result<u16, std::num::ParseIntError> {
+            "8080".parse()
+        }
+
+        fn parse_invalid() -> Result<u16, std::num::ParseIntError> {
+            "invalid".parse()
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A,
+            B {
+                #[reflect(try_default = "parse_port")]
+                port: u16,
+            },
+            C(#[reflect(try_default = "parse_invalid")] u16),
+        }
+
+        // === Successful Default === //
+        let dyn_enum = DynamicEnum::new("B", DynamicStruct::default());
+        assert_eq!(
+            Some(TestEnum::B { port: 8080 }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        let mut value = TestEnum::A;
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(TestEnum::B { port: 8080 }, value);
+
+        // === Failed Default === //
+        let dyn_enum = DynamicEnum::new("C", DynamicTuple::default());
+        assert_eq!(None, TestEnum::from_reflect(&dyn_enum));
+
+        let mut value = TestEnum::A;
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::FieldDefaultFailed { variant_name, field_name, error })
+                    if &**variant_name == "C" && &**field_name == ".0" && error.contains("invalid digit")
+            ),
+            "`result` was {result:?}"
+        );
+        assert_eq!(TestEnum::A, value);
+
+        // === Present Field === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(1_u16);
+        let dyn_enum = DynamicEnum::new("C", dyn_tuple);
+        assert_eq!(Some(TestEnum::C(1)), TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_accept_variant_aliases() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(try_from)]
+        enum TestEnum {
+            #[reflect(alias = "Round", alias = "Sphere")]
+            Circle(f32),
+            #[reflect(alias = "Rectangle")]
+            Rect {
+                width: f32,
+            },
+            Empty,
+        }
+
+        // === Type Info === //
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        assert_eq!(
+            &["Round", "Sphere"],
+            info.variant("Circle").unwrap().aliases()
+        );
+        assert!(info.variant("Empty").unwrap().aliases().is_empty());
+        assert_eq!(
+            Some("Circle"),
+            info.variant_by_alias("Sphere").map(VariantInfo::name)
+        );
+        assert!(info.variant("Round").is_none());
+
+        // === FromReflect === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(1.5_f32);
+        let dyn_enum = DynamicEnum::new("Round", dyn_tuple);
+        assert_eq!(
+            Some(TestEnum::Circle(1.5)),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+        assert_eq!(
+            TestEnum::Circle(1.5),
+            TestEnum::try_from(&dyn_enum as &dyn Reflect).unwrap()
+        );
+
+        // === Apply === //
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("width", 2.0_f32);
+        let dyn_enum = DynamicEnum::new("Rectangle", dyn_struct);
+
+        let mut value = TestEnum::Empty;
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(TestEnum::Rect { width: 2.0 }, value);
+    }
+
+    #[test]
+    fn enum_should_respect_cfg_on_variants() {
+        #[cfg(not(test))]
+        #[derive(Reflect)]
+        struct Missing;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(discriminant_names)]
+        enum TestEnum {
+            A,
+            #[cfg(not(test))]
+            B(Missing),
+            #[cfg(test)]
+            C {
+                value: usize,
+            },
+            D,
+        }
+
+        // === Type Info === //
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        assert_eq!(3, info.variant_len());
+        assert!(info.variant("B").is_none());
+        assert_eq!(Some(1), info.index_of("C"));
+
+        // === Variant Index === //
+        assert_eq!(0, TestEnum::A.variant_index());
+        assert_eq!(1, TestEnum::C { value: 1 }.variant_index());
+        assert_eq!(2, TestEnum::D.variant_index());
+        assert_eq!(2, TestEnum::D.clone_dynamic().variant_index());
+
+        // === Discriminants === //
+        assert_eq!(
+            &[(0, "A"), (1, "C"), (2, "D")],
+            TestEnum::reflect_discriminant_names()
+        );
+
+        // === FromReflect === //
+        let dyn_enum = DynamicEnum::new("2", DynamicVariant::Unit);
+        assert_eq!(Some(TestEnum::D), TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_map_unnamed_fields_around_ignored_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(#[reflect(ignore)] u8, u32),
+            B(u32, #[reflect(ignore)] u8, u32, #[reflect(ignore)] u8, u32),
+        }
+
+        // === Field Access === //
+        let value = TestEnum::B(1, 2, 3, 4, 5);
+        assert_eq!(3, value.field_len());
+        assert_eq!(Some(&3), value.field_at(1).unwrap().downcast_ref::<u32>());
+        assert_eq!(Some(&5), value.field_at(2).unwrap().downcast_ref::<u32>());
+        assert!(value.field_at(3).is_none());
+
+        // === FromReflect === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(123_u32);
+        let dyn_enum = DynamicEnum::new("A", dyn_tuple);
+        assert_eq!(Some(TestEnum::A(0, 123)), TestEnum::from_reflect(&dyn_enum));
+
+        let dyn_enum = TestEnum::B(1, 2, 3, 4, 5).clone_dynamic();
+        assert_eq!(
+            Some(TestEnum::B(1, 0, 3, 0, 5)),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        // === Apply === //
+        let mut value = TestEnum::B(0, 7, 0, 8, 0);
+        value.apply(&dyn_enum);
+        assert_eq!(TestEnum::B(1, 7, 3, 8, 5), value);
+
+        let mut value = TestEnum::A(9, 0);
+        value.apply(&dyn_enum);
+        assert_eq!(TestEnum::B(1, 0, 3, 0, 5), value);
+
+        // Missing fields are reported by their reflection index
+        let dyn_enum = DynamicEnum::new("A", DynamicTuple::default());
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::MissingEnumField { variant_name, field_name })
+                    if &**variant_name == "A" && &**field_name == ".0"
+            ),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    fn dynamic_enum_should_edit_variant_in_place() {
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 1_usize);
+        let mut dyn_enum = DynamicEnum::new("A", dyn_struct);
+
+        // === Mutable Access === //
+        assert!(dyn_enum.variant_mut().as_tuple_mut().is_none());
+        *dyn_enum
+            .variant_mut()
+            .as_struct_mut()
+            .unwrap()
+            .get_field_mut::<usize>("value")
+            .unwrap() = 2;
+        assert_eq!(
+            Some(&2),
+            dyn_enum.field("value").unwrap().downcast_ref::<usize>()
+        );
+
+        // === Same Variant === //
+        // Fields are updated through mutable references, so other fields are left untouched
+        dyn_enum
+            .variant_mut()
+            .as_struct_mut()
+            .unwrap()
+            .insert("extra", 3_u8);
+        let mut source = DynamicStruct::default();
+        source.insert("value", 4_usize);
+        dyn_enum.apply(&DynamicEnum::new("A", source));
+        let data = dyn_enum.variant().as_struct().unwrap();
+        assert_eq!(Some(&4), data.get_field::<usize>("value"));
+        assert_eq!(Some(&3), data.get_field::<u8>("extra"));
+
+        // === Different Variant === //
+        let mut source = DynamicTuple::default();
+        source.insert(5_i32);
+        dyn_enum.apply(&DynamicEnum::new("B", source));
+        let data = dyn_enum.variant_mut().as_tuple_mut().unwrap();
+        assert_eq!(Some(&5), data.field(0).unwrap().downcast_ref::<i32>());
+        assert!(dyn_enum.variant().as_struct().is_none());
+    }
+
+    #[test]
+    fn enum_should_from_reflect_with_split_variants() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(split_variants)]
+        enum TestEnum<T> {
+            A,
+            B(T, #[reflect(ignore)] u8),
+            C {
+                #[reflect(default)]
+                value: Option<T>,
+                other: String,
+            },
+        }
+
+        for value in [
+            TestEnum::A,
+            TestEnum::B(1_usize, 0),
+            TestEnum::C {
+                value: Some(2),
+                other: String::from("hello"),
+            },
+        ] {
+            let dyn_enum = value.clone_dynamic();
+            assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("other", String::from("world"));
+        let dyn_enum = DynamicEnum::new("2", dyn_struct);
+        assert_eq!(
+            Some(TestEnum::<usize>::C {
+                value: None,
+                other: String::from("world"),
+            }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        let dyn_enum = DynamicEnum::new("B", DynamicTuple::default());
+        assert_eq!(None, TestEnum::<usize>::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_use_with_module_for_fields() {
+        mod lenient {
+            use crate::{ApplyError, Reflect};
+
+            pub fn from_reflect(value: &dyn Reflect) -> Option<f32> {
+                value
+                    .downcast_ref::<f32>()
+                    .copied()
+                    .or_else(|| value.downcast_ref::<i32>().map(|value| *value as f32))
+            }
+
+            pub fn apply(value: &dyn Reflect) -> Result<f32, ApplyError> {
+                from_reflect(value).ok_or_else(|| ApplyError::MismatchedTypes {
+                    from_type: value.reflect_type_path().into(),
+                    to_type: "f32".into(),
+                })
+            }
+
+            pub fn clone(value: &f32) -> Box<dyn Reflect> {
+                Box::new(value.round() as i32)
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(#[reflect(with = "lenient")] f32),
+            B {
+                #[reflect(with = "lenient")]
+                value: f32,
+            },
+        }
+
+        // === clone === //
+        let dyn_enum = TestEnum::A(1.6).clone_dynamic();
+        assert_eq!(
+            Some(&2),
+            dyn_enum.field_at(0).unwrap().downcast_ref::<i32>()
+        );
+
+        // === from_reflect === //
+        assert_eq!(Some(TestEnum::A(2.0)), TestEnum::from_reflect(&dyn_enum));
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 1.5_f32);
+        let dyn_enum = DynamicEnum::new("B", dyn_struct);
+        assert_eq!(
+            Some(TestEnum::B { value: 1.5 }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        // === apply === //
+        let mut value = TestEnum::A(0.0);
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 3_i32);
+        value.try_apply(&DynamicEnum::new("B", dyn_struct)).unwrap();
+        assert_eq!(TestEnum::B { value: 3.0 }, value);
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(String::from("nope"));
+        let result = value.try_apply(&DynamicEnum::new("A", dyn_tuple));
+        assert!(
+            matches!(result, Err(ApplyError::MismatchedTypes { .. })),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn enum_should_pass_context_to_with_module() {
+        mod scaled {
+            use crate::{ApplyError, Reflect};
+
+            pub struct Scale(pub f32);
+
+            pub fn from_reflect(value: &dyn Reflect) -> Option<f32> {
+                value.downcast_ref::<f32>().copied()
+            }
+
+            pub fn from_reflect_with_context(value: &dyn Reflect, context: &Scale) -> Option<f32> {
+                from_reflect(value).map(|value| value * context.0)
+            }
+
+            pub fn apply(value: &dyn Reflect) -> Result<f32, ApplyError> {
+                from_reflect(value).ok_or_else(|| ApplyError::MismatchedTypes {
+                    from_type: value.reflect_type_path().into(),
+                    to_type: "f32".into(),
+                })
+            }
+
+            pub fn clone(value: &f32) -> Box<dyn Reflect> {
+                Box::new(*value)
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_reflect(context = scaled::Scale))]
+        enum TestEnum {
+            A(#[reflect(with = "scaled")] f32, f32),
+            B {
+                #[reflect(with = "scaled")]
+                value: f32,
+            },
+            C,
+        }
+
+        let scale = scaled::Scale(2.0);
+
+        let dyn_enum = TestEnum::A(1.5, 1.5).clone_dynamic();
+        assert_eq!(
+            Some(TestEnum::A(3.0, 1.5)),
+            TestEnum::from_reflect_with_context(&dyn_enum, &scale)
+        );
+        assert_eq!(
+            Some(TestEnum::A(1.5, 1.5)),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        let dyn_enum = TestEnum::B { value: 4.0 }.clone_dynamic();
+        assert_eq!(
+            Some(TestEnum::B { value: 8.0 }),
+            TestEnum::from_reflect_with_context(&dyn_enum, &scale)
+        );
+
+        assert_eq!(
+            Some(TestEnum::C),
+            TestEnum::from_reflect_with_context(&TestEnum::C, &scale)
+        );
+        assert_eq!(None, TestEnum::from_reflect_with_context(&1.0_f32, &scale));
+    }
+
+    #[test]
+    fn enum_reflect_hash_should_match_hash() {
+        use std::any::Any;
+        use std::hash::{Hash, Hasher};
+
+        #[derive(Reflect, Hash)]
+        #[reflect(Hash)]
+        enum TestEnum {
+            A,
+            B(usize, #[reflect(ignore)] u8),
+            C { value: String },
+        }
+
+        fn hash(value: &TestEnum) -> u64 {
+            let mut hasher = crate::utility::reflect_hasher();
+            Hash::hash(&Any::type_id(value), &mut hasher);
+            Hash::hash(value, &mut hasher);
+            hasher.finish()
+        }
+
+        for value in [
+            TestEnum::A,
+            TestEnum::B(1, 2),
+            TestEnum::C {
+                value: String::from("hello"),
+            },
+        ] {
+            assert_eq!(Some(hash(&value)), value.reflect_hash());
+        }
+
+        // Ignored fields still take part in `Hash`, so they must affect `reflect_hash` too
+        assert_ne!(
+            TestEnum::B(1, 2).reflect_hash(),
+            TestEnum::B(1, 3).reflect_hash()
+        );
+        assert_ne!(TestEnum::A.reflect_hash(), TestEnum::B(0, 0).reflect_hash());
+    }
+
+    #[test]
+    fn enum_should_construct_catch_all_variant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(try_from)]
+        enum TestEnum {
+            A,
+            B(usize),
+            #[reflect(catch_all)]
+            Other {
+                name: String,
+            },
+        }
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(123_usize);
+        let dyn_enum = DynamicEnum::new("B", dyn_tuple);
+        assert_eq!(Some(TestEnum::B(123)), TestEnum::from_reflect(&dyn_enum));
+
+        // === from_reflect === //
+        let dyn_enum = DynamicEnum::new("Unknown", DynamicVariant::Unit);
+        assert_eq!(
+            Some(TestEnum::Other {
+                name: String::from("Unknown")
+            }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        // Unknown names are not matched against discriminants when there is a catch-all variant
+        let dyn_discriminant = DynamicEnum::new("0", DynamicVariant::Unit);
+        assert_eq!(
+            Some(TestEnum::Other {
+                name: String::from("0")
+            }),
+            TestEnum::from_reflect(&dyn_discriminant)
+        );
+
+        // === try_from === //
+        assert_eq!(
+            TestEnum::Other {
+                name: String::from("Unknown")
+            },
+            TestEnum::try_from(&dyn_enum as &dyn Reflect).unwrap()
+        );
+
+        // === try_apply === //
+        let mut value = TestEnum::A;
+        value
+            .try_apply(&DynamicEnum::new("Newer", DynamicVariant::Unit))
+            .unwrap();
+        assert_eq!(
+            TestEnum::Other {
+                name: String::from("Newer")
+            },
+            value
+        );
+
+        // The catch-all variant round-trips like any other variant
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_use_closure_defaults() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A {
+                #[reflect(default = || Vec::with_capacity(8))]
+                values: Vec<usize>,
+                #[reflect(ignore, default = || 123)]
+                ignored: usize,
+            },
+        }
+
+        let dyn_enum = DynamicEnum::new("A", DynamicStruct::default());
+        let value = TestEnum::from_reflect(&dyn_enum).unwrap();
+        let TestEnum::A { values, ignored } = &value;
+        assert!(values.is_empty());
+        assert!(values.capacity() >= 8);
+        assert_eq!(123, *ignored);
+    }
+
+    #[test]
+    fn enum_try_apply_should_reject_ignored_fields_when_strict() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(strict_apply)]
+        enum TestEnum {
+            A(usize, #[reflect(ignore)] usize),
+            B {
+                foo: usize,
+                #[reflect(ignore)]
+                bar: usize,
+            },
+        }
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(1_usize);
+        dyn_tuple.insert(2_usize);
+        let tuple_enum = DynamicEnum::new("A", dyn_tuple);
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 1_usize);
+        dyn_struct.insert("bar", 2_usize);
+        let struct_enum = DynamicEnum::new("B", dyn_struct);
+
+        // === Same Variant === //
+        let mut value = TestEnum::A(0, 0);
+        let result = value.try_apply(&tuple_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::IgnoredFieldPresent { variant_name, field_name })
+                    if &**variant_name == "A" && &**field_name == ".1"
+            ),
+            "`result` was {result:?}"
+        );
+
+        // === Different Variant === //
+        let result = value.try_apply(&struct_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::IgnoredFieldPresent { variant_name, field_name })
+                    if &**variant_name == "B" && &**field_name == "bar"
+            ),
+            "`result` was {result:?}"
+        );
+        assert_eq!(TestEnum::A(1, 0), value);
+
+        // Values containing only reflected fields still apply
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 3_usize);
+        value.try_apply(&DynamicEnum::new("B", dyn_struct)).unwrap();
+        assert_eq!(TestEnum::B { foo: 3, bar: 0 }, value);
+    }
+
+    #[test]
+    fn enum_try_apply_should_reject_unknown_fields_when_denied() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(deny_unknown_fields)]
+        enum TestEnum {
+            A(usize),
+            B {
+                foo: usize,
+                #[reflect(ignore)]
+                bar: usize,
+            },
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 1_usize);
+        dyn_struct.insert("baz", 2_usize);
+        let unknown_enum = DynamicEnum::new("B", dyn_struct);
+
+        // === Different Variant === //
+        let mut value = TestEnum::A(0);
+        let result = value.try_apply(&unknown_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::UnknownField { variant_name, field_name })
+                    if &**variant_name == "B" && &**field_name == "baz"
+            ),
+            "`result` was {result:?}"
+        );
+        assert_eq!(TestEnum::A(0), value);
+
+        // === Same Variant === //
+        let mut value = TestEnum::B { foo: 0, bar: 0 };
+        let result = value.try_apply(&unknown_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::UnknownField { variant_name, field_name })
+                    if &**variant_name == "B" && &**field_name == "baz"
+            ),
+            "`result` was {result:?}"
+        );
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(1_usize);
+        dyn_tuple.insert(2_usize);
+        let mut value = TestEnum::A(0);
+        let result = value.try_apply(&DynamicEnum::new("A", dyn_tuple));
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::UnknownField { variant_name, field_name })
+                    if &**variant_name == "A" && &**field_name == ".1"
+            ),
+            "`result` was {result:?}"
+        );
+
+        // Ignored fields are still declared, so they are dropped rather than rejected
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 3_usize);
+        dyn_struct.insert("bar", 4_usize);
+        value.try_apply(&DynamicEnum::new("B", dyn_struct)).unwrap();
+        assert_eq!(TestEnum::B { foo: 3, bar: 0 }, value);
+    }
+
+    #[test]
+    fn enum_should_reflect_aliased_field_types() {
+        type Meters = f32;
+        type Pair<T> = (T, T);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum<T> {
+            A(Meters),
+            B { range: Pair<Meters>, value: T },
+        }
+
+        let value = TestEnum::B {
+            range: (1.0, 2.0),
+            value: 3_usize,
+        };
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+
+        let mut target = TestEnum::<usize>::A(0.0);
+        target.apply(&TestEnum::<usize>::A(5.0));
+        assert_eq!(TestEnum::A(5.0), target);
+
+        let TypeInfo::Enum(info) = TestEnum::<usize>::type_info() else {
+            panic!("Expected `TypeInfo::Enum`");
+        };
+        let VariantInfo::Tuple(variant) = info.variant("A").unwrap() else {
+            panic!("Expected `VariantInfo::Tuple`");
+        };
+        assert!(variant.field_at(0).unwrap().is::<f32>());
+    }
+
+    #[test]
+    fn enum_should_register_default_variant() {
+        #[derive(Reflect, Debug, PartialEq, Default)]
+        enum TestEnum {
+            A(usize),
+            #[default]
+            #[reflect(default)]
+            B,
+        }
+
+        let registration = TestEnum::get_type_registration();
+        let reflect_default = registration.data::<std_traits::ReflectDefault>().unwrap();
+        let value = reflect_default.default();
+        assert_eq!(Some(&TestEnum::B), value.downcast_ref::<TestEnum>());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "does not construct its `#[reflect(default)]` variant")]
+    fn enum_should_panic_on_mismatched_default_variant() {
+        #[derive(Reflect, Default)]
+        enum TestEnum {
+            #[default]
+            A,
+            #[reflect(default)]
+            B,
+        }
+
+        TestEnum::get_type_registration();
+    }
+
+    #[test]
+    fn enum_should_register_default_variant_from_field_defaults() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct NoDefault(usize);
+
+        fn default_value() -> NoDefault {
+            NoDefault(123)
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(NoDefault),
+            #[reflect(default)]
+            B {
+                #[reflect(default = "default_value")]
+                value: NoDefault,
+                count: usize,
+            },
+        }
+
+        let registration = TestEnum::get_type_registration();
+        let reflect_default = registration.data::<std_traits::ReflectDefault>().unwrap();
+        let value = reflect_default.default();
+        assert_eq!(
+            Some(&TestEnum::B {
+                value: NoDefault(123),
+                count: 0,
+            }),
+            value.downcast_ref::<TestEnum>()
+        );
+    }
+
+    #[test]
+    fn enum_should_from_reflect_with_debug_from_reflect() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(debug_from_reflect)]
+        enum TestEnum {
+            A(usize),
+            B { value: String },
+        }
+
+        let value = TestEnum::B {
+            value: String::from("hello"),
+        };
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(String::from("not a usize"));
+        let dyn_enum = DynamicEnum::new("A", dyn_tuple);
+        assert_eq!(None, TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_read_renamed_fields_from_previous_name() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A {
+                #[reflect(from = "old_value")]
+                value: usize,
+                #[reflect(from = "old_other", default)]
+                other: usize,
+            },
+        }
+
+        // === Previous Name === //
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("old_value", 1_usize);
+        dyn_struct.insert("old_other", 2_usize);
+        let dyn_enum = DynamicEnum::new("A", dyn_struct);
+        assert_eq!(
+            Some(TestEnum::A { value: 1, other: 2 }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        // === Current Name === //
+        let value = TestEnum::A { value: 3, other: 4 };
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+
+        // === Missing === //
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 5_usize);
+        let dyn_enum = DynamicEnum::new("A", dyn_struct);
+        assert_eq!(
+            Some(TestEnum::A { value: 5, other: 0 }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+    }
+
+    #[test]
+    fn enum_try_apply_should_widen_numeric_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(coerce_numeric)]
+        enum TestEnum {
+            A { value: i64, ratio: f64 },
+            B(i32),
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 123_i32);
+        dyn_struct.insert("ratio", 0.5_f32);
+        let dyn_enum = DynamicEnum::new("A", dyn_struct);
+
+        // === Different Variant === //
+        let mut value = TestEnum::B(0);
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(
+            TestEnum::A {
+                value: 123,
+                ratio: 0.5
+            },
+            value
+        );
+
+        // === Same Variant === //
+        let mut value = TestEnum::A {
+            value: 0,
+            ratio: 0.0,
+        };
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(
+            TestEnum::A {
+                value: 123,
+                ratio: 0.5
+            },
+            value
+        );
+
+        // === Lossy === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(123_i64);
+        let dyn_enum = DynamicEnum::new("B", dyn_tuple);
+        let mut value = TestEnum::A {
+            value: 0,
+            ratio: 0.0,
+        };
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(result, Err(ApplyError::MismatchedTypes { .. })),
+            "Incorrect error type"
+        );
+    }
+
+    #[test]
+    fn enum_should_reflect_static_reference_fields() {
+        use std::borrow::Cow;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Tag {
+            Named(&'static str),
+            Owned { name: Cow<'static, str> },
+        }
+
+        let value = Tag::Named("hello");
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), Tag::from_reflect(&dyn_enum));
+
+        let value = Tag::Owned {
+            name: Cow::Borrowed("hello"),
+        };
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), Tag::from_reflect(&dyn_enum));
+
+        let mut value = Tag::Named("hello");
+        value.apply(&dyn_enum);
+        assert_eq!(
+            Tag::Owned {
+                name: Cow::Borrowed("hello")
+            },
+            value
+        );
+    }
+
+    #[test]
+    fn enum_try_apply_should_only_overwrite_present_fields_of_same_variant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A { foo: usize, bar: String },
+            B(usize, #[reflect(default)] usize),
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 123_usize);
+        let dyn_enum = DynamicEnum::new("A", dyn_struct);
+
+        // === Same Variant === //
+        let mut value = TestEnum::A {
+            foo: 0,
+            bar: String::from("hello"),
+        };
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(
+            TestEnum::A {
+                foo: 123,
+                bar: String::from("hello")
+            },
+            value
+        );
+
+        // === Variant Change === //
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(123_usize);
+        let dyn_enum = DynamicEnum::new("B", dyn_tuple);
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(TestEnum::B(123, 0), value);
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 123_usize);
+        let dyn_enum = DynamicEnum::new("A", dyn_struct);
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(result, Err(ApplyError::MissingEnumField { .. })),
+            "Incorrect error type"
+        );
+    }
+
+    #[test]
+    fn enum_should_construct_phantom_data_fields() {
+        use std::marker::PhantomData;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum<T> {
+            A(PhantomData<T>, u32),
+        }
+
+        let value = TestEnum::<u8>::A(PhantomData, 123);
+        assert_eq!(1, value.field_len());
+        assert_eq!(Some(&123_u32), value.field_at(0).unwrap().downcast_ref());
+
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), TestEnum::<u8>::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_from_reflect_by_tag() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_tag)]
+        #[repr(u8)]
+        enum Packet {
+            Ping,
+            Move { x: i32, y: i32 } = 4,
+            Chat(String),
+        }
+
+        assert_eq!(Some(Packet::Ping), Packet::from_tag(0, &()));
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("x", 1_i32);
+        dyn_struct.insert("y", 2_i32);
+        assert_eq!(
+            Some(Packet::Move { x: 1, y: 2 }),
+            Packet::from_tag(4, &dyn_struct)
+        );
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(String::from("hello"));
+        assert_eq!(
+            Some(Packet::Chat(String::from("hello"))),
+            Packet::from_tag(5, &dyn_tuple)
+        );
+
+        let value = Packet::Chat(String::from("hello"));
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(value), Packet::from_tag(5, &dyn_enum));
+
+        assert_eq!(None, Packet::from_tag(1, &()));
+        assert_eq!(None, Packet::from_tag(4, &dyn_tuple));
+    }
+
+    #[test]
+    fn enum_should_from_reflect_by_const_expression_tag() {
+        const BASE: u16 = 0x100;
+        const fn shifted(index: u16) -> u16 {
+            BASE << index
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_tag, discriminant_names)]
+        #[repr(u16)]
+        enum Opcode {
+            Nop = BASE,
+            // Implicit discriminants continue on from the const expression
+            Load,
+            Store { address: u16 } = shifted(2),
+            Halt = BASE * 8 - 1,
+        }
+
+        assert_eq!(
+            &[
+                (0x100, "Nop"),
+                (0x101, "Load"),
+                (0x400, "Store"),
+                (0x7FF, "Halt"),
+            ],
+            Opcode::reflect_discriminant_names()
+        );
+        assert_eq!(Some(Opcode::Nop), Opcode::from_tag(BASE, &()));
+        assert_eq!(Some(Opcode::Load), Opcode::from_tag(BASE + 1, &()));
+        assert_eq!(Some(Opcode::Halt), Opcode::from_tag(0x7FF, &()));
+
+        let value = Opcode::Store { address: 12 };
+        assert_eq!(
+            Some(&value),
+            Opcode::from_tag(shifted(2), &value.clone_dynamic()).as_ref()
+        );
+        assert_eq!(None, Opcode::from_tag(0, &()));
+    }
+
+    #[test]
+    fn enum_should_round_trip_sparse_discriminants_by_tag() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_tag, discriminant_names)]
+        #[repr(u8)]
+        enum Sparse {
+            A = 1,
+            B { value: u32 } = 5,
+            C(String) = 200,
+        }
+
+        let values = [
+            Sparse::A,
+            Sparse::B { value: 123 },
+            Sparse::C(String::from("hello")),
+        ];
+        for value in values {
+            let (tag, _) = Sparse::reflect_discriminant_names()
+                .iter()
+                .find(|(_, name)| *name == value.variant_name())
+                .unwrap();
+            let dyn_enum = value.clone_dynamic();
+            assert_eq!(Some(&value), Sparse::from_tag(*tag, &dyn_enum).as_ref());
+
+            // Matching by name is unaffected by the gaps
+            assert_eq!(Some(value), Sparse::from_reflect(&dyn_enum));
+        }
+
+        // Tags within the gaps, or past the last discriminant, belong to no variant
+        for tag in [0, 2, 4, 6, 199, 201] {
+            assert_eq!(None, Sparse::from_tag(tag, &()));
+        }
+    }
+
+    #[test]
+    fn enum_should_initialize_ignored_fields_without_default() {
+        #[derive(Debug, PartialEq)]
+        struct Cache(Vec<usize>);
+
+        fn build_cache() -> Cache {
+            Cache(vec![1, 2, 3])
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A {
+                value: usize,
+                #[reflect(ignore, default = "build_cache")]
+                cache: Cache,
+            },
+            B(usize),
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 123_usize);
+        let dyn_enum = DynamicEnum::new("A", dyn_struct);
+
+        let expected = TestEnum::A {
+            value: 123,
+            cache: build_cache(),
+        };
+        assert_eq!(Some(&expected), TestEnum::from_reflect(&dyn_enum).as_ref());
+
+        let mut value = TestEnum::B(0);
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(expected, value);
+    }
+
+    #[test]
+    fn enum_should_reflect_transparently() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Foo {
+            a: usize,
+            b: usize,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(transparent)]
+        enum Wrapper {
+            Foo(Foo),
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(transparent)]
+        enum Id {
+            Value { id: u32 },
+        }
+
+        // === Struct Field === //
+        let mut value = Wrapper::Foo(Foo { a: 1, b: 2 });
+        assert!(matches!(value.reflect_ref(), ReflectRef::Struct(_)));
+        assert_eq!(
+            Foo::type_path(),
+            value.get_represented_type_info().unwrap().type_path()
+        );
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("b", 3_usize);
+        value.try_apply(&dyn_struct).unwrap();
+        assert_eq!(Wrapper::Foo(Foo { a: 1, b: 3 }), value);
+
+        let cloned = value.clone_value();
+        assert!(cloned.is::<DynamicStruct>());
+        assert_eq!(Some(&value), Wrapper::from_reflect(&*cloned).as_ref());
+        assert_eq!(Some(&value), Wrapper::from_reflect(&value).as_ref());
+
+        // === Value Field === //
+        let mut value = Id::Value { id: 1 };
+        assert!(matches!(value.reflect_ref(), ReflectRef::Value(_)));
+        assert_eq!(Some(Id::Value { id: 2 }), Id::from_reflect(&2_u32));
+
+        value.try_apply(&Id::Value { id: 3 }).unwrap();
+        assert_eq!(Id::Value { id: 3 }, value);
+        assert_eq!(Some(true), value.reflect_partial_eq(&3_u32));
+    }
+
+    #[test]
+    fn enum_should_report_from_dynamic_enum_errors() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_dynamic_enum)]
+        enum TestEnum {
+            A(usize),
+            B { value: String },
+        }
+
+        let value = TestEnum::B {
+            value: String::from("hello"),
+        };
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(value, TestEnum::from_dynamic_enum(&dyn_enum).unwrap());
+
+        let dyn_enum = DynamicEnum::new("C", ());
+        assert_eq!(
+            FromReflectError::UnknownVariant {
+                enum_name: TestEnum::type_path().into(),
+                variant_name: "C".into(),
+            },
+            TestEnum::from_dynamic_enum(&dyn_enum).unwrap_err()
+        );
+
+        let dyn_enum = DynamicEnum::new("B", DynamicStruct::default());
+        assert_eq!(
+            FromReflectError::MissingField {
+                variant_name: "B".into(),
+                field_name: "value".into(),
+            },
+            TestEnum::from_dynamic_enum(&dyn_enum).unwrap_err()
+        );
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(String::from("not a usize"));
+        let dyn_enum = DynamicEnum::new("A", dyn_tuple);
+        assert_eq!(
+            FromReflectError::MismatchedTypes {
+                variant_name: "A".into(),
+                field_name: ".0".into(),
+                from_type: String::type_path().into(),
+                to_type: usize::type_path().into(),
+            },
+            TestEnum::from_dynamic_enum(&dyn_enum).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn enum_should_apply_struct_to_active_variant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(apply_struct_to_variant)]
+        enum TestEnum {
+            A(usize),
+            B { foo: usize, bar: bool },
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 123_usize);
+
+        let mut value = TestEnum::B { foo: 0, bar: true };
+        value.try_apply(&dyn_struct).unwrap();
+        assert_eq!(
+            TestEnum::B {
+                foo: 123,
+                bar: true
+            },
+            value
+        );
+
+        // The active variant is never changed
+        let mut value = TestEnum::A(0);
+        assert!(matches!(
+            value.try_apply(&dyn_struct),
+            Err(ApplyError::MismatchedKinds {
+                from_kind: ReflectKind::Struct,
+                to_kind: ReflectKind::Enum,
+            })
+        ));
+        assert_eq!(TestEnum::A(0), value);
+    }
+
+    #[test]
+    fn enum_should_from_reflect_tuple_variant_from_named_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(u32, u32),
+        }
+
+        let mut data = DynamicTuple::default();
+        data.insert(1_u32);
+        data.insert(2_u32);
+        let dyn_enum = DynamicEnum::new("A", data);
+        assert_eq!(Some(TestEnum::A(1, 2)), TestEnum::from_reflect(&dyn_enum));
+
+        // Named fields are matched by name rather than by order
+        let mut data = DynamicStruct::default();
+        data.insert("1", 2_u32);
+        data.insert("0", 1_u32);
+        let dyn_enum = DynamicEnum::new("A", data);
+        assert_eq!(Some(TestEnum::A(1, 2)), TestEnum::from_reflect(&dyn_enum));
+
+        // A missing named field is not taken from another field's position
+        let mut data = DynamicStruct::default();
+        data.insert("1", 2_u32);
+        let dyn_enum = DynamicEnum::new("A", data);
+        assert_eq!(None, TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_compare_ptr_eq_fields_by_pointer() {
+        use std::sync::Arc;
+
+        #[derive(Reflect)]
+        enum TestEnum {
+            A(#[reflect(eq = "ptr")] Arc<String>),
+            B {
+                #[reflect(eq = "ptr")]
+                value: Arc<String>,
+            },
+        }
+
+        let shared = Arc::new(String::from("hello"));
+
+        let value = TestEnum::A(shared.clone());
+        assert_eq!(
+            Some(true),
+            value.reflect_partial_eq(&TestEnum::A(shared.clone()))
+        );
+        assert_eq!(Some(true), value.reflect_partial_eq(&*value.clone_value()));
+
+        let value = TestEnum::B {
+            value: shared.clone(),
+        };
+        assert_eq!(Some(true), value.reflect_partial_eq(&*value.clone_value()));
+
+        // Separate allocations are compared by their contents, which `Arc` cannot do
+        let other = TestEnum::B {
+            value: Arc::new(String::from("hello")),
+        };
+        assert_eq!(Some(false), value.reflect_partial_eq(&other));
+    }
+
+    #[test]
+    fn enum_should_try_apply_with_cold_errors() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(cold_errors)]
+        enum TestEnum {
+            A(usize),
+            B { value: String },
+        }
+
+        let mut value = TestEnum::A(0);
+        value
+            .try_apply(&TestEnum::B {
+                value: String::from("hello"),
+            })
+            .unwrap();
+        assert_eq!(
+            TestEnum::B {
+                value: String::from("hello")
+            },
+            value
+        );
+
+        let dyn_enum = DynamicEnum::new("A", ());
+        assert!(matches!(
+            value.try_apply(&dyn_enum),
+            Err(ApplyError::MismatchedVariantTypes { .. })
+        ));
+
+        let dyn_enum = DynamicEnum::new("C", ());
+        assert!(matches!(
+            value.try_apply(&dyn_enum),
+            Err(ApplyError::UnknownVariant { .. })
+        ));
+
+        assert!(matches!(
+            value.try_apply(&123_usize),
+            Err(ApplyError::MismatchedKinds { .. })
+        ));
+    }
+
+    #[test]
+    fn enum_should_from_reflect_by_wide_or_signed_tag() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_tag, discriminant_names)]
+        #[repr(u128)]
+        enum WideId {
+            Small,
+            Large = u128::MAX - 1,
+            Largest,
+        }
+
+        assert_eq!(Some(WideId::Small), WideId::from_tag(0, &()));
+        assert_eq!(Some(WideId::Large), WideId::from_tag(u128::MAX - 1, &()));
+        assert_eq!(Some(WideId::Largest), WideId::from_tag(u128::MAX, &()));
+        assert_eq!(
+            &[
+                (0, "Small"),
+                (u128::MAX - 1, "Large"),
+                (u128::MAX, "Largest")
+            ],
+            WideId::reflect_discriminant_names()
+        );
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_tag, discriminant_names)]
+        #[repr(i32)]
+        enum SignedId {
+            Negative = -2,
+            AlsoNegative,
+            Zero,
+        }
+
+        assert_eq!(Some(SignedId::Negative), SignedId::from_tag(-2, &()));
+        assert_eq!(Some(SignedId::AlsoNegative), SignedId::from_tag(-1, &()));
+        assert_eq!(Some(SignedId::Zero), SignedId::from_tag(0, &()));
+        assert_eq!(
+            &[(-2, "Negative"), (-1, "AlsoNegative"), (0, "Zero")],
+            SignedId::reflect_discriminant_names()
+        );
+
+        // Names holding a discriminant are parsed using the same integer type
+        let dyn_enum = DynamicEnum::new(u128::MAX.to_string(), ());
+        assert_eq!(Some(WideId::Largest), WideId::from_reflect(&dyn_enum));
+        let dyn_enum = DynamicEnum::new("-1", ());
+        assert_eq!(
+            Some(SignedId::AlsoNegative),
+            SignedId::from_reflect(&dyn_enum)
+        );
+    }
+
+    #[test]
+    fn enum_should_report_first_failing_field_in_declaration_order() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_dynamic_enum)]
+        enum TestEnum {
+            A {
+                first: usize,
+                #[reflect(ignore)]
+                ignored: usize,
+                second: usize,
+            },
+        }
+
+        // Both fields fail, and are given in the opposite order to their declaration
+        let mut data = DynamicStruct::default();
+        data.insert("second", String::from("not a usize"));
+        data.insert("first", String::from("not a usize"));
+        let dyn_enum = DynamicEnum::new("A", data);
+
+        assert_eq!(
+            FromReflectError::MismatchedTypes {
+                variant_name: "A".into(),
+                field_name: "first".into(),
+                from_type: String::type_path().into(),
+                to_type: usize::type_path().into(),
+            },
+            TestEnum::from_dynamic_enum(&dyn_enum).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn enum_should_preserve_ignored_fields_on_same_variant_apply() {
+        use std::sync::Arc;
+
+        #[derive(Reflect)]
+        enum TestEnum {
+            A {
+                value: f32,
+                count: usize,
+                #[reflect(ignore)]
+                shared: Arc<String>,
+            },
+            B(#[reflect(ignore)] Arc<String>, usize),
+        }
+
+        let shared = Arc::new(String::from("asset"));
+
+        // === Struct Variant === //
+        let mut value = TestEnum::A {
+            value: 1.0,
+            count: 1,
+            shared: Arc::clone(&shared),
+        };
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 2.0_f32);
+        value.try_apply(&DynamicEnum::new("A", dyn_struct)).unwrap();
+
+        let TestEnum::A {
+            value,
+            count,
+            shared: preserved,
+        } = value
+        else {
+            panic!("expected variant `A`");
+        };
+        assert_eq!((2.0, 1), (value, count));
+        assert!(Arc::ptr_eq(&shared, &preserved));
+
+        // === Tuple Variant === //
+        let mut value = TestEnum::B(Arc::clone(&shared), 1);
+        value.apply(&TestEnum::B(Arc::new(String::from("other")), 2));
+
+        let TestEnum::B(preserved, count) = value else {
+            panic!("expected variant `B`");
+        };
+        assert_eq!(2, count);
+        assert!(Arc::ptr_eq(&shared, &preserved));
+    }
+
+    #[test]
+    fn enum_should_not_reflect_ignored_variants() {
+        #[derive(Reflect)]
+        #[reflect(from_dynamic_enum)]
+        enum TestEnum {
+            Named(String),
+            #[reflect(ignore)]
+            Native(Box<dyn Fn() -> usize + Send + Sync>),
+        }
+
+        let native = TestEnum::Native(Box::new(|| 123));
+        assert_eq!("Native", native.variant_name());
+        assert_eq!(0, native.field_len());
+        assert!(native.field_at(0).is_none());
+
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        assert!(info.contains_variant("Native"));
+
+        // Ignored variants are never constructed
+        let dyn_enum = DynamicEnum::new("Native", DynamicTuple::default());
+        assert!(TestEnum::from_reflect(&dyn_enum).is_none());
+        assert_eq!(
+            FromReflectError::IgnoredVariant {
+                variant_name: "Native".into(),
+            },
+            TestEnum::from_dynamic_enum(&dyn_enum).err().unwrap()
+        );
+
+        let mut value = TestEnum::Named(String::from("hello"));
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::IgnoredVariant { variant_name }) if &**variant_name == "Native"
+            ),
+            "`result` was {result:?}"
+        );
+        assert!(matches!(value, TestEnum::Named(ref name) if name == "hello"));
+
+        // Nor are they applied to
+        let mut value = native;
+        let mut data = DynamicTuple::default();
+        data.insert(String::from("hello"));
+        let result = value.try_apply(&DynamicEnum::new("Named", data));
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::IgnoredVariant { variant_name }) if &**variant_name == "Native"
+            ),
+            "`result` was {result:?}"
+        );
+        assert!(matches!(value, TestEnum::Native(ref f) if f() == 123));
+    }
+
+    #[test]
+    fn enum_should_reflect_macro_generated_variants() {
+        macro_rules! define_enum {
+            ($name:ident { $($variant:ident($($ty:ty),*) { $($field:ident: $field_ty:ty),* }),* }) => {
+                #[derive(Reflect, Debug, PartialEq)]
+                #[reflect(collect_apply_errors, try_from)]
+                enum $name {
+                    $(
+                        $variant($($ty),*),
+                    )*
+                    Named { $($($field: $field_ty),*),* },
+                }
+            };
+        }
+
+        define_enum!(TestEnum {
+            A(usize, usize) { a: usize, b: String },
+            B(String) { c: f32 }
+        });
+
+        let mut value = TestEnum::A(1, 2);
+        value.apply(&TestEnum::A(3, 4));
+        assert_eq!(TestEnum::A(3, 4), value);
+
+        let named = TestEnum::Named {
+            a: 1,
+            b: String::from("b"),
+            c: 2.0,
+        };
+        value.apply(&named);
+        assert_eq!(named, value);
+
+        assert_eq!(
+            Some(TestEnum::B(String::from("b"))),
+            TestEnum::from_reflect(&TestEnum::B(String::from("b")))
+        );
+        assert_eq!(
+            TestEnum::A(5, 6),
+            TestEnum::try_from(&TestEnum::A(5, 6) as &dyn Reflect).unwrap()
+        );
+    }
+
+    #[test]
+    fn enum_field_names_should_not_collide_with_generated_locals() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(collect_apply_errors, try_from)]
+        enum TestEnum {
+            A {
+                value: usize,
+                _value: usize,
+                _field_0: usize,
+                __param0: usize,
+            },
+            B(usize),
+        }
+
+        let a = TestEnum::A {
+            value: 1,
+            _value: 2,
+            _field_0: 3,
+            __param0: 4,
+        };
+
+        assert_eq!(Some(&a), TestEnum::from_reflect(&a).as_ref());
+        assert_eq!(a, TestEnum::try_from(&a as &dyn Reflect).unwrap());
+
+        let mut value = TestEnum::B(0);
+        value.apply(&a);
+        assert_eq!(a, value);
+    }
+
+    #[test]
+    fn enum_should_apply_to_boxed_trait_object_fields() {
+        trait Shape: Reflect {}
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Circle {
+            radius: f32,
+        }
+
+        impl Shape for Circle {}
+
+        #[derive(Reflect)]
+        enum Slot {
+            Empty,
+            Filled(#[reflect(apply_via = "downcast")] Box<dyn Shape>),
+        }
+
+        let mut slot = Slot::Filled(Box::new(Circle { radius: 1.0 }));
+        assert_eq!(
+            Some(&Circle { radius: 1.0 }),
+            slot.field_at(0).unwrap().downcast_ref::<Circle>()
+        );
+
+        let TypeInfo::Enum(info) = Slot::type_info() else {
+            panic!("expected enum info");
+        };
+        let Some(VariantInfo::Tuple(info)) = info.variant("Filled") else {
+            panic!("expected tuple variant");
+        };
+        assert_eq!(
+            std::any::TypeId::of::<dyn Reflect>(),
+            info.field_at(0).unwrap().type_id()
+        );
+
+        // Updates are applied to the concrete value behind the box
+        let mut radius = DynamicStruct::default();
+        radius.insert("radius", 2.0_f32);
+        let mut data = DynamicTuple::default();
+        data.insert(radius);
+        let dyn_enum = DynamicEnum::new("Filled", data);
+
+        slot.try_apply(&dyn_enum).unwrap();
+        let Slot::Filled(shape) = &slot else {
+            panic!("expected `Slot::Filled`");
+        };
+        assert_eq!(
+            Some(&Circle { radius: 2.0 }),
+            shape.as_reflect().downcast_ref::<Circle>()
+        );
+        assert!(slot.reflect_partial_eq(&dyn_enum).unwrap_or_default());
+
+        // The concrete type is unknown, so the variant cannot be constructed
+        let mut empty = Slot::Empty;
+        let result = empty.try_apply(&dyn_enum);
+        assert!(
+            matches!(result, Err(ApplyError::MismatchedTypes { .. })),
+            "`result` was {result:?}"
+        );
+        assert!(Slot::from_reflect(&dyn_enum).is_none());
+    }
+
+    #[test]
+    fn enum_should_construct_boxed_trait_object_fields_from_registry() {
+        #[reflect_trait]
+        trait Shape: Reflect {
+            fn name(&self) -> &str;
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Shape)]
+        struct Circle {
+            radius: f32,
+        }
+
+        impl Shape for Circle {
+            fn name(&self) -> &str {
+                "circle"
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Shape)]
+        struct Square {
+            side: f32,
+        }
+
+        impl Shape for Square {
+            fn name(&self) -> &str {
+                "square"
+            }
+        }
+
+        #[derive(Reflect)]
+        #[reflect(from_reflect(context = TypeRegistry))]
+        enum Slot {
+            Empty,
+            Filled(#[reflect(apply_via = "downcast")] Box<dyn Shape>),
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Circle>();
+        registry.register::<Square>();
+
+        let circle = Slot::Filled(Box::new(Circle { radius: 1.0 })).clone_dynamic();
+        let Some(Slot::Filled(shape)) = Slot::from_reflect_with_context(&circle, &registry) else {
+            panic!("expected `Slot::Filled`");
+        };
+        assert_eq!("circle", shape.name());
+        assert_eq!(
+            Some(&Circle { radius: 1.0 }),
+            shape.as_reflect().downcast_ref::<Circle>()
+        );
+
+        let square = Slot::Filled(Box::new(Square { side: 2.0 })).clone_dynamic();
+        let Some(Slot::Filled(shape)) = Slot::from_reflect_with_context(&square, &registry) else {
+            panic!("expected `Slot::Filled`");
+        };
+        assert_eq!("square", shape.name());
+        assert_eq!(
+            Some(&Square { side: 2.0 }),
+            shape.as_reflect().downcast_ref::<Square>()
+        );
+
+        // Without a registry, the concrete type cannot be found
+        assert!(Slot::from_reflect(&circle).is_none());
+        assert!(Slot::from_reflect_with_context(&circle, &TypeRegistry::empty()).is_none());
+        assert!(matches!(
+            Slot::from_reflect_with_context(&Slot::Empty.clone_dynamic(), &TypeRegistry::empty()),
+            Some(Slot::Empty)
+        ));
+    }
+
+    #[test]
+    fn enum_from_dynamic_enum_errors_should_be_sendable() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_dynamic_enum)]
+        enum TestEnum {
+            A { value: usize },
+        }
+
+        // The error owns its names, so it outlives the value it was created from
+        let error = {
+            let dyn_enum = DynamicEnum::new("A", DynamicStruct::default());
+            TestEnum::from_dynamic_enum(&dyn_enum).unwrap_err()
+        };
+
+        let error = std::thread::spawn(move || error).join().unwrap();
+        assert_eq!(
+            FromReflectError::MissingField {
+                variant_name: "A".into(),
+                field_name: "value".into(),
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn enum_try_apply_should_reject_mismatched_variant_types() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A,
+            B(usize),
+            C { foo: usize },
+        }
+
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(1_usize);
+        let tuple_enum = DynamicEnum::new("C", dyn_tuple);
+
+        let mut value = TestEnum::A;
+        let result = value.try_apply(&tuple_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::MismatchedVariantTypes { variant_name, expected, found })
+                    if &**variant_name == "C"
+                        && *expected == VariantType::Struct
+                        && *found == VariantType::Tuple
+            ),
+            "`result` was {result:?}"
+        );
+        assert_eq!(TestEnum::A, value);
+
+        let unit_enum = DynamicEnum::new("B", ());
+        let result = value.try_apply(&unit_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::MismatchedVariantTypes { variant_name, expected, found })
+                    if &**variant_name == "B"
+                        && *expected == VariantType::Tuple
+                        && *found == VariantType::Unit
+            ),
+            "`result` was {result:?}"
+        );
+
+        // Tuple variants may still be given struct-like data
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("0", 1_usize);
+        let struct_enum = DynamicEnum::new("B", dyn_struct);
+        value.try_apply(&struct_enum).unwrap();
+        assert_eq!(TestEnum::B(1), value);
+    }
+
+    #[test]
+    fn enum_try_apply_should_only_look_up_type_paths_on_error() {
+        thread_local! {
+            static TYPE_PATH_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(type_path = false)]
+        struct Counted(usize);
+
+        impl TypePath for Counted {
+            fn type_path() -> &'static str {
+                TYPE_PATH_CALLS.with(|calls| calls.set(calls.get() + 1));
+                "bevy_reflect::enums::tests::Counted"
+            }
+
+            fn short_type_path() -> &'static str {
+                "Counted"
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A,
+            B(Counted),
+        }
+
+        let mut data = DynamicTuple::default();
+        data.insert(Counted(123));
+        let dyn_enum = DynamicEnum::new("B", data);
+
+        let mut value = TestEnum::A;
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(TestEnum::B(Counted(123)), value);
+        assert_eq!(0, TYPE_PATH_CALLS.with(std::cell::Cell::get));
+
+        let mut data = DynamicTuple::default();
+        data.insert(123_usize);
+        let dyn_enum = DynamicEnum::new("B", data);
+
+        let mut value = TestEnum::A;
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(&result, Err(ApplyError::MismatchedTypes { .. })),
+            "`result` was {result:?}"
+        );
+        assert_eq!(1, TYPE_PATH_CALLS.with(std::cell::Cell::get));
+    }
+
+    #[test]
+    fn enum_variant_info_should_list_required_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A,
+            B(
+                usize,
+                #[reflect(ignore)] usize,
+                #[reflect(default)] usize,
+                usize,
+            ),
+            C {
+                #[reflect(default)]
+                foo: usize,
+                bar: usize,
+            },
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_reflect(default))]
+        enum DefaultedEnum {
+            A(usize),
+        }
+
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected `TypeInfo::Enum`");
+        };
+        assert!(info.variant("A").unwrap().required_fields().is_empty());
+        // Ignored fields are not reflected, so do not take up an index
+        assert_eq!(&[0, 2], info.variant("B").unwrap().required_fields());
+
+        let VariantInfo::Struct(variant) = info.variant("C").unwrap() else {
+            panic!("expected `VariantInfo::Struct`");
+        };
+        assert_eq!(&[1], variant.required_fields());
+        assert_eq!("bar", variant.field_at(1).unwrap().name());
+
+        let TypeInfo::Enum(info) = DefaultedEnum::type_info() else {
+            panic!("expected `TypeInfo::Enum`");
+        };
+        assert!(info.variant("A").unwrap().required_fields().is_empty());
+    }
+
+    #[test]
+    fn enum_clone_should_not_substitute_field_defaults() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A {
+                #[reflect(default = "some_value")]
+                value: Option<usize>,
+            },
+        }
+
+        fn some_value() -> Option<usize> {
+            Some(123)
+        }
+
+        let value = TestEnum::A { value: None };
+        let cloned = value.clone_value();
+        assert_eq!(Some(&value), TestEnum::from_reflect(&*cloned).as_ref());
+
+        let mut applied = TestEnum::A { value: Some(1) };
+        applied.apply(&*cloned);
+        assert_eq!(value, applied);
+    }
+
+    #[test]
+    fn enum_try_apply_should_only_overwrite_present_indices_of_same_tuple_variant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(usize, usize, usize),
+            B { foo: usize },
+        }
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("1", 123_usize);
+        let dyn_enum = DynamicEnum::new("A", dyn_struct);
+
+        let mut value = TestEnum::A(1, 2, 3);
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(TestEnum::A(1, 123, 3), value);
+
+        // Struct variants are still applied by name
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 123_usize);
+        let dyn_enum = DynamicEnum::new("B", dyn_struct);
+
+        let mut value = TestEnum::B { foo: 0 };
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(TestEnum::B { foo: 123 }, value);
+    }
+
+    #[test]
+    fn enum_registered_partial_eq_should_fall_back_to_structural_comparison() {
+        #[derive(Reflect, Debug)]
+        #[reflect(PartialEq)]
+        enum TestEnum {
+            A(usize),
+            B { value: f32 },
+        }
+
+        // Every value compares equal through `PartialEq`, unlike through the fields
+        impl PartialEq for TestEnum {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        let a = TestEnum::A(1);
+        let b = TestEnum::B { value: 1.23 };
+        assert_eq!(Some(true), a.reflect_partial_eq(&b));
+
+        let mut data = DynamicTuple::default();
+        data.insert(1_usize);
+        let dyn_enum = DynamicEnum::new("A", data);
+        assert_eq!(Some(true), a.reflect_partial_eq(&dyn_enum));
+        assert_eq!(Some(false), b.reflect_partial_eq(&dyn_enum));
+
+        assert_eq!(Some(false), a.reflect_partial_eq(&1_usize));
+    }
+
+    #[test]
+    fn enum_should_match_variant_names_case_insensitively() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_reflect(case_insensitive))]
+        enum TestEnum {
+            Walking(usize),
+            Running {
+                speed: f32,
+            },
+            // Exact matches are preferred over case-insensitive ones
+            #[allow(clippy::upper_case_acronyms)]
+            WALKING,
+        }
+
+        let mut data = DynamicTuple::default();
+        data.insert(123_usize);
+        let dyn_enum = DynamicEnum::new("walking", data);
+        assert_eq!(
+            Some(TestEnum::Walking(123)),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        let mut data = DynamicStruct::default();
+        data.insert("speed", 1.23_f32);
+        let dyn_enum = DynamicEnum::new("RUNNING", data);
+        let mut value = TestEnum::Walking(0);
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(TestEnum::Running { speed: 1.23 }, value);
+
+        let dyn_enum = DynamicEnum::new("WALKING", ());
+        assert_eq!(Some(TestEnum::WALKING), TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_access_common_field() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(common_field(id: u32))]
+        enum Entity {
+            Player {
+                id: u32,
+                name: String,
+            },
+            Monster {
+                health: f32,
+                id: u32,
+            },
+            #[reflect(ignore)]
+            Hidden {
+                id: u32,
+            },
+        }
+
+        let player = Entity::Player {
+            id: 1,
+            name: String::from("Ferris"),
+        };
+        let monster = Entity::Monster {
+            health: 10.0,
+            id: 2,
+        };
+        assert_eq!(&1, player.reflect_common_field());
+        assert_eq!(&2, monster.reflect_common_field());
+        assert_eq!(&3, Entity::Hidden { id: 3 }.reflect_common_field());
+    }
+
+    #[test]
+    fn enum_set_or_try_apply_should_move_same_type_values() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(String),
+            B { values: Vec<u32> },
+        }
+
+        let mut value = TestEnum::A(String::new());
+
+        let values = vec![1, 2, 3];
+        let ptr = values.as_ptr();
+        value
+            .set_or_try_apply(Box::new(TestEnum::B { values }))
+            .unwrap();
+        let TestEnum::B { values } = &value else {
+            panic!("Expected `TestEnum::B`");
+        };
+        // The buffer was moved rather than reconstructed
+        assert_eq!(ptr, values.as_ptr());
+
+        // Other values fall back to being applied
+        let dyn_enum = TestEnum::A(String::from("hello")).clone_dynamic();
+        value.set_or_try_apply(Box::new(dyn_enum)).unwrap();
+        assert_eq!(TestEnum::A(String::from("hello")), value);
+
+        let result = value.set_or_try_apply(Box::new(123_u32));
+        assert!(
+            matches!(&result, Err(ApplyError::MismatchedKinds { .. })),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    fn enum_should_round_trip_result_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum MyError {
+            NotFound,
+            Invalid { reason: String },
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Response {
+            Empty,
+            Value(Result<u32, MyError>),
+            Named { result: Result<u32, MyError> },
+        }
+
+        let values = [
+            Response::Value(Ok(123)),
+            Response::Value(Err(MyError::NotFound)),
+            Response::Named {
+                result: Err(MyError::Invalid {
+                    reason: String::from("bad"),
+                }),
+            },
+        ];
+        for value in values {
+            let dyn_enum = value.clone_dynamic();
+            assert_eq!(Some(&value), Response::from_reflect(&dyn_enum).as_ref());
+
+            let mut target = Response::Empty;
+            target.apply(&dyn_enum);
+            assert_eq!(value, target);
+        }
+
+        // Applying to the same variant switches the `Result` in place
+        let mut target = Response::Value(Ok(1));
+        target.apply(&Response::Value(Err(MyError::NotFound)));
+        assert_eq!(Response::Value(Err(MyError::NotFound)), target);
+    }
+
+    #[test]
+    fn enum_clone_dynamic_should_omit_default_fields() {
+        fn default_speed() -> f32 {
+            1.0
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(omit_default_fields)]
+        enum TestEnum {
+            A(#[reflect(default)] usize),
+            B {
+                name: String,
+                #[reflect(default)]
+                count: usize,
+                #[reflect(default = "default_speed")]
+                speed: f32,
+            },
+        }
+
+        let value = TestEnum::B {
+            name: String::new(),
+            count: 0,
+            speed: 1.0,
+        };
+        let dyn_enum = value.clone_dynamic();
+        // Fields without a default are always included
+        assert_eq!(1, dyn_enum.field_len());
+        assert!(dyn_enum.field("name").is_some());
+        assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+
+        let value = TestEnum::B {
+            name: String::from("foo"),
+            count: 3,
+            speed: 1.0,
+        };
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(2, dyn_enum.field_len());
+        assert_eq!(
+            Some(&3),
+            dyn_enum.field("count").unwrap().downcast_ref::<usize>()
+        );
+        assert!(dyn_enum.field("speed").is_none());
+        assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+
+        // Tuple fields are identified by position, so they are kept
+        assert_eq!(1, TestEnum::A(0).clone_dynamic().field_len());
+    }
+
+    #[test]
+    fn enum_should_construct_variants_deprecated_with_note() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(collect_apply_errors)]
+        enum TestEnum {
+            #[reflect(deprecated = "use `New` instead")]
+            Old(usize),
+            #[reflect(deprecated(since = "0.14", note = "use `New` instead"))]
+            Older {
+                value: usize,
+            },
+            New(usize),
+        }
+
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected `TypeInfo::Enum`");
+        };
+        let deprecation = info.variant("Old").unwrap().deprecation().unwrap();
+        assert_eq!(Some("use `New` instead"), deprecation.note());
+        assert_eq!(None, deprecation.since());
+        let deprecation = info.variant("Older").unwrap().deprecation().unwrap();
+        assert_eq!(Some("use `New` instead"), deprecation.note());
+        assert_eq!(Some("0.14"), deprecation.since());
+
+        // Constructing a deprecated variant more than once only warns the first time,
+        // and never changes the constructed value
+        for _ in 0..2 {
+            let dyn_enum = TestEnum::Old(1).clone_dynamic();
+            assert_eq!(Some(TestEnum::Old(1)), TestEnum::from_reflect(&dyn_enum));
+
+            let mut value = TestEnum::New(0);
+            value.apply(&TestEnum::Older { value: 2 });
+            assert_eq!(TestEnum::Older { value: 2 }, value);
+        }
+    }
+
+    #[test]
+    fn enum_should_take_missing_fields_from_variant_default() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(default_variant)]
+        enum Shape {
+            Point,
+            #[reflect(default = "Shape::unit_rect")]
+            Rect {
+                width: f32,
+                height: f32,
+                #[reflect(default)]
+                rounded: bool,
+                #[reflect(ignore)]
+                id: usize,
+            },
+        }
+
+        impl Shape {
+            fn unit_rect() -> Self {
+                CALLS.with(|calls| calls.set(calls.get() + 1));
+                Shape::Rect {
+                    width: 1.0,
+                    height: 1.0,
+                    rounded: true,
+                    id: 7,
+                }
+            }
+        }
+
+        let TypeInfo::Enum(info) = Shape::type_info() else {
+            panic!("Expected `TypeInfo::Enum`");
+        };
+        assert!(info.variant("Rect").unwrap().required_fields().is_empty());
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("height", 2.0_f32);
+        let dyn_enum = DynamicEnum::new("Rect", dyn_struct);
+
+        // Missing fields come from the variant's function, called once,
+        // while a field's own default takes precedence
+        let expected = Shape::Rect {
+            width: 1.0,
+            height: 2.0,
+            rounded: false,
+            id: 7,
+        };
+        assert_eq!(Some(&expected), Shape::from_reflect(&dyn_enum).as_ref());
+        assert_eq!(1, CALLS.with(Cell::get));
+
+        let mut value = Shape::Point;
+        value.apply(&dyn_enum);
+        assert_eq!(expected, value);
+        assert_eq!(2, CALLS.with(Cell::get));
+
+        assert_eq!(
+            Some(Shape::Rect {
+                width: 1.0,
+                height: 1.0,
+                rounded: false,
+                id: 7,
+            }),
+            Shape::reflect_default_variant("Rect")
+        );
+    }
+
+    #[test]
+    fn enum_should_fail_when_variant_default_constructs_another_variant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(try_from, from_dynamic_enum, default_variant)]
+        enum Shape {
+            Point,
+            #[reflect(default = "Shape::point")]
+            Rect {
+                width: f32,
+                height: f32,
+            },
+        }
+
+        impl Shape {
+            fn point() -> Self {
+                Shape::Point
+            }
+        }
+
+        let dyn_enum = DynamicEnum::new("Rect", DynamicStruct::default());
+
+        assert_eq!(None, Shape::from_reflect(&dyn_enum));
+        assert_eq!(None, Shape::reflect_default_variant("Rect"));
+        assert_eq!(
+            Err(FromReflectError::MismatchedVariantDefault {
+                variant_name: "Rect".into()
+            }),
+            Shape::from_dynamic_enum(&dyn_enum)
+        );
+
+        let result = Shape::try_from(&dyn_enum as &dyn Reflect);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::MismatchedVariantDefault { variant_name }) if &**variant_name == "Rect"
+            ),
+            "`result` was {result:?}"
+        );
+
+        let mut value = Shape::Point;
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::MismatchedVariantDefault { variant_name }) if &**variant_name == "Rect"
+            ),
+            "`result` was {result:?}"
+        );
+        assert_eq!(Shape::Point, value);
+    }
+
+    #[test]
+    fn enum_should_batch_missing_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(batch_missing_fields, try_from)]
+        enum TestEnum {
+            A,
+            B {
+                foo: usize,
+                bar: String,
+                baz: f32,
+                #[reflect(default)]
+                qux: u8,
+            },
+        }
+
+        // === Several Missing === //
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("bar", String::from("bar"));
+        let dyn_enum = DynamicEnum::new("B", dyn_struct);
+
+        let mut value = TestEnum::A;
+        let Err(ApplyError::Multiple(errors)) = value.try_apply(&dyn_enum) else {
+            panic!("expected multiple errors");
+        };
+        let field_names = errors
+            .iter()
+            .map(|error| match error {
+                ApplyError::MissingEnumField {
+                    variant_name,
+                    field_name,
+                } if &**variant_name == "B" => &**field_name,
+                error => panic!("unexpected error: {error:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["foo", "baz"], field_names);
+        assert_eq!(TestEnum::A, value);
+
+        assert!(matches!(
+            TestEnum::try_from(&dyn_enum as &dyn Reflect),
+            Err(ApplyError::Multiple(errors)) if errors.len() == 2
+        ));
+
+        // === Single Missing === //
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 1_usize);
+        dyn_struct.insert("bar", String::from("bar"));
+        let dyn_enum = DynamicEnum::new("B", dyn_struct);
+
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(&result, Err(ApplyError::MissingEnumField { field_name, .. }) if &**field_name == "baz"),
+            "`result` was {result:?}"
+        );
+
+        // === Success === //
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 1_usize);
+        dyn_struct.insert("bar", String::from("bar"));
+        dyn_struct.insert("baz", 2.0_f32);
+        let dyn_enum = DynamicEnum::new("B", dyn_struct);
+
+        value.try_apply(&dyn_enum).unwrap();
+        assert_eq!(
+            TestEnum::B {
+                foo: 1,
+                bar: String::from("bar"),
+                baz: 2.0,
+                qux: 0,
+            },
+            value
+        );
+    }
+
+    #[test]
+    fn enum_should_round_trip_boxed_slice_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(Box<[u32]>),
+            B { values: Box<[u32]> },
+        }
+
+        let value = TestEnum::A(Box::new([1, 2, 3]));
+        let dyn_enum = value.clone_dynamic();
+        assert!(dyn_enum.field_at(0).unwrap().is::<DynamicList>());
+        assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+
+        let mut dyn_list = DynamicList::default();
+        dyn_list.push(4_u32);
+        dyn_list.push(5_u32);
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("values", dyn_list);
+
+        let mut value = TestEnum::A(Box::default());
+        value.apply(&DynamicEnum::new("B", dyn_struct));
+        assert_eq!(
+            TestEnum::B {
+                values: Box::new([4, 5])
+            },
+            value
+        );
+
+        // Applying a longer list to the same variant grows the slice
+        value.apply(&TestEnum::B {
+            values: Box::new([6, 7, 8]),
+        });
+        assert_eq!(
+            TestEnum::B {
+                values: Box::new([6, 7, 8])
+            },
+            value
+        );
+    }
+
+    #[test]
+    fn enum_should_round_trip_non_zero_fields() {
+        use std::num::{NonZeroI8, NonZeroU32};
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A(NonZeroU32),
+            B { value: NonZeroI8 },
+        }
+
+        // === Round Trip === //
+        for value in [
+            TestEnum::A(NonZeroU32::new(7).unwrap()),
+            TestEnum::B {
+                value: NonZeroI8::new(-3).unwrap(),
+            },
+        ] {
+            let dyn_enum = value.clone_dynamic();
+            assert_eq!(Some(value), TestEnum::from_reflect(&dyn_enum));
+        }
+
+        // === Zero === //
+        // Non-zero types are only reconstructed from themselves, so a plain zero is rejected
+        let mut dyn_tuple = DynamicTuple::default();
+        dyn_tuple.insert(0_u32);
+        let dyn_enum = DynamicEnum::new("A", dyn_tuple);
+        assert_eq!(None, TestEnum::from_reflect(&dyn_enum));
+
+        let mut value = TestEnum::B {
+            value: NonZeroI8::new(1).unwrap(),
+        };
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(&result, Err(ApplyError::MismatchedTypes { .. })),
+            "`result` was {result:?}"
+        );
+        assert_eq!(
+            TestEnum::B {
+                value: NonZeroI8::new(1).unwrap()
+            },
+            value
+        );
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 0_i8);
+        let result = value.try_apply(&DynamicEnum::new("B", dyn_struct));
+        assert!(
+            matches!(&result, Err(ApplyError::MismatchedTypes { .. })),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    fn enum_should_reconstruct_hash_and_eq_faithfully() {
+        #[derive(Reflect, Debug, PartialEq, Eq, Hash)]
+        #[reflect(Hash, PartialEq)]
+        enum TestEnum {
+            A(usize),
+            B { value: String },
+        }
+
+        let mut map = bevy_utils::HashMap::new();
+        map.insert(TestEnum::A(1), "a");
+        map.insert(
+            TestEnum::B {
+                value: String::from("b"),
+            },
+            "b",
+        );
+
+        for key in map.keys() {
+            let reconstructed = TestEnum::from_reflect(key).unwrap();
+            assert_eq!(Some(&map[key]), map.get(&reconstructed));
+        }
+    }
+
+    #[test]
+    #[cfg(all(debug_assertions, feature = "verify_from_reflect"))]
+    #[should_panic(expected = "did not reconstruct an equal")]
+    fn enum_should_panic_on_unfaithful_reconstruction() {
+        #[derive(Reflect, PartialEq, Hash)]
+        #[reflect(Hash, PartialEq)]
+        enum TestEnum {
+            A(usize, #[reflect(ignore)] u8),
+        }
+
+        TestEnum::from_reflect(&TestEnum::A(1, 2));
+    }
+
+    #[test]
+    fn enum_should_rename_all_variant_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(rename_all = "camelCase")]
+        enum TestEnum {
+            A { foo_bar: usize, baz: bool },
+            B(usize),
+        }
+
+        let value = TestEnum::A {
+            foo_bar: 123,
+            baz: true,
+        };
+        assert_eq!(
+            Some(&123usize),
+            value.field("fooBar").unwrap().downcast_ref()
+        );
+        assert!(value.field("foo_bar").is_none());
+
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        let VariantInfo::Struct(variant) = info.variant("A").unwrap() else {
+            panic!("expected struct variant");
+        };
+        assert!(variant.field("fooBar").is_some());
+        assert_eq!(Some(1), variant.index_of("baz"));
+
+        let dynamic = value.clone_dynamic();
+        assert_eq!(Some(value), TestEnum::from_reflect(&dynamic));
+
+        let mut data = DynamicStruct::default();
+        data.insert("baz", false);
+        let dynamic = DynamicEnum::new("A", data);
+        let result = TestEnum::B(0).try_apply(&dynamic);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::MissingEnumField { field_name, .. }) if &**field_name == "fooBar"
+            ),
+            "`result` was {result:?}"
+        );
+
+        #[derive(Reflect)]
+        #[reflect(rename_all = "kebab-case")]
+        enum KebabEnum {
+            A { foo_bar: usize },
+        }
+
+        let value = KebabEnum::A { foo_bar: 1 };
+        assert_eq!(Some("foo-bar"), value.name_at(0));
+    }
+
+    #[test]
+    fn enum_should_apply_scalar_to_newtype_variant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(apply_scalar_to_newtype)]
+        enum TestEnum {
+            A(u32),
+            B(u32, u32),
+            C { value: u32 },
+        }
+
+        let mut value = TestEnum::A(0);
+        value.try_apply(&123_u32).unwrap();
+        assert_eq!(TestEnum::A(123), value);
+
+        let result = value.try_apply(&1.5_f32);
+        assert!(
+            matches!(&result, Err(ApplyError::MismatchedTypes { .. })),
+            "`result` was {result:?}"
+        );
+        assert_eq!(TestEnum::A(123), value);
+
+        let mut value = TestEnum::B(0, 0);
+        let result = value.try_apply(&123_u32);
+        assert!(
+            matches!(&result, Err(ApplyError::MismatchedKinds { .. })),
+            "`result` was {result:?}"
+        );
+
+        let mut value = TestEnum::C { value: 0 };
+        let result = value.try_apply(&123_u32);
+        assert!(
+            matches!(&result, Err(ApplyError::MismatchedKinds { .. })),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    #[allow(unsafe_code)]
+    fn enum_should_generate_repr_offsets() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(repr_offsets)]
+        #[repr(C)]
+        enum TestEnum {
+            A(u8, #[reflect(ignore)] u16, u32),
+            B { foo: u8, bar: u64 },
+            C,
+        }
+
+        let offset_of = |variant: &str, field: &str| {
+            TestEnum::reflect_field_offsets()
+                .iter()
+                .find(|(v, f, _)| *v == variant && *f == field)
+                .map(|(_, _, offset)| *offset)
+        };
+        assert_eq!(4, TestEnum::reflect_field_offsets().len());
+        assert_eq!(None, offset_of("A", "2"));
+
+        let mut value = TestEnum::B { foo: 1, bar: 2 };
+        let offset = offset_of("B", "bar").unwrap();
+        let ptr = std::ptr::from_mut(&mut value).cast::<u8>();
+        // SAFETY: `value` holds the `B` variant, whose `bar` field is a `u64` at `offset`
+        unsafe {
+            assert_eq!(2, ptr.add(offset).cast::<u64>().read());
+            ptr.add(offset).cast::<u64>().write(123);
+        }
+        assert_eq!(TestEnum::B { foo: 1, bar: 123 }, value);
+
+        let value = TestEnum::A(1, 2, 3);
+        let offset = offset_of("A", "1").unwrap();
+        // SAFETY: `value` holds the `A` variant, whose second reflected field is a `u32` at `offset`
+        let field = unsafe {
+            std::ptr::from_ref(&value)
+                .cast::<u8>()
+                .add(offset)
+                .cast::<u32>()
+                .read()
+        };
+        assert_eq!(3, field);
+
+        #[derive(Reflect)]
+        #[reflect(repr_offsets)]
+        #[repr(C, u8)]
+        enum TaggedEnum {
+            A { value: u16 },
+        }
+
+        let value = TaggedEnum::A { value: 42 };
+        let offset = TaggedEnum::reflect_field_offsets()[0].2;
+        assert_eq!(2, offset);
+        // SAFETY: `value` holds the `A` variant, whose `value` field is a `u16` at `offset`
+        let field = unsafe {
+            std::ptr::from_ref(&value)
+                .cast::<u8>()
+                .add(offset)
+                .cast::<u16>()
+                .read()
+        };
+        assert_eq!(42, field);
+    }
+
+    #[test]
+    fn enum_should_reflect_array_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A([u32; 3]),
+            B { values: [u32; 3] },
+        }
+
+        let value = TestEnum::A([1, 2, 3]);
+        let dynamic = value.clone_dynamic();
+        assert_eq!(Some(&value), TestEnum::from_reflect(&dynamic).as_ref());
+
+        let mut target = TestEnum::B { values: [0; 3] };
+        target.try_apply(&value).unwrap();
+        assert_eq!(value, target);
+
+        let mut data = DynamicStruct::default();
+        data.insert_boxed("values", Box::new(DynamicArray::from_vec(vec![4_u32, 5])));
+        let dynamic = DynamicEnum::new("B", data);
+        assert_eq!(None, TestEnum::from_reflect(&dynamic));
+
+        let mut target = TestEnum::B { values: [0; 3] };
+        let result = target.try_apply(&dynamic);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::DifferentSize {
+                    from_size: 2,
+                    to_size: 3
+                })
+            ),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    fn enum_should_suggest_closest_variant_name() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(try_from)]
+        enum TestEnum {
+            Visible,
+            Hidden,
+            Inherited,
+            #[reflect(hidden)]
+            Invisible,
+        }
+
+        let mut value = TestEnum::Hidden;
+        let result = value.try_apply(&DynamicEnum::new("Visable", ()));
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::UnknownVariant { suggestion: Some(suggestion), .. })
+                    if &**suggestion == "Visible"
+            ),
+            "`result` was {result:?}"
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .ends_with("did you mean `Visible`?"));
+
+        let result = TestEnum::try_from(&DynamicEnum::new("inherited", ()) as &dyn Reflect);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::UnknownVariant { suggestion: Some(suggestion), .. })
+                    if &**suggestion == "Inherited"
+            ),
+            "`result` was {result:?}"
+        );
+
+        for name in ["Foo", "Invisibl"] {
+            let result = value.try_apply(&DynamicEnum::new(name, ()));
+            assert!(
+                matches!(
+                    &result,
+                    Err(ApplyError::UnknownVariant {
+                        suggestion: None,
+                        ..
+                    })
+                ),
+                "`result` was {result:?}"
+            );
+        }
+        assert_eq!(TestEnum::Hidden, value);
+    }
+
+    #[test]
+    #[allow(unsafe_code)]
+    fn enum_should_initialize_ignored_maybe_uninit_fields() {
+        use core::mem::MaybeUninit;
+
+        fn init() -> MaybeUninit<u32> {
+            MaybeUninit::new(7)
+        }
+
+        #[derive(Reflect)]
+        enum TestEnum {
+            A(usize, #[reflect(ignore, default = "init")] MaybeUninit<u32>),
+            B,
+        }
+
+        let dyn_enum = TestEnum::A(1, MaybeUninit::new(123)).clone_dynamic();
+        let Some(TestEnum::A(value, uninit)) = TestEnum::from_reflect(&dyn_enum) else {
+            panic!("expected `TestEnum::A`");
+        };
+        assert_eq!(1, value);
+        // SAFETY: `init` always initializes the field
+        assert_eq!(7, unsafe { uninit.assume_init() });
+
+        let mut value = TestEnum::B;
+        value.apply(&dyn_enum);
+        let TestEnum::A(_, uninit) = value else {
+            panic!("expected `TestEnum::A`");
+        };
+        // SAFETY: `init` always initializes the field
+        assert_eq!(7, unsafe { uninit.assume_init() });
+    }
+
+    #[test]
+    fn enum_should_reflect_multiply_bounded_generics() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(where T: Default)]
+        enum TestEnum<T: Reflect + Clone + Send, U>
+        where
+            U: Clone + Send + Sync + FromReflect + TypePath,
+        {
+            A(T),
+            B(T, T),
+            C { value: U, other: T },
+        }
+
+        let value = TestEnum::<u32, String>::C {
+            value: String::from("hello"),
+            other: 123,
+        };
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(&value), TestEnum::from_reflect(&dyn_enum).as_ref());
+
+        let mut target = TestEnum::<u32, String>::B(1, 2);
+        target.apply(&dyn_enum);
+        assert_eq!(value, target);
+
+        assert!(<TestEnum<u32, String> as Typed>::type_info().is::<TestEnum<u32, String>>());
+    }
+
+    #[test]
+    fn enum_should_strip_raw_field_identifiers() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            A {
+                r#type: u32,
+                #[reflect(ignore)]
+                r#match: bool,
+            },
+            B,
+        }
+
+        let value = TestEnum::A {
+            r#type: 123,
+            r#match: false,
+        };
+        assert_eq!(Some(&123u32), value.field("type").unwrap().downcast_ref());
+        assert!(value.field("r#type").is_none());
+        assert_eq!(Some("type"), value.name_at(0));
+
+        let TypeInfo::Enum(info) = TestEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        let VariantInfo::Struct(variant) = info.variant("A").unwrap() else {
+            panic!("expected struct variant");
+        };
+        assert_eq!(&["type"], variant.field_names());
+
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(&value), TestEnum::from_reflect(&dyn_enum).as_ref());
+
+        let mut target = TestEnum::B;
+        target.apply(&dyn_enum);
+        assert_eq!(value, target);
+    }
+
+    #[test]
+    fn enum_should_default_missing_options_to_none() {
+        fn some() -> Option<u32> {
+            Some(7)
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_reflect(default_options))]
+        enum TestEnum {
+            A {
+                value: u32,
+                label: Option<String>,
+                #[reflect(default = "some")]
+                count: Option<u32>,
+            },
+        }
+
+        let mut data = DynamicStruct::default();
+        data.insert("value", 1_u32);
+        let dyn_enum = DynamicEnum::new("A", data);
+        assert_eq!(
+            Some(TestEnum::A {
+                value: 1,
+                label: None,
+                count: Some(7),
+            }),
+            TestEnum::from_reflect(&dyn_enum)
+        );
+
+        let dyn_enum = DynamicEnum::new("A", DynamicStruct::default());
+        assert_eq!(None, TestEnum::from_reflect(&dyn_enum));
+    }
+
+    #[test]
+    fn enum_should_keep_where_clause_of_generic_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(try_from, from_dynamic_enum)]
+        enum TestEnum<T>
+        where
+            T: Reflect + Clone,
+        {
+            A(Vec<T>),
+            B { value: T },
+        }
+
+        let value = TestEnum::A(vec![1_u32, 2, 3]);
+        let dyn_enum = value.clone_dynamic();
+        assert_eq!(Some(&value), TestEnum::from_reflect(&dyn_enum).as_ref());
+        assert_eq!(
+            value,
+            TestEnum::try_from(&dyn_enum as &dyn Reflect).unwrap()
+        );
+        assert_eq!(value, TestEnum::from_dynamic_enum(&dyn_enum).unwrap());
+
+        let mut target = TestEnum::B { value: 0_u32 };
+        target.try_apply(&dyn_enum).unwrap();
+        assert_eq!(value, target);
+
+        let registration = TestEnum::<u32>::get_type_registration();
+        assert!(registration.type_info().is::<TestEnum<u32>>());
+    }
+
+    #[test]
+    fn enum_should_count_applied_field_changes() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(apply_counting)]
+        enum TestEnum {
+            A { foo: usize, bar: String },
+            B(usize),
+        }
+
+        let mut value = TestEnum::A {
+            foo: 1,
+            bar: String::from("hello"),
+        };
+        let unchanged = value.clone_dynamic();
+        assert_eq!(0, value.apply_counting(&unchanged).unwrap());
+
+        let mut data = DynamicStruct::default();
+        data.insert("foo", 2_usize);
+        data.insert("bar", String::from("hello"));
+        assert_eq!(
+            1,
+            value.apply_counting(&DynamicEnum::new("A", data)).unwrap()
+        );
+        assert_eq!(
+            TestEnum::A {
+                foo: 2,
+                bar: String::from("hello")
+            },
+            value
+        );
+
+        assert_eq!(1, value.apply_counting(&TestEnum::B(3)).unwrap());
+        assert_eq!(TestEnum::B(3), value);
+
+        let result = value.apply_counting(&123_usize);
+        assert!(
+            matches!(&result, Err(ApplyError::MismatchedKinds { .. })),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    fn enum_should_reflect_tuple_variants_with_many_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum TestEnum {
+            Empty,
+            Wide(
+                u8,
+                u16,
+                u32,
+                u64,
+                i8,
+                i16,
+                i32,
+                i64,
+                f32,
+                f64,
+                bool,
+                char,
+                String,
+                usize,
+                isize,
+                Option<u8>,
+            ),
+        }
+
+        let wide = TestEnum::Wide(
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+            7,
+            8,
+            9.0,
+            10.0,
+            true,
+            'c',
+            String::from("thirteen"),
+            14,
+            15,
+            Some(16),
+        );
+        assert_eq!(16, wide.field_len());
+        assert_eq!(
+            Some(&Some(16_u8)),
+            wide.field_at(15).unwrap().downcast_ref()
+        );
+
+        let dyn_enum = wide.clone_dynamic();
+        assert_eq!(16, dyn_enum.field_len());
+        assert!(wide.reflect_partial_eq(&dyn_enum).unwrap_or_default());
+        assert_eq!(Some(&wide), TestEnum::from_reflect(&dyn_enum).as_ref());
+
+        let mut value = TestEnum::Empty;
+        value.apply(&dyn_enum);
+        assert_eq!(wide, value);
+    }
+
+    #[test]
+    fn enum_should_only_construct_listed_variants() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_reflect(only = "Guest, Member"), try_from, from_dynamic_enum)]
+        enum Role {
+            Guest,
+            Member { id: u32 },
+            Admin { id: u32 },
+        }
+
+        assert_eq!(
+            Some(Role::Guest),
+            Role::from_reflect(&Role::Guest.clone_dynamic())
+        );
+        assert_eq!(
+            Some(Role::Member { id: 1 }),
+            Role::from_reflect(&Role::Member { id: 1 }.clone_dynamic())
+        );
+        assert_eq!(
+            None,
+            Role::from_reflect(&Role::Admin { id: 1 }.clone_dynamic())
+        );
+
+        // The fallible conversions reject the excluded variant too
+        assert_eq!(
+            Ok(Role::Member { id: 1 }),
+            Role::from_dynamic_enum(&Role::Member { id: 1 }.clone_dynamic())
+        );
+        assert_eq!(
+            Err(FromReflectError::ExcludedVariant {
+                variant_name: "Admin".into()
+            }),
+            Role::from_dynamic_enum(&Role::Admin { id: 1 }.clone_dynamic())
+        );
+
+        let value: &dyn Reflect = &Role::Member { id: 1 };
+        assert_eq!(Role::Member { id: 1 }, Role::try_from(value).unwrap());
+        let value: &dyn Reflect = &Role::Admin { id: 1 };
+        let result = Role::try_from(value);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::ExcludedVariant { variant_name }) if &**variant_name == "Admin"
+            ),
+            "`result` was {result:?}"
+        );
+
+        // The excluded variant is still reflected and can still be applied
+        let mut role = Role::Member { id: 1 };
+        role.apply(&Role::Admin { id: 2 });
+        assert_eq!(Role::Admin { id: 2 }, role);
+    }
+
+    #[test]
+    fn enum_try_apply_should_reject_variants_of_superset_enum() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum OldEnum {
+            A,
+            B(u32),
+        }
+
+        #[derive(Reflect)]
+        enum NewEnum {
+            A,
+            B(u32),
+            C { value: f32 },
+        }
+
+        let mut value = OldEnum::B(1);
+        let result = value.try_apply(&NewEnum::C { value: 1.0 });
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::UnknownVariant { variant_name, .. }) if &**variant_name == "C"
+            ),
+            "`result` was {result:?}"
+        );
+        assert_eq!(OldEnum::B(1), value);
+
+        // A made-up name is never mapped to a variant by its position
+        let mut data = DynamicTuple::default();
+        data.insert(2_u32);
+        let dyn_enum = DynamicEnum::new("1", data);
+        let mut value = OldEnum::A;
+        let result = value.try_apply(&dyn_enum);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::UnknownVariant { variant_name, .. }) if &**variant_name == "1"
+            ),
+            "`result` was {result:?}"
+        );
+        assert_eq!(OldEnum::A, value);
+
+        // Known variants can still be applied
+        value.try_apply(&NewEnum::B(3)).unwrap();
+        assert_eq!(OldEnum::B(3), value);
+    }
+
+    #[test]
+    #[allow(unsafe_code)]
+    fn enum_should_generate_repr_offsets_for_over_aligned_enums() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(repr_offsets)]
+        #[repr(C, align(32))]
+        enum TestEnum {
+            A { foo: u8, bar: u64 },
+            B(u16),
+        }
+
+        assert_eq!(32, std::mem::align_of::<TestEnum>());
+
+        let value = TestEnum::A { foo: 1, bar: 2 };
+        let clone = Box::new(TestEnum::from_reflect(&*value.clone_value()).unwrap());
+        assert_eq!(value, *clone);
+        assert_eq!(0, (std::ptr::from_ref(&*clone) as usize) % 32);
+
+        // Every reflected field of the clone holds the same bytes as the original
+        let read_bytes = |value: &TestEnum, offset: usize, len: usize| {
+            // SAFETY: both values hold the `A` variant, whose fields are plain integers at `offset`
+            unsafe {
+                std::slice::from_raw_parts(std::ptr::from_ref(value).cast::<u8>().add(offset), len)
+                    .to_vec()
+            }
+        };
+        for (variant, field, offset) in TestEnum::reflect_field_offsets() {
+            if *variant != "A" {
+                continue;
+            }
+            let len = match *field {
+                "foo" => 1,
+                "bar" => 8,
+                _ => unreachable!(),
+            };
+            assert_eq!(
+                read_bytes(&value, *offset, len),
+                read_bytes(&clone, *offset, len)
+            );
+        }
+        assert_eq!(
+            2,
+            // SAFETY: `clone` holds the `A` variant, whose `bar` field is a `u64` at the listed offset
+            unsafe {
+                std::ptr::from_ref(&*clone)
+                    .cast::<u8>()
+                    .add(TestEnum::reflect_field_offsets()[1].2)
+                    .cast::<u64>()
+                    .read()
+            }
+        );
+    }
+
+    #[test]
+    fn enum_should_evaluate_fields_in_declaration_order() {
+        use std::cell::RefCell;
+
+        thread_local! {
+            static CALLS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        }
+
+        fn record(name: &'static str) -> usize {
+            CALLS.with(|calls| {
+                let mut calls = calls.borrow_mut();
+                calls.push(name);
+                calls.len()
+            })
+        }
+
+        fn first() -> usize {
+            record("first")
+        }
+
+        fn second() -> usize {
+            record("second")
+        }
+
+        fn third() -> usize {
+            record("third")
+        }
+
+        fn fourth() -> usize {
+            record("fourth")
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(try_from)]
+        enum TestEnum {
+            A {
+                #[reflect(ignore, default = "first")]
+                ignored_a: usize,
+                #[reflect(default = "second")]
+                active_b: usize,
+                #[reflect(ignore, default = "third")]
+                ignored_c: usize,
+                #[reflect(default = "fourth")]
+                active_d: usize,
+            },
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(collect_apply_errors, try_from)]
+        enum CollectingEnum {
+            A {
+                #[reflect(ignore, default = "first")]
+                ignored_a: usize,
+                #[reflect(default = "second")]
+                active_b: usize,
+                #[reflect(ignore, default = "third")]
+                ignored_c: usize,
+                #[reflect(default = "fourth")]
+                active_d: usize,
+            },
+        }
+
+        let dyn_enum = DynamicEnum::new("A", DynamicStruct::default());
+        let take_calls = || CALLS.with(|calls| std::mem::take(&mut *calls.borrow_mut()));
+        let expected_calls = vec!["first", "second", "third", "fourth"];
+
+        let expected = TestEnum::A {
+            ignored_a: 1,
+            active_b: 2,
+            ignored_c: 3,
+            active_d: 4,
+        };
+        assert_eq!(Some(&expected), TestEnum::from_reflect(&dyn_enum).as_ref());
+        assert_eq!(expected_calls, take_calls());
+        assert_eq!(
+            expected,
+            TestEnum::try_from(&dyn_enum as &dyn Reflect).unwrap()
+        );
+        assert_eq!(expected_calls, take_calls());
+
+        let expected = CollectingEnum::A {
+            ignored_a: 1,
+            active_b: 2,
+            ignored_c: 3,
+            active_d: 4,
+        };
+        assert_eq!(
+            Some(&expected),
+            CollectingEnum::from_reflect(&dyn_enum).as_ref()
+        );
+        assert_eq!(expected_calls, take_calls());
+        assert_eq!(
+            expected,
+            CollectingEnum::try_from(&dyn_enum as &dyn Reflect).unwrap()
+        );
+        assert_eq!(expected_calls, take_calls());
+    }
+
+    #[test]
+    fn enum_should_construct_from_externally_tagged_map() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(from_reflect(externally_tagged))]
+        enum TestEnum {
+            Unit,
+            Tuple(u8, String),
+            #[reflect(alias = "Structure")]
+            Struct {
+                value: f32,
+            },
+        }
+
+        let tagged = |name: &str, fields: Box<dyn Reflect>| {
+            let mut map = DynamicMap::default();
+            map.insert_boxed(Box::new(String::from(name)), fields);
+            map
+        };
+
+        let mut fields = DynamicStruct::default();
+        fields.insert("value", 1.5_f32);
+        let value = tagged("Struct", Box::new(fields.clone_dynamic()));
+        assert_eq!(
+            Some(TestEnum::Struct { value: 1.5 }),
+            TestEnum::from_reflect(&value)
+        );
+        let value = tagged("Structure", Box::new(fields));
+        assert_eq!(
+            Some(TestEnum::Struct { value: 1.5 }),
+            TestEnum::from_reflect(&value)
+        );
+
+        let mut fields = DynamicTuple::default();
+        fields.insert(1_u8);
+        fields.insert(String::from("hello"));
+        let value = tagged("Tuple", Box::new(fields));
+        assert_eq!(
+            Some(TestEnum::Tuple(1, String::from("hello"))),
+            TestEnum::from_reflect(&value)
+        );
+
+        let mut value = DynamicMap::default();
+        value.insert("Unit", ());
+        assert_eq!(Some(TestEnum::Unit), TestEnum::from_reflect(&value));
+
+        // Values round-trip through their externally tagged form
+        for original in [
+            TestEnum::Unit,
+            TestEnum::Tuple(2, String::from("world")),
+            TestEnum::Struct { value: 3.0 },
+        ] {
+            let fields: Box<dyn Reflect> = match original.reflect_ref() {
+                ReflectRef::Enum(dyn_enum) => match dyn_enum.clone_dynamic().variant() {
+                    DynamicVariant::Unit => Box::new(()),
+                    DynamicVariant::Tuple(fields) => Box::new(fields.clone_dynamic()),
+                    DynamicVariant::Struct(fields) => Box::new(fields.clone_dynamic()),
+                },
+                _ => unreachable!(),
+            };
+            let value = tagged(original.variant_name(), fields);
+            assert_eq!(Some(original), TestEnum::from_reflect(&value));
+        }
+
+        // Only single-entry maps naming a known variant are accepted
+        assert_eq!(
+            None,
+            TestEnum::from_reflect(&tagged("Missing", Box::new(())))
+        );
+        let mut value = tagged("Unit", Box::new(()));
+        value.insert(String::from("Tuple"), ());
+        assert_eq!(None, TestEnum::from_reflect(&value));
+        assert_eq!(None, TestEnum::from_reflect(&DynamicMap::default()));
+    }
+
+    #[test]
+    fn enum_should_merge_fields_by_policy() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(merge)]
+        enum Config {
+            Window {
+                title: String,
+                #[reflect(merge = "keep")]
+                id: u32,
+                #[reflect(merge = "combine")]
+                icon: Option<String>,
+                #[reflect(merge = "combine")]
+                scale: Option<f32>,
+            },
+            Tuple(
+                #[reflect(merge = "keep")] u8,
+                #[reflect(merge = "replace")] u8,
+            ),
+            Headless,
+        }
+
+        let mut config = Config::Window {
+            title: String::from("Base"),
+            id: 1,
+            icon: Some(String::from("base.png")),
+            scale: Some(1.0),
+        };
+        config
+            .reflect_merge(&Config::Window {
+                title: String::from("Override"),
+                id: 2,
+                icon: None,
+                scale: Some(2.0),
+            })
+            .unwrap();
+        assert_eq!(
+            Config::Window {
+                title: String::from("Override"),
+                id: 1,
+                icon: Some(String::from("base.png")),
+                scale: Some(2.0),
+            },
+            config
+        );
+
+        // Fields missing from the other value are left unchanged
+        let mut fields = DynamicStruct::default();
+        fields.insert("title", String::from("Partial"));
+        config
+            .reflect_merge(&DynamicEnum::new("Window", fields))
+            .unwrap();
+        assert_eq!(
+            Config::Window {
+                title: String::from("Partial"),
+                id: 1,
+                icon: Some(String::from("base.png")),
+                scale: Some(2.0),
+            },
+            config
+        );
+
+        let mut tuple = Config::Tuple(1, 2);
+        tuple.reflect_merge(&Config::Tuple(3, 4)).unwrap();
+        assert_eq!(Config::Tuple(1, 4), tuple);
+
+        // Another variant replaces the whole value
+        config.reflect_merge(&Config::Headless).unwrap();
+        assert_eq!(Config::Headless, config);
+
+        let result = config.reflect_merge(&123_u32);
+        assert!(
+            matches!(result, Err(ApplyError::MismatchedKinds { .. })),
+            "`result` was {result:?}"
+        );
+    }
+
+    #[test]
+    fn should_extend_fields_marked_apply_extend() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Log {
+            Lines(#[reflect(apply = "extend")] Vec<u32>, Vec<u32>),
+            Named {
+                #[reflect(apply = "extend")]
+                lines: Vec<u32>,
+            },
+        }
+
+        let mut log = Log::Lines(vec![1, 2, 3], vec![1, 2, 3]);
+        log.apply(&Log::Lines(vec![4, 5], vec![4, 5]));
+        // Only the marked field keeps its existing elements
+        assert_eq!(Log::Lines(vec![1, 2, 3, 4, 5], vec![4, 5, 3]), log);
+
+        let mut log = Log::Named { lines: vec![1] };
+        log.apply(&Log::Named { lines: vec![2] });
+        assert_eq!(Log::Named { lines: vec![1, 2] }, log);
+
+        // Switching variants constructs the field from the value alone
+        log.apply(&Log::Lines(vec![6], vec![]));
+        assert_eq!(Log::Lines(vec![6], vec![]), log);
+    }
 }
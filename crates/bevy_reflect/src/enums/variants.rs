@@ -75,6 +75,44 @@ impl VariantInfo {
         }
     }
 
+    /// The deprecation metadata of the underlying variant, if any.
+    pub fn deprecation(&self) -> Option<&VariantDeprecation> {
+        match self {
+            Self::Struct(info) => info.deprecation(),
+            Self::Tuple(info) => info.deprecation(),
+            Self::Unit(info) => info.deprecation(),
+        }
+    }
+
+    /// The alternative names of the underlying variant.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Self::Struct(info) => info.aliases(),
+            Self::Tuple(info) => info.aliases(),
+            Self::Unit(info) => info.aliases(),
+        }
+    }
+
+    /// Returns true if the underlying variant is hidden from the list of variants an enum advertises.
+    pub fn is_hidden(&self) -> bool {
+        match self {
+            Self::Struct(info) => info.is_hidden(),
+            Self::Tuple(info) => info.is_hidden(),
+            Self::Unit(info) => info.is_hidden(),
+        }
+    }
+
+    /// The indices of the fields of the underlying variant that have no default.
+    ///
+    /// Unit variants have no fields, so this is always empty for them.
+    pub fn required_fields(&self) -> &'static [usize] {
+        match self {
+            Self::Struct(info) => info.required_fields(),
+            Self::Tuple(info) => info.required_fields(),
+            Self::Unit(_) => &[],
+        }
+    }
+
     /// The docstring of the underlying variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -104,6 +142,10 @@ pub struct StructVariantInfo {
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
     custom_attributes: Arc<CustomAttributes>,
+    deprecation: Option<VariantDeprecation>,
+    aliases: &'static [&'static str],
+    hidden: bool,
+    required_fields: &'static [usize],
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -119,6 +161,10 @@ impl StructVariantInfo {
             field_names,
             field_indices,
             custom_attributes: Arc::new(CustomAttributes::default()),
+            deprecation: None,
+            aliases: &[],
+            hidden: false,
+            required_fields: &[],
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -138,6 +184,34 @@ impl StructVariantInfo {
         }
     }
 
+    /// Marks this variant as deprecated.
+    pub fn with_deprecation(self, deprecation: VariantDeprecation) -> Self {
+        Self {
+            deprecation: Some(deprecation),
+            ..self
+        }
+    }
+
+    /// Sets the alternative names this variant may be referred to by,
+    /// such as names it had before being renamed.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// Sets whether this variant is hidden from the list of variants an enum advertises.
+    pub fn with_hidden(self, hidden: bool) -> Self {
+        Self { hidden, ..self }
+    }
+
+    /// Sets the indices of the fields that have no default,
+    /// and so must be present to construct this variant.
+    pub fn with_required_fields(self, required_fields: &'static [usize]) -> Self {
+        Self {
+            required_fields,
+            ..self
+        }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
@@ -183,6 +257,31 @@ impl StructVariantInfo {
             .collect()
     }
 
+    /// The deprecation metadata of this variant, if any.
+    pub fn deprecation(&self) -> Option<&VariantDeprecation> {
+        self.deprecation.as_ref()
+    }
+
+    /// The alternative names of this variant.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns true if this variant is hidden from the list of variants an enum advertises.
+    ///
+    /// Hidden variants can still be looked up and constructed by name.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// The indices of the fields that have no default,
+    /// and so must be present to construct this variant.
+    ///
+    /// Fields are indexed as by [`field_at`](Self::field_at).
+    pub fn required_fields(&self) -> &'static [usize] {
+        self.required_fields
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -198,6 +297,10 @@ pub struct TupleVariantInfo {
     name: &'static str,
     fields: Box<[UnnamedField]>,
     custom_attributes: Arc<CustomAttributes>,
+    deprecation: Option<VariantDeprecation>,
+    aliases: &'static [&'static str],
+    hidden: bool,
+    required_fields: &'static [usize],
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -209,6 +312,10 @@ impl TupleVariantInfo {
             name,
             fields: fields.to_vec().into_boxed_slice(),
             custom_attributes: Arc::new(CustomAttributes::default()),
+            deprecation: None,
+            aliases: &[],
+            hidden: false,
+            required_fields: &[],
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -228,6 +335,34 @@ impl TupleVariantInfo {
         }
     }
 
+    /// Marks this variant as deprecated.
+    pub fn with_deprecation(self, deprecation: VariantDeprecation) -> Self {
+        Self {
+            deprecation: Some(deprecation),
+            ..self
+        }
+    }
+
+    /// Sets the alternative names this variant may be referred to by,
+    /// such as names it had before being renamed.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// Sets whether this variant is hidden from the list of variants an enum advertises.
+    pub fn with_hidden(self, hidden: bool) -> Self {
+        Self { hidden, ..self }
+    }
+
+    /// Sets the indices of the fields that have no default,
+    /// and so must be present to construct this variant.
+    pub fn with_required_fields(self, required_fields: &'static [usize]) -> Self {
+        Self {
+            required_fields,
+            ..self
+        }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
@@ -248,6 +383,31 @@ impl TupleVariantInfo {
         self.fields.len()
     }
 
+    /// The deprecation metadata of this variant, if any.
+    pub fn deprecation(&self) -> Option<&VariantDeprecation> {
+        self.deprecation.as_ref()
+    }
+
+    /// The alternative names of this variant.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns true if this variant is hidden from the list of variants an enum advertises.
+    ///
+    /// Hidden variants can still be looked up and constructed by name.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// The indices of the fields that have no default,
+    /// and so must be present to construct this variant.
+    ///
+    /// Fields are indexed as by [`field_at`](Self::field_at).
+    pub fn required_fields(&self) -> &'static [usize] {
+        self.required_fields
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -262,6 +422,9 @@ impl TupleVariantInfo {
 pub struct UnitVariantInfo {
     name: &'static str,
     custom_attributes: Arc<CustomAttributes>,
+    deprecation: Option<VariantDeprecation>,
+    aliases: &'static [&'static str],
+    hidden: bool,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -272,6 +435,9 @@ impl UnitVariantInfo {
         Self {
             name,
             custom_attributes: Arc::new(CustomAttributes::default()),
+            deprecation: None,
+            aliases: &[],
+            hidden: false,
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -291,11 +457,47 @@ impl UnitVariantInfo {
         }
     }
 
+    /// Marks this variant as deprecated.
+    pub fn with_deprecation(self, deprecation: VariantDeprecation) -> Self {
+        Self {
+            deprecation: Some(deprecation),
+            ..self
+        }
+    }
+
+    /// Sets the alternative names this variant may be referred to by,
+    /// such as names it had before being renamed.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// Sets whether this variant is hidden from the list of variants an enum advertises.
+    pub fn with_hidden(self, hidden: bool) -> Self {
+        Self { hidden, ..self }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The deprecation metadata of this variant, if any.
+    pub fn deprecation(&self) -> Option<&VariantDeprecation> {
+        self.deprecation.as_ref()
+    }
+
+    /// The alternative names of this variant.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns true if this variant is hidden from the list of variants an enum advertises.
+    ///
+    /// Hidden variants can still be looked up and constructed by name.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -304,3 +506,85 @@ impl UnitVariantInfo {
 
     impl_custom_attribute_methods!(self.custom_attributes, "variant");
 }
+
+/// Deprecation metadata for an enum variant.
+///
+/// This can be registered using the `#[reflect(deprecated(...))]` variant attribute
+/// and is accessible via [`VariantInfo::deprecation`].
+/// Migration tooling may use it to map deprecated variants onto their replacements.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// #[derive(Reflect)]
+/// enum Shape {
+///   #[reflect(deprecated(since = "0.14", replaced_by = "Circle"))]
+///   Round(f32),
+///   Circle(f32),
+/// }
+///
+/// let TypeInfo::Enum(info) = Shape::type_info() else {
+///   panic!("expected enum info");
+/// };
+///
+/// let deprecation = info.variant("Round").unwrap().deprecation().unwrap();
+/// assert_eq!(Some("0.14"), deprecation.since());
+/// assert_eq!(Some("Circle"), deprecation.replaced_by());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VariantDeprecation {
+    since: Option<&'static str>,
+    replaced_by: Option<&'static str>,
+    note: Option<&'static str>,
+}
+
+impl VariantDeprecation {
+    /// Create a new, empty [`VariantDeprecation`].
+    pub const fn new() -> Self {
+        Self {
+            since: None,
+            replaced_by: None,
+            note: None,
+        }
+    }
+
+    /// Sets the version in which the variant was deprecated.
+    pub const fn with_since(self, since: &'static str) -> Self {
+        Self {
+            since: Some(since),
+            ..self
+        }
+    }
+
+    /// Sets the name of the variant that replaces the deprecated one.
+    pub const fn with_replaced_by(self, replaced_by: &'static str) -> Self {
+        Self {
+            replaced_by: Some(replaced_by),
+            ..self
+        }
+    }
+
+    /// Sets the message explaining the deprecation.
+    pub const fn with_note(self, note: &'static str) -> Self {
+        Self {
+            note: Some(note),
+            ..self
+        }
+    }
+
+    /// The version in which the variant was deprecated, if any.
+    pub fn since(&self) -> Option<&'static str> {
+        self.since
+    }
+
+    /// The name of the variant that replaces the deprecated one, if any.
+    pub fn replaced_by(&self) -> Option<&'static str> {
+        self.replaced_by
+    }
+
+    /// The message explaining the deprecation, if any.
+    pub fn note(&self) -> Option<&'static str> {
+        self.note
+    }
+}
@@ -27,6 +27,20 @@ impl NamedField {
         }
     }
 
+    /// Create a new [`NamedField`] for a boxed trait object, whose concrete type is only known at runtime.
+    ///
+    /// The field is described as a `dyn Reflect`.
+    pub fn new_dyn(name: &'static str) -> Self {
+        Self {
+            name,
+            type_path: TypePathTable::of::<dyn Reflect>(),
+            type_id: TypeId::of::<dyn Reflect>(),
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -105,6 +119,20 @@ impl UnnamedField {
         }
     }
 
+    /// Create a new [`UnnamedField`] for a boxed trait object, whose concrete type is only known at runtime.
+    ///
+    /// The field is described as a `dyn Reflect`.
+    pub fn new_dyn(index: usize) -> Self {
+        Self {
+            index,
+            type_path: TypePathTable::of::<dyn Reflect>(),
+            type_id: TypeId::of::<dyn Reflect>(),
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
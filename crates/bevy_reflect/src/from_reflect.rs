@@ -1,4 +1,5 @@
 use crate::{FromType, Reflect};
+use thiserror::Error;
 
 /// A trait that enables types to be dynamically constructed from reflected data.
 ///
@@ -124,3 +125,59 @@ impl<T: FromReflect> FromType<T> for ReflectFromReflect {
         }
     }
 }
+
+/// An error describing why an enum could not be constructed from reflected data.
+///
+/// This is returned by the `from_dynamic_enum` function generated for enums deriving `Reflect`
+/// with `#[reflect(from_dynamic_enum)]`.
+///
+/// The error owns all of its names, so it is `Send + Sync + 'static`
+/// and may be passed across threads, such as out of an asynchronous asset loader.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum FromReflectError {
+    #[error("variant with name `{variant_name}` does not exist on enum `{enum_name}`")]
+    /// The enum has no variant with the given name.
+    UnknownVariant {
+        enum_name: Box<str>,
+        variant_name: Box<str>,
+    },
+
+    #[error("enum variant `{variant_name}` is ignored by reflection")]
+    /// The variant is marked `#[reflect(ignore)]` and so cannot be constructed.
+    IgnoredVariant { variant_name: Box<str> },
+
+    #[error("enum variant `{variant_name}` cannot be constructed from reflected data")]
+    /// The variant is not listed in the enum's `#[reflect(from_reflect(only = "..."))]` attribute.
+    ExcludedVariant { variant_name: Box<str> },
+
+    #[error(
+        "the default function of enum variant `{variant_name}` constructed a different variant"
+    )]
+    /// The variant's `#[reflect(default = "...")]` function returned a different variant,
+    /// so it could not provide the defaults for this variant's fields.
+    MismatchedVariantDefault { variant_name: Box<str> },
+
+    #[error("enum variant `{variant_name}` is missing its field `{field_name}`")]
+    /// A field of the variant was missing, and it has no default.
+    MissingField {
+        variant_name: Box<str>,
+        field_name: Box<str>,
+    },
+
+    #[error("field `{field_name}` of enum variant `{variant_name}` is `{from_type}`, which cannot be converted to `{to_type}`")]
+    /// A field of the variant could not be converted to the field's type.
+    MismatchedTypes {
+        variant_name: Box<str>,
+        field_name: Box<str>,
+        from_type: Box<str>,
+        to_type: Box<str>,
+    },
+
+    #[error("failed to create a default value for field `{field_name}` of enum variant `{variant_name}`: {error}")]
+    /// The fallible default function of a missing field returned an error.
+    FieldDefaultFailed {
+        variant_name: Box<str>,
+        field_name: Box<str>,
+        error: Box<str>,
+    },
+}
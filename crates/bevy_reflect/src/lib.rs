@@ -543,10 +543,13 @@ extern crate alloc;
 /// These are not meant to be used directly and are subject to breaking changes.
 #[doc(hidden)]
 pub mod __macro_exports {
+    use crate::std_traits::ReflectDefault;
     use crate::{
-        DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-        DynamicTupleStruct, GetTypeRegistration, TypeRegistry,
+        ApplyError, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct,
+        DynamicTuple, DynamicTupleStruct, Enum, FromReflect, GetTypeRegistration, Reflect,
+        ReflectFromReflect, ReflectMut, ReflectRef, TypeRegistration, TypeRegistry, VariantType,
     };
+    use std::any::{Any, TypeId};
 
     /// A wrapper trait around [`GetTypeRegistration`].
     ///
@@ -580,6 +583,337 @@ pub mod __macro_exports {
     impl RegisterForReflection for DynamicArray {}
 
     impl RegisterForReflection for DynamicTuple {}
+
+    /// Logs that a field of an enum variant could not be constructed by `FromReflect::from_reflect`.
+    ///
+    /// This is called by types marked `#[reflect(debug_from_reflect)]`,
+    /// and only logs when the `debug_from_reflect` feature is enabled.
+    #[inline]
+    #[allow(unused_variables)]
+    pub fn from_reflect_field_failed(type_path: &str, variant_name: &str, field_name: &str) {
+        #[cfg(feature = "debug_from_reflect")]
+        bevy_utils::tracing::debug!(
+            "failed to construct field `{field_name}` of variant `{variant_name}` on `{type_path}` from reflection"
+        );
+    }
+
+    /// Logs a warning, at most once per `once`, that a deprecated enum variant was constructed
+    /// by `FromReflect::from_reflect` or `Reflect::try_apply`.
+    ///
+    /// This is called for variants marked `#[reflect(deprecated = "...")]`,
+    /// and only logs when the `warn_deprecated_variants` feature is enabled.
+    #[inline]
+    #[allow(unused_variables)]
+    pub fn deprecated_variant_constructed(
+        once: &std::sync::Once,
+        type_path: &str,
+        variant_name: &str,
+        note: &str,
+    ) {
+        #[cfg(feature = "warn_deprecated_variants")]
+        once.call_once(|| {
+            bevy_utils::tracing::warn!(
+                "constructed deprecated variant `{variant_name}` of `{type_path}`: {note}"
+            );
+        });
+    }
+
+    /// Asserts that `output`, constructed by `FromReflect::from_reflect` from `input`,
+    /// is equal to `input` and has the same hash, if `input` is itself a `T`.
+    ///
+    /// This is called by enums registering both `#[reflect(Hash, PartialEq)]`,
+    /// and only checks in debug builds with the `verify_from_reflect` feature enabled.
+    #[inline]
+    #[allow(unused_variables)]
+    pub fn verify_from_reflect<T: Reflect + PartialEq + std::hash::Hash>(
+        input: &dyn Reflect,
+        output: &T,
+    ) {
+        #[cfg(all(debug_assertions, feature = "verify_from_reflect"))]
+        if let Some(input) = input.downcast_ref::<T>() {
+            use std::hash::Hasher;
+
+            let hash = |value: &T| {
+                let mut hasher = crate::utility::reflect_hasher();
+                value.hash(&mut hasher);
+                hasher.finish()
+            };
+
+            assert!(
+                output == input,
+                "`FromReflect::from_reflect` did not reconstruct an equal `{}`",
+                input.reflect_type_path()
+            );
+            assert_eq!(
+                hash(input),
+                hash(output),
+                "`FromReflect::from_reflect` did not reconstruct `{}` with the same hash",
+                input.reflect_type_path()
+            );
+        }
+    }
+
+    /// Provides access to the fields of a reflected variant,
+    /// which may be given as a struct, tuple struct, tuple or enum.
+    ///
+    /// This is used by the `from_tag` function generated for types marked `#[reflect(from_tag)]`,
+    /// and mirrors the field accessors of [`Enum`].
+    pub struct VariantFields<'a>(&'a dyn Reflect);
+
+    impl<'a> VariantFields<'a> {
+        pub fn new(fields: &'a dyn Reflect) -> Self {
+            Self(fields)
+        }
+
+        /// Returns the field with the given name, if the fields are named.
+        pub fn field(&self, name: &str) -> Option<&'a dyn Reflect> {
+            match self.0.reflect_ref() {
+                ReflectRef::Struct(fields) => fields.field(name),
+                ReflectRef::Enum(fields) => fields.field(name),
+                _ => None,
+            }
+        }
+
+        /// Returns whether the fields are named, like those of a struct variant, or positional.
+        pub fn variant_type(&self) -> VariantType {
+            match self.0.reflect_ref() {
+                ReflectRef::Struct(_) => VariantType::Struct,
+                ReflectRef::Enum(fields) => fields.variant_type(),
+                _ => VariantType::Tuple,
+            }
+        }
+
+        /// Returns the field at the given index.
+        pub fn field_at(&self, index: usize) -> Option<&'a dyn Reflect> {
+            match self.0.reflect_ref() {
+                ReflectRef::Struct(fields) => fields.field_at(index),
+                ReflectRef::TupleStruct(fields) => fields.field(index),
+                ReflectRef::Tuple(fields) => fields.field(index),
+                ReflectRef::Enum(fields) => fields.field_at(index),
+                _ => None,
+            }
+        }
+    }
+
+    /// Returns the variant name held by the key of an externally tagged enum,
+    /// which may be a `String` or a `&'static str`.
+    ///
+    /// This is used by types marked `#[reflect(from_reflect(externally_tagged))]`.
+    pub fn variant_name_from_key(key: &dyn Reflect) -> Option<&str> {
+        if let Some(key) = key.downcast_ref::<String>() {
+            Some(key)
+        } else {
+            key.downcast_ref::<&'static str>().copied()
+        }
+    }
+
+    /// Widens a reflected numeric `value` into the numeric type identified by `target`.
+    ///
+    /// Only lossless conversions (those covered by [`From`]) are performed,
+    /// such as `i32` to `i64` or `f32` to `f64`.
+    /// Returns `None` if `value` cannot be widened into `target`.
+    pub fn widen_numeric(value: &dyn Reflect, target: TypeId) -> Option<Box<dyn Reflect>> {
+        macro_rules! widen {
+            ($($target:ty: $($source:ty),*;)*) => {
+                $(
+                    if target == TypeId::of::<$target>() {
+                        $(
+                            if let Some(value) = value.downcast_ref::<$source>() {
+                                return Some(Box::new(<$target>::from(*value)));
+                            }
+                        )*
+                        return None;
+                    }
+                )*
+            };
+        }
+
+        widen! {
+            i16: i8, u8;
+            i32: i8, i16, u8, u16;
+            i64: i8, i16, i32, u8, u16, u32;
+            i128: i8, i16, i32, i64, u8, u16, u32, u64;
+            u16: u8;
+            u32: u8, u16;
+            u64: u8, u16, u32;
+            u128: u8, u16, u32, u64;
+            f32: i8, i16, u8, u16;
+            f64: f32, i8, i16, i32, u8, u16, u32;
+        }
+
+        None
+    }
+
+    /// Constructs `T` from `value` using [`FromReflect`],
+    /// falling back to a lossless numeric widening if `value` is a different numeric type.
+    ///
+    /// This is used by types marked `#[reflect(coerce_numeric)]`.
+    pub fn from_reflect_widened<T: FromReflect>(value: &dyn Reflect) -> Option<T> {
+        T::from_reflect(value)
+            .or_else(|| T::from_reflect(&*widen_numeric(value, TypeId::of::<T>())?))
+    }
+
+    /// Constructs the concrete type represented by `value` using the [`ReflectFromReflect`]
+    /// registered for it in `registry`, returning the constructed value along with its registration.
+    ///
+    /// This is used by `from_reflect_with_context` to construct fields marked `#[reflect(apply_via = "downcast")]`.
+    pub fn from_reflect_boxed<'a>(
+        value: &dyn Reflect,
+        registry: &'a TypeRegistry,
+    ) -> Option<(&'a TypeRegistration, Box<dyn Reflect>)> {
+        let type_id = value.get_represented_type_info()?.type_id();
+        let registration = registry.get(type_id)?;
+        let value = registration
+            .data::<ReflectFromReflect>()?
+            .from_reflect(value)?;
+        Some((registration, value))
+    }
+
+    /// Applies `value` to `target` using [`Reflect::try_apply`],
+    /// falling back to a lossless numeric widening if the types do not match.
+    ///
+    /// This is used by types marked `#[reflect(coerce_numeric)]`.
+    pub fn try_apply_widened(
+        target: &mut dyn Reflect,
+        value: &dyn Reflect,
+    ) -> Result<(), ApplyError> {
+        let result = target.try_apply(value);
+        if let Err(ApplyError::MismatchedTypes { .. }) = result {
+            if let Some(widened) = widen_numeric(value, Any::type_id(target.as_any())) {
+                return target.try_apply(&*widened);
+            }
+        }
+        result
+    }
+
+    /// Returns how many fields of `this` applying `value` would change,
+    /// comparing each field with [`Reflect::reflect_partial_eq`].
+    ///
+    /// A value of another variant changes the variant itself, which counts as a single change.
+    /// Fields `this` does not have are not counted, since applying them fails or skips them.
+    ///
+    /// This is used by the `apply_counting` method of types marked `#[reflect(apply_counting)]`.
+    pub fn count_changed_fields(this: &dyn Enum, value: &dyn Reflect) -> usize {
+        let is_changed = |current: Option<&dyn Reflect>, value: &dyn Reflect| {
+            current.is_some_and(|current| current.reflect_partial_eq(value) != Some(true))
+        };
+
+        match value.reflect_ref() {
+            ReflectRef::Enum(value) if value.variant_name() != this.variant_name() => 1,
+            ReflectRef::Enum(value) => value
+                .iter_fields()
+                .enumerate()
+                .filter(|(index, field)| {
+                    let current = match field.name() {
+                        // Tuple variants may also be given as struct variants with fields named "0", "1", ...
+                        Some(name) => this.field(name).or_else(|| {
+                            name.parse::<usize>()
+                                .ok()
+                                .and_then(|index| this.field_at(index))
+                        }),
+                        None => this.field_at(*index),
+                    };
+                    is_changed(current, field.value())
+                })
+                .count(),
+            ReflectRef::Struct(value) => value
+                .iter_fields()
+                .enumerate()
+                .filter(|(index, field)| {
+                    let current = value.name_at(*index).and_then(|name| this.field(name));
+                    is_changed(current, *field)
+                })
+                .count(),
+            // Any other value can only be applied to the single field of a newtype variant
+            _ => usize::from(is_changed(this.field_at(0), value)),
+        }
+    }
+
+    /// Applies `value` to the `Option` field `field` unless `value` is `None`.
+    ///
+    /// This is used by the `reflect_merge` method for fields marked `#[reflect(merge = "combine")]`.
+    pub fn merge_option(field: &mut dyn Reflect, value: &dyn Reflect) -> Result<(), ApplyError> {
+        match value.reflect_ref() {
+            ReflectRef::Enum(value) if value.variant_name() == "None" => Ok(()),
+            _ => field.try_apply(value),
+        }
+    }
+
+    /// Applies `value` to the field `field`, appending the elements of a list `value`
+    /// to those already in `field` rather than overwriting them.
+    ///
+    /// Other values, including maps (which are already merged key by key), are applied as usual.
+    ///
+    /// This is used by enum variant fields marked `#[reflect(apply = "extend")]`.
+    pub fn try_apply_extend(
+        field: &mut dyn Reflect,
+        value: &dyn Reflect,
+    ) -> Result<(), ApplyError> {
+        if let (ReflectMut::List(field), ReflectRef::List(value)) =
+            (field.reflect_mut(), value.reflect_ref())
+        {
+            for item in value.iter() {
+                field.push(item.clone_value());
+            }
+            return Ok(());
+        }
+
+        field.try_apply(value)
+    }
+
+    /// Marks the calling branch as unlikely to be taken.
+    ///
+    /// The optimizer treats branches calling a `#[cold]` function as unlikely,
+    /// and moves them out of the hot path.
+    ///
+    /// This is used by types marked `#[reflect(cold_errors)]`.
+    #[cold]
+    #[inline(never)]
+    pub fn cold_path() {}
+
+    /// Returns the name in `variant_names` closest to the unknown variant `name` by edit distance,
+    /// if it is close enough to likely be a typo.
+    ///
+    /// This is only called once an [`ApplyError::UnknownVariant`] is being returned,
+    /// so it is kept out of the hot path.
+    #[cold]
+    pub fn closest_variant_name(name: &str, variant_names: &[&str]) -> Option<Box<str>> {
+        // Allow roughly one edit for every three characters, like rustc's suggestions
+        let max_distance = name.chars().count().max(3) / 3;
+        variant_names
+            .iter()
+            .map(|variant_name| (edit_distance(name, variant_name), variant_name))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, variant_name)| Box::from(*variant_name))
+    }
+
+    /// Returns the Levenshtein distance between `a` and `b`,
+    /// the number of single character insertions, deletions and substitutions turning one into the other.
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let b = b.chars().collect::<Vec<_>>();
+        let mut row = (0..=b.len()).collect::<Vec<_>>();
+        for (i, a_char) in a.chars().enumerate() {
+            let mut previous = row[0];
+            row[0] = i + 1;
+            // Called as `<[char]>::iter` so that no `List` or `Array` import in scope can shadow it
+            for (j, b_char) in <[char]>::iter(&b).enumerate() {
+                let substitution = previous + usize::from(a_char != *b_char);
+                previous = row[j + 1];
+                row[j + 1] = substitution.min(previous + 1).min(row[j] + 1);
+            }
+        }
+        row[b.len()]
+    }
+
+    /// Creates a [`ReflectDefault`] that constructs values with `default`
+    /// rather than with the type's [`Default`] impl.
+    ///
+    /// This is used by enums whose `#[reflect(default)]` variant has fields
+    /// with their own `#[reflect(default = "...")]` functions.
+    pub fn reflect_default_from_fn(default: fn() -> Box<dyn Reflect>) -> ReflectDefault {
+        ReflectDefault { default }
+    }
 }
 
 #[cfg(test)]
@@ -935,6 +1269,46 @@ mod tests {
         assert_eq!(Some(expected), my_struct);
     }
 
+    #[test]
+    fn from_reflect_should_use_fallible_default_field_attributes() {
+        #[derive(Reflect, Eq, PartialEq, Debug)]
+        struct MyStruct {
+            #[reflect(try_default = "get_foo_default")]
+            foo: usize,
+        }
+
+        #[derive(Reflect, Eq, PartialEq, Debug)]
+        struct MyFailingStruct {
+            #[reflect(try_default = "get_failing_default")]
+            foo: usize,
+        }
+
+        fn get_foo_default() -> Result<usize, &'static str> {
+            Ok(123)
+        }
+
+        fn get_failing_default() -> Result<usize, &'static str> {
+            Err("no default")
+        }
+
+        let dyn_struct = DynamicStruct::default();
+        assert_eq!(
+            Some(MyStruct { foo: 123 }),
+            <MyStruct as FromReflect>::from_reflect(&dyn_struct)
+        );
+        assert_eq!(
+            None,
+            <MyFailingStruct as FromReflect>::from_reflect(&dyn_struct)
+        );
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", 321_usize);
+        assert_eq!(
+            Some(MyFailingStruct { foo: 321 }),
+            <MyFailingStruct as FromReflect>::from_reflect(&dyn_struct)
+        );
+    }
+
     #[test]
     fn from_reflect_should_use_default_variant_field_attributes() {
         #[derive(Reflect, Eq, PartialEq, Debug)]
@@ -998,6 +1372,106 @@ mod tests {
         assert_eq!(Some(expected), my_struct);
     }
 
+    #[test]
+    fn from_reflect_should_use_default_for_all_fields() {
+        #[derive(Reflect, Eq, PartialEq, Debug)]
+        #[reflect(from_reflect(default))]
+        struct MyStruct {
+            foo: String,
+            #[reflect(default = "get_bar_default")]
+            bar: usize,
+        }
+
+        #[derive(Reflect, Eq, PartialEq, Debug)]
+        #[reflect(from_reflect(default))]
+        enum MyEnum {
+            Foo(String, usize),
+            Bar {
+                #[reflect(default = "get_bar_default")]
+                bar: usize,
+                baz: u8,
+            },
+        }
+
+        fn get_bar_default() -> usize {
+            123
+        }
+
+        let dyn_struct = DynamicStruct::default();
+        assert_eq!(
+            Some(MyStruct {
+                foo: String::default(),
+                bar: 123,
+            }),
+            <MyStruct as FromReflect>::from_reflect(&dyn_struct)
+        );
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("foo", String::from("Hello"));
+        assert_eq!(
+            Some(MyStruct {
+                foo: String::from("Hello"),
+                bar: 123,
+            }),
+            <MyStruct as FromReflect>::from_reflect(&dyn_struct)
+        );
+
+        let dyn_enum = DynamicEnum::new("Foo", DynamicTuple::default());
+        assert_eq!(
+            Some(MyEnum::Foo(String::default(), 0)),
+            <MyEnum as FromReflect>::from_reflect(&dyn_enum)
+        );
+
+        // A field's own default takes precedence over the container's
+        let dyn_enum = DynamicEnum::new("Bar", DynamicStruct::default());
+        assert_eq!(
+            Some(MyEnum::Bar { bar: 123, baz: 0 }),
+            <MyEnum as FromReflect>::from_reflect(&dyn_enum)
+        );
+    }
+
+    #[test]
+    fn should_reflect_transparent_struct_as_its_field() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Inner {
+            A(usize),
+            B { value: f32 },
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(transparent)]
+        #[repr(transparent)]
+        struct Wrapper(Inner);
+
+        let mut value = Wrapper(Inner::A(123));
+        assert!(matches!(value.reflect_ref(), ReflectRef::Enum(_)));
+        assert_eq!(
+            Inner::type_path(),
+            value.get_represented_type_info().unwrap().type_path()
+        );
+
+        let mut dyn_struct = DynamicStruct::default();
+        dyn_struct.insert("value", 1.23_f32);
+        value.try_apply(&DynamicEnum::new("B", dyn_struct)).unwrap();
+        assert_eq!(Wrapper(Inner::B { value: 1.23 }), value);
+
+        let cloned = value.clone_value();
+        assert!(cloned.is::<DynamicEnum>());
+        assert_eq!(Some(&value), Wrapper::from_reflect(&*cloned).as_ref());
+        assert_eq!(Some(&value), Wrapper::from_reflect(&value).as_ref());
+        assert_eq!(
+            Some(true),
+            value.reflect_partial_eq(&Inner::B { value: 1.23 })
+        );
+
+        // The serialized data is identical to that of the wrapped enum
+        let mut registry = TypeRegistry::default();
+        registry.register::<Inner>();
+        let wrapped = ron::to_string(&ReflectSerializer::new(&value, &registry)).unwrap();
+        let unwrapped = ron::to_string(&ReflectSerializer::new(&value.0, &registry)).unwrap();
+        assert_eq!(unwrapped, wrapped);
+    }
+
     #[test]
     fn reflect_complex_patch() {
         #[derive(Reflect, Eq, PartialEq, Debug)]
@@ -2195,6 +2669,35 @@ bevy_reflect::tests::Test {
         assert_not_impl_all!(Foo<Baz>: Reflect);
     }
 
+    #[test]
+    fn should_replace_field_bounds_with_custom_bound() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(bound = "T: FromReflect + TypePath + GetTypeRegistration")]
+        enum Tree<T> {
+            Leaf(T),
+            Node(Vec<Tree<T>>),
+            Marker(#[reflect(ignore)] PhantomData<T>),
+        }
+
+        #[derive(Reflect)]
+        #[reflect(bound = "")]
+        enum Expr {
+            Literal(i32),
+            Add(Vec<Expr>),
+        }
+
+        assert_impl_all!(Tree<usize>: Reflect, FromReflect, GetTypeRegistration);
+        assert_impl_all!(Expr: Reflect, FromReflect, GetTypeRegistration);
+
+        let tree = Tree::Node(vec![Tree::Leaf(1_usize), Tree::Node(vec![])]);
+        let dyn_tree = tree.clone_dynamic();
+        assert_eq!(Some(tree), Tree::from_reflect(&dyn_tree));
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Expr>();
+        assert!(registry.contains(TypeId::of::<Expr>()));
+    }
+
     #[test]
     fn recursive_typed_storage_does_not_hang() {
         #[derive(Reflect)]
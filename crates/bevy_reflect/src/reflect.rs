@@ -1,7 +1,7 @@
 use crate::{
     array_debug, enum_debug, list_debug, map_debug, serde::Serializable, struct_debug, tuple_debug,
     tuple_struct_debug, Array, DynamicTypePath, Enum, List, Map, Struct, Tuple, TupleStruct,
-    TypeInfo, TypePath, Typed, ValueInfo,
+    TypeInfo, TypePath, Typed, ValueInfo, VariantType,
 };
 use std::{
     any::{Any, TypeId},
@@ -129,12 +129,99 @@ pub enum ApplyError {
     /// Attempted to apply to types with mismatched sizez, e.g. a [u8; 4] to [u8; 3].
     DifferentSize { from_size: usize, to_size: usize },
 
-    #[error("variant with name `{variant_name}` does not exist on enum `{enum_name}`")]
+    #[error("variant with name `{variant_name}` does not exist on enum `{enum_name}`{}", did_you_mean(.suggestion))]
     /// The enum we tried to apply to didn't contain a variant with the give name.
     UnknownVariant {
         enum_name: Box<str>,
         variant_name: Box<str>,
+        /// The existing variant whose name is closest to `variant_name`, if any is close enough.
+        suggestion: Option<Box<str>>,
     },
+
+    #[error("failed to create a default value for field `{field_name}` of enum variant `{variant_name}`: {error}")]
+    /// The fallible default function of a missing enum variant field returned an error.
+    FieldDefaultFailed {
+        variant_name: Box<str>,
+        field_name: Box<str>,
+        error: Box<str>,
+    },
+
+    #[error(
+        "enum variant `{variant_name}` does not reflect field `{field_name}`, but it was present"
+    )]
+    /// A field that the enum variant does not reflect, such as one marked `#[reflect(ignore)]`,
+    /// was present in the applied value.
+    ///
+    /// This is only returned by enums deriving `Reflect` with `#[reflect(strict_apply)]`.
+    IgnoredFieldPresent {
+        variant_name: Box<str>,
+        field_name: Box<str>,
+    },
+
+    #[error("enum variant `{variant_name}` has no field `{field_name}`, but it was present")]
+    /// A field that the enum variant does not declare was present in the applied value.
+    ///
+    /// This is only returned by enums deriving `Reflect` with `#[reflect(deny_unknown_fields)]`.
+    UnknownField {
+        variant_name: Box<str>,
+        field_name: Box<str>,
+    },
+
+    #[error("enum variant `{variant_name}` is a {expected:?} variant, but {found:?} variant data was given")]
+    /// The data applied to an enum variant had a different [`VariantType`] than the variant itself,
+    /// e.g. tuple data for a struct variant.
+    MismatchedVariantTypes {
+        variant_name: Box<str>,
+        expected: VariantType,
+        found: VariantType,
+    },
+
+    #[error("enum variant `{variant_name}` is ignored by reflection")]
+    /// The enum variant that was applied to, or that was being switched to,
+    /// is marked `#[reflect(ignore)]` and so cannot be applied.
+    IgnoredVariant { variant_name: Box<str> },
+
+    #[error("enum variant `{variant_name}` cannot be constructed from reflected data")]
+    /// The enum variant being constructed, such as by `TryFrom<&dyn Reflect>`,
+    /// is not listed in the enum's `#[reflect(from_reflect(only = "..."))]` attribute.
+    ExcludedVariant { variant_name: Box<str> },
+
+    #[error(
+        "the default function of enum variant `{variant_name}` constructed a different variant"
+    )]
+    /// The variant's `#[reflect(default = "...")]` function returned a different variant,
+    /// so it could not provide the defaults for this variant's fields.
+    MismatchedVariantDefault { variant_name: Box<str> },
+
+    #[error("{} errors occurred while applying: {}", .0.len(), DisplayErrors(.0))]
+    /// Multiple errors occurred while applying.
+    ///
+    /// This is only returned by types that opt into collecting every error,
+    /// such as enums deriving `Reflect` with `#[reflect(collect_apply_errors)]`.
+    Multiple(Vec<ApplyError>),
+}
+
+/// Formats the suggested variant of an [`ApplyError::UnknownVariant`], if any.
+fn did_you_mean(suggestion: &Option<Box<str>>) -> String {
+    suggestion
+        .as_ref()
+        .map(|suggestion| format!(", did you mean `{suggestion}`?"))
+        .unwrap_or_default()
+}
+
+/// Helper for displaying a list of [`ApplyError`]s separated by semicolons.
+struct DisplayErrors<'a>(&'a [ApplyError]);
+
+impl std::fmt::Display for DisplayErrors<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
 }
 
 /// A zero-sized enumuration of the "kinds" of a reflected type.
@@ -233,6 +320,12 @@ pub trait Reflect: DynamicTypePath + Any + Send + Sync {
     ///   the variant of `value`. The corresponding fields of that variant are
     ///   applied from `value` onto `self`. Fields which are not present in both
     ///   values are ignored.
+    ///   If `self` is already that variant, only the fields present in `value` are
+    ///   overwritten and the rest keep their current values, so `value` may be a
+    ///   partial [`DynamicEnum`](crate::DynamicEnum).
+    ///   For tuple variants, such a value can name fields by their index, like `"1"`,
+    ///   to overwrite only those fields.
+    ///   Otherwise, the new variant is constructed entirely from `value`.
     /// - If `T` is a [`List`] or [`Array`], then each element of `value` is applied
     ///   to the corresponding element of `self`. Up to `self.len()` items are applied,
     ///   and excess elements in `value` are appended to `self`.
@@ -274,6 +367,24 @@ pub trait Reflect: DynamicTypePath + Any + Send + Sync {
     /// consider maintaining a cloned instance of this data you can switch to if a error is encountered.
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError>;
 
+    /// Replaces this value with an owned reflected value of the same type,
+    /// or tries to [`apply`](Reflect::apply) it otherwise.
+    ///
+    /// If `value` is already of this type, it is [`set`](Reflect::set) directly,
+    /// moving it into place without reconstructing any of its fields.
+    /// Otherwise, this falls back to [`try_apply`](Reflect::try_apply).
+    ///
+    /// Note that setting replaces the whole value, unlike applying it.
+    /// Fields marked `#[reflect(ignore)]` are overwritten,
+    /// list elements past the end of `value` are dropped,
+    /// and map entries missing from `value` are removed.
+    fn set_or_try_apply(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        match self.set(value) {
+            Ok(()) => Ok(()),
+            Err(value) => self.try_apply(value.as_ref()),
+        }
+    }
+
     /// Performs a type-checked assignment of a reflected value to this value.
     ///
     /// If `value` does not contain a value of type `T`, returns an `Err`
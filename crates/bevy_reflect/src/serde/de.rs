@@ -1,8 +1,8 @@
-use crate::serde::SerializationData;
+use crate::serde::{EnumSerializationData, SerializationData};
 use crate::{
     ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
     DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, TupleInfo, TupleStructInfo,
+    ReflectDeserialize, Struct, StructInfo, StructVariantInfo, TupleInfo, TupleStructInfo,
     TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
 };
 use erased_serde::Deserializer;
@@ -644,14 +644,24 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration.data(),
+            self.registry,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registration, self.registry)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.registration.data(),
+            self.registry,
+        )
     }
 }
 
@@ -675,7 +685,7 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
         visit_tuple(
             &mut seq,
             self.tuple_struct_info,
-            self.registration,
+            self.registration.data(),
             self.registry,
         )
         .map(DynamicTupleStruct::from)
@@ -699,7 +709,12 @@ impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registration, self.registry)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.registration.data(),
+            self.registry,
+        )
     }
 }
 
@@ -839,6 +854,12 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
             enum_info: self.enum_info,
         })?;
 
+        let serialization_data = self
+            .registration
+            .data::<EnumSerializationData>()
+            .and_then(|data| data.variant(variant_info.name()));
+        let skipped_len = serialization_data.map(SerializationData::len).unwrap_or(0);
+
         let value: DynamicVariant = match variant_info {
             VariantInfo::Unit(..) => variant.unit_variant()?.into(),
             VariantInfo::Struct(struct_info) => variant
@@ -846,12 +867,12 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                     struct_info.field_names(),
                     StructVariantVisitor {
                         struct_info,
-                        registration: self.registration,
+                        serialization_data,
                         registry: self.registry,
                     },
                 )?
                 .into(),
-            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 && skipped_len == 0 => {
                 let registration = tuple_info.get_field_registration(0, self.registry)?;
                 let value = variant.newtype_variant_seed(TypedReflectDeserializer {
                     registration,
@@ -863,10 +884,10 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
             }
             VariantInfo::Tuple(tuple_info) => variant
                 .tuple_variant(
-                    tuple_info.field_len(),
+                    tuple_info.field_len() - skipped_len,
                     TupleVariantVisitor {
                         tuple_info,
-                        registration: self.registration,
+                        serialization_data,
                         registry: self.registry,
                     },
                 )?
@@ -919,14 +940,16 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
             where
                 E: Error,
             {
-                self.0.variant(variant_name).ok_or_else(|| {
-                    let names = self.0.iter().map(|variant| variant.name());
-                    Error::custom(format_args!(
-                        "unknown variant `{}`, expected one of {:?}",
-                        variant_name,
-                        ExpectedValues(names.collect())
-                    ))
-                })
+                self.0
+                    .variant(variant_name)
+                    .or_else(|| self.0.variant_by_alias(variant_name))
+                    .ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "unknown variant `{}`, expected one of {:?}",
+                            variant_name,
+                            ExpectedValues(self.0.variant_names().to_vec())
+                        ))
+                    })
             }
         }
 
@@ -936,7 +959,7 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
 
 struct StructVariantVisitor<'a> {
     struct_info: &'static StructVariantInfo,
-    registration: &'a TypeRegistration,
+    serialization_data: Option<&'a SerializationData>,
     registry: &'a TypeRegistry,
 }
 
@@ -951,20 +974,30 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.serialization_data,
+            self.registry,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registration, self.registry)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.serialization_data,
+            self.registry,
+        )
     }
 }
 
 struct TupleVariantVisitor<'a> {
     tuple_info: &'static TupleVariantInfo,
-    registration: &'a TypeRegistration,
+    serialization_data: Option<&'a SerializationData>,
     registry: &'a TypeRegistry,
 }
 
@@ -979,7 +1012,12 @@ impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registration, self.registry)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.serialization_data,
+            self.registry,
+        )
     }
 }
 
@@ -1036,7 +1074,7 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
 fn visit_struct<'de, T, V>(
     map: &mut V,
     info: &'static T,
-    registration: &TypeRegistration,
+    serialization_data: Option<&SerializationData>,
     registry: &TypeRegistry,
 ) -> Result<DynamicStruct, V::Error>
 where
@@ -1061,13 +1099,23 @@ where
         dynamic_struct.insert_boxed(&key, value);
     }
 
-    if let Some(serialization_data) = registration.data::<SerializationData>() {
+    if let Some(serialization_data) = serialization_data {
         for (skipped_index, skipped_field) in serialization_data.iter_skipped() {
             let Some(field) = info.field_at(*skipped_index) else {
                 continue;
             };
             dynamic_struct.insert_boxed(field.name(), skipped_field.generate_default());
         }
+
+        // Conditionally skipped fields are only defaulted if they were actually skipped
+        for (skip_if_index, skip_if_field) in serialization_data.iter_skip_if() {
+            let Some(field) = info.field_at(*skip_if_index) else {
+                continue;
+            };
+            if dynamic_struct.field(field.name()).is_none() {
+                dynamic_struct.insert_boxed(field.name(), skip_if_field.generate_default());
+            }
+        }
     }
 
     Ok(dynamic_struct)
@@ -1076,7 +1124,7 @@ where
 fn visit_tuple<'de, T, V>(
     seq: &mut V,
     info: &T,
-    registration: &TypeRegistration,
+    serialization_data: Option<&SerializationData>,
     registry: &TypeRegistry,
 ) -> Result<DynamicTuple, V::Error>
 where
@@ -1092,8 +1140,6 @@ where
         return Ok(tuple);
     }
 
-    let serialization_data = registration.data::<SerializationData>();
-
     for index in 0..len {
         if let Some(value) = serialization_data.and_then(|data| data.generate_default(index)) {
             tuple.insert_boxed(value);
@@ -1115,7 +1161,7 @@ where
 fn visit_struct_seq<'de, T, V>(
     seq: &mut V,
     info: &T,
-    registration: &TypeRegistration,
+    serialization_data: Option<&SerializationData>,
     registry: &TypeRegistry,
 ) -> Result<DynamicStruct, V::Error>
 where
@@ -1131,8 +1177,6 @@ where
         return Ok(dynamic_struct);
     }
 
-    let serialization_data = registration.data::<SerializationData>();
-
     for index in 0..len {
         let name = info.field_at(index).unwrap().name();
 
@@ -1183,7 +1227,9 @@ mod tests {
 
     use crate as bevy_reflect;
     use crate::serde::{ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer};
-    use crate::{DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypeRegistry};
+    use crate::{
+        DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypeInfo, TypeRegistry, Typed,
+    };
 
     #[derive(Reflect, Debug, PartialEq)]
     struct MyStruct {
@@ -1553,6 +1599,81 @@ mod tests {
         assert!(expected.reflect_partial_eq(output.as_ref()).unwrap());
     }
 
+    #[test]
+    fn enum_should_deserialize_variant_alias() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum MyEnum {
+            #[reflect(alias = "Round", alias = "Sphere")]
+            Circle(f32),
+            Square,
+        }
+
+        let mut registry = get_registry();
+        registry.register::<MyEnum>();
+
+        let input = r#"{
+    "bevy_reflect::serde::de::tests::MyEnum": Round(1.5),
+}"#;
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let output = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+
+        // The dynamic value uses the variant's current name
+        let expected = DynamicEnum::from(MyEnum::Circle(1.5));
+        assert!(expected.reflect_partial_eq(output.as_ref()).unwrap());
+        assert_eq!(
+            Some(MyEnum::Circle(1.5)),
+            <MyEnum as FromReflect>::from_reflect(output.as_ref())
+        );
+    }
+
+    #[test]
+    fn enum_should_round_trip_hidden_variant() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum MyEnum {
+            Circle(f32),
+            #[reflect(hidden)]
+            Internal(f32),
+        }
+
+        let mut registry = get_registry();
+        registry.register::<MyEnum>();
+
+        let TypeInfo::Enum(info) = MyEnum::type_info() else {
+            panic!("Expected `TypeInfo::Enum`");
+        };
+        assert_eq!(&["Circle"], info.variant_names());
+        assert!(info.variant("Internal").unwrap().is_hidden());
+
+        let input = MyEnum::Internal(1.5);
+        let serializer = ReflectSerializer::new(&input, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let output = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(
+            Some(input),
+            <MyEnum as FromReflect>::from_reflect(output.as_ref())
+        );
+
+        // Hidden variants are not advertised when a variant is unknown
+        let input = r#"{
+    "bevy_reflect::serde::de::tests::MyEnum": Square,
+}"#;
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let error = reflect_deserializer
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("unknown variant `Square`, expected one of `Circle`"),
+            "`error` was {error}"
+        );
+    }
+
     // Regression test for https://github.com/bevyengine/bevy/issues/12462
     #[test]
     fn should_reserialize() {
@@ -12,7 +12,7 @@ mod tests {
     use crate::{
         serde::{ReflectDeserializer, ReflectSerializer},
         type_registry::TypeRegistry,
-        DynamicStruct, FromReflect, Reflect,
+        DynamicEnum, DynamicStruct, FromReflect, Reflect,
     };
     use serde::de::DeserializeSeed;
 
@@ -136,6 +136,106 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_serialization_enum() {
+        #[derive(Debug, Reflect, PartialEq)]
+        enum TestEnum {
+            Struct {
+                a: i32,
+                #[reflect(skip_serializing)]
+                #[reflect(default = "custom_default")]
+                b: i32,
+            },
+            Tuple(#[reflect(skip_serializing)] i32, i32),
+            Unit,
+        }
+
+        fn custom_default() -> i32 {
+            -1
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestEnum>();
+
+        for (value, expected_ron, expected) in [
+            (
+                TestEnum::Struct { a: 1, b: 2 },
+                r#"{"bevy_reflect::serde::tests::TestEnum":Struct(a:1)}"#,
+                TestEnum::Struct { a: 1, b: -1 },
+            ),
+            (
+                TestEnum::Tuple(3, 4),
+                r#"{"bevy_reflect::serde::tests::TestEnum":Tuple(4)}"#,
+                TestEnum::Tuple(0, 4),
+            ),
+            (
+                TestEnum::Unit,
+                r#"{"bevy_reflect::serde::tests::TestEnum":Unit}"#,
+                TestEnum::Unit,
+            ),
+        ] {
+            let serializer = ReflectSerializer::new(&value, &registry);
+            let serialized = ron::ser::to_string(&serializer).unwrap();
+            assert_eq!(expected_ron, serialized);
+
+            let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+            let reflect_deserializer = ReflectDeserializer::new(&registry);
+            let deserialized = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+
+            let received = <TestEnum as FromReflect>::from_reflect(&*deserialized).unwrap();
+            assert_eq!(
+                expected, received,
+                "FromReflect failed: expected {expected:?} found {received:?}"
+            );
+        }
+
+        // Skipped fields may also be missing entirely
+        let mut dynamic_struct = DynamicStruct::default();
+        dynamic_struct.insert("a", 5_i32);
+        let dynamic_enum = DynamicEnum::new("Struct", dynamic_struct);
+        assert_eq!(
+            Some(TestEnum::Struct { a: 5, b: -1 }),
+            <TestEnum as FromReflect>::from_reflect(&dynamic_enum)
+        );
+    }
+
+    #[test]
+    fn test_serialization_skip_if() {
+        #[derive(Debug, Reflect, PartialEq)]
+        enum TestEnum {
+            Struct {
+                a: i32,
+                #[reflect(skip_if = "Option::is_none")]
+                b: Option<i32>,
+            },
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestEnum>();
+
+        for (value, expected_ron) in [
+            (
+                TestEnum::Struct { a: 1, b: None },
+                r#"{"bevy_reflect::serde::tests::TestEnum":Struct(a:1)}"#,
+            ),
+            (
+                TestEnum::Struct { a: 1, b: Some(2) },
+                r#"{"bevy_reflect::serde::tests::TestEnum":Struct(a:1,b:Some(2))}"#,
+            ),
+        ] {
+            let serializer = ReflectSerializer::new(&value, &registry);
+            let serialized = ron::ser::to_string(&serializer).unwrap();
+            assert_eq!(expected_ron, serialized);
+
+            let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+            let reflect_deserializer = ReflectDeserializer::new(&registry);
+            let deserialized = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+
+            let received = <TestEnum as FromReflect>::from_reflect(&*deserialized).unwrap();
+            assert_eq!(value, received);
+        }
+    }
+
     #[test]
     #[should_panic(
         expected = "cannot serialize dynamic value without represented type: bevy_reflect::DynamicStruct"
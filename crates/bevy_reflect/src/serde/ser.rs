@@ -11,7 +11,7 @@ use serde::{
     Serialize,
 };
 
-use super::SerializationData;
+use super::{EnumSerializationData, SerializationData};
 
 pub enum Serializable<'a> {
     Owned(Box<dyn erased_serde::Serialize + 'a>),
@@ -282,17 +282,22 @@ impl<'a> Serialize for StructSerializer<'a> {
             .registry
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
-        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
-        let mut state = serializer.serialize_struct(
-            struct_info.type_path_table().ident().unwrap(),
-            self.struct_value.field_len() - ignored_len,
-        )?;
+        let should_skip = |index: usize, value: &dyn Reflect| {
+            serialization_data
+                .map(|data| data.should_skip(index, value))
+                .unwrap_or(false)
+        };
+        let field_len = self
+            .struct_value
+            .iter_fields()
+            .enumerate()
+            .filter(|(index, value)| !should_skip(*index, *value))
+            .count();
+        let mut state = serializer
+            .serialize_struct(struct_info.type_path_table().ident().unwrap(), field_len)?;
 
         for (index, value) in self.struct_value.iter_fields().enumerate() {
-            if serialization_data
-                .map(|data| data.is_field_skipped(index))
-                .unwrap_or(false)
-            {
+            if should_skip(index, value) {
                 continue;
             }
             let key = struct_info.field_at(index).unwrap().name();
@@ -393,6 +398,18 @@ impl<'a> Serialize for EnumSerializer<'a> {
         let variant_type = self.enum_value.variant_type();
         let field_len = self.enum_value.field_len();
 
+        let serialization_data = self
+            .registry
+            .get(type_info.type_id())
+            .and_then(|registration| registration.data::<EnumSerializationData>())
+            .and_then(|data| data.variant(variant_name));
+        let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
+        let should_skip = |index: usize, value: &dyn Reflect| {
+            serialization_data
+                .map(|data| data.should_skip(index, value))
+                .unwrap_or(false)
+        };
+
         match variant_type {
             VariantType::Unit => {
                 if type_info.type_path_table().module_path() == Some("core::option")
@@ -413,13 +430,22 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     }
                 };
 
+                let serialized_len = self
+                    .enum_value
+                    .iter_fields()
+                    .enumerate()
+                    .filter(|(index, field)| !should_skip(*index, field.value()))
+                    .count();
                 let mut state = serializer.serialize_struct_variant(
                     enum_name,
                     variant_index,
                     variant_name,
-                    field_len,
+                    serialized_len,
                 )?;
                 for (index, field) in self.enum_value.iter_fields().enumerate() {
+                    if should_skip(index, field.value()) {
+                        continue;
+                    }
                     let field_info = struct_info.field_at(index).unwrap();
                     state.serialize_field(
                         field_info.name(),
@@ -428,7 +454,7 @@ impl<'a> Serialize for EnumSerializer<'a> {
                 }
                 state.end()
             }
-            VariantType::Tuple if field_len == 1 => {
+            VariantType::Tuple if field_len == 1 && ignored_len == 0 => {
                 let field = self.enum_value.field_at(0).unwrap();
 
                 if type_info.type_path_table().module_path() == Some("core::option")
@@ -449,9 +475,12 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     enum_name,
                     variant_index,
                     variant_name,
-                    field_len,
+                    field_len - ignored_len,
                 )?;
-                for field in self.enum_value.iter_fields() {
+                for (index, field) in self.enum_value.iter_fields().enumerate() {
+                    if should_skip(index, field.value()) {
+                        continue;
+                    }
                     state.serialize_field(&TypedReflectSerializer::new(
                         field.value(),
                         self.registry,
@@ -6,6 +6,7 @@ use bevy_utils::HashMap;
 #[derive(Debug, Clone)]
 pub struct SerializationData {
     skipped_fields: HashMap<usize, SkippedField>,
+    skip_if_fields: HashMap<usize, SkipIfField>,
 }
 
 impl SerializationData {
@@ -20,8 +21,24 @@ impl SerializationData {
     pub fn new<I: Iterator<Item = (usize, SkippedField)>>(skipped_iter: I) -> Self {
         Self {
             skipped_fields: skipped_iter.collect(),
+            skip_if_fields: HashMap::default(),
         }
     }
+
+    /// Adds the given fields, which are only skipped during serialization when their predicate returns true.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_if_iter`: The iterator of field indices to be conditionally skipped during serialization.
+    ///   Like [`new`](Self::new), indices are assigned only to reflected fields.
+    pub fn with_skip_if<I: Iterator<Item = (usize, SkipIfField)>>(
+        mut self,
+        skip_if_iter: I,
+    ) -> Self {
+        self.skip_if_fields.extend(skip_if_iter);
+        self
+    }
+
     /// Returns true if the given index corresponds to a field meant to be skipped during (de)serialization.
     ///
     /// # Example
@@ -60,6 +77,38 @@ impl SerializationData {
         self.skipped_fields.contains_key(&index)
     }
 
+    /// Returns true if the given value of the field at the given index should be skipped during serialization.
+    ///
+    /// This is the case for fields that are always skipped,
+    /// as well as for fields marked `#[reflect(skip_if = "...")]` whose predicate returns true for `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_reflect::{Reflect, TypeRegistry, serde::SerializationData};
+    /// #[derive(Reflect)]
+    /// struct MyStruct {
+    ///   #[reflect(skip_if = "Option::is_none")]
+    ///   maybe: Option<i32>,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<MyStruct>();
+    ///
+    /// let serialization_data = registry.get_type_data::<SerializationData>(TypeId::of::<MyStruct>()).unwrap();
+    /// assert!(serialization_data.should_skip(0, &None::<i32>));
+    /// assert!(!serialization_data.should_skip(0, &Some(123)));
+    /// ```
+    pub fn should_skip(&self, index: usize, value: &dyn Reflect) -> bool {
+        self.is_field_skipped(index)
+            || self
+                .skip_if_fields
+                .get(&index)
+                .map(|field| field.should_skip(value))
+                .unwrap_or(false)
+    }
+
     /// Generates a default instance of the skipped field at the given index.
     ///
     /// Returns `None` if the field is not skipped.
@@ -111,6 +160,62 @@ impl SerializationData {
     pub fn iter_skipped(&self) -> Iter<'_, usize, SkippedField> {
         self.skipped_fields.iter()
     }
+
+    /// Returns an iterator over the fields marked `#[reflect(skip_if = "...")]`.
+    ///
+    /// Each item in the iterator is a tuple containing:
+    /// 1. The reflected index of the field
+    /// 2. The (de)serialization metadata of the field
+    pub fn iter_skip_if(&self) -> Iter<'_, usize, SkipIfField> {
+        self.skip_if_fields.iter()
+    }
+}
+
+/// Contains data relevant to the automatic reflect powered (de)serialization of an enum type.
+///
+/// Each variant with fields marked `#[reflect(skip_serializing)]` has its own [`SerializationData`],
+/// indexed by the variant's name.
+#[derive(Debug, Clone)]
+pub struct EnumSerializationData {
+    variants: HashMap<&'static str, SerializationData>,
+}
+
+impl EnumSerializationData {
+    /// Creates a new `EnumSerializationData` instance with the given variants.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant_iter`: The iterator of variant names and the [`SerializationData`] of their fields.
+    ///   Variants without any skipped fields do not need to be included in this iterator.
+    pub fn new<I: Iterator<Item = (&'static str, SerializationData)>>(variant_iter: I) -> Self {
+        Self {
+            variants: variant_iter.collect(),
+        }
+    }
+
+    /// Returns the [`SerializationData`] of the variant with the given name, if it has any skipped fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_reflect::{Reflect, TypeRegistry, serde::EnumSerializationData};
+    /// #[derive(Reflect)]
+    /// enum MyEnum {
+    ///   A(i32, #[reflect(skip_serializing)] i32),
+    ///   B(i32),
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<MyEnum>();
+    ///
+    /// let serialization_data = registry.get_type_data::<EnumSerializationData>(TypeId::of::<MyEnum>()).unwrap();
+    /// assert!(serialization_data.variant("A").unwrap().is_field_skipped(1));
+    /// assert!(serialization_data.variant("B").is_none());
+    /// ```
+    pub fn variant(&self, name: &str) -> Option<&SerializationData> {
+        self.variants.get(name)
+    }
 }
 
 /// Data needed for (de)serialization of a skipped field.
@@ -134,3 +239,36 @@ impl SkippedField {
         (self.default_fn)()
     }
 }
+
+/// Data needed for (de)serialization of a field marked `#[reflect(skip_if = "...")]`.
+#[derive(Debug, Clone)]
+pub struct SkipIfField {
+    predicate: fn(&dyn Reflect) -> bool,
+    default_fn: fn() -> Box<dyn Reflect>,
+}
+
+impl SkipIfField {
+    /// Create a new `SkipIfField`.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate`: A function pointer deciding whether a value of the field should be skipped.
+    /// * `default_fn`: A function pointer used to generate a default instance of the field
+    ///   when it is absent during deserialization.
+    pub fn new(predicate: fn(&dyn Reflect) -> bool, default_fn: fn() -> Box<dyn Reflect>) -> Self {
+        Self {
+            predicate,
+            default_fn,
+        }
+    }
+
+    /// Returns true if the given value of the field should be skipped during serialization.
+    pub fn should_skip(&self, value: &dyn Reflect) -> bool {
+        (self.predicate)(value)
+    }
+
+    /// Generates a default instance of the field.
+    pub fn generate_default(&self) -> Box<dyn Reflect> {
+        (self.default_fn)()
+    }
+}
@@ -5,7 +5,7 @@ use crate::{FromType, Reflect};
 /// A [`ReflectDefault`] for type `T` can be obtained via [`FromType::from_type`].
 #[derive(Clone)]
 pub struct ReflectDefault {
-    default: fn() -> Box<dyn Reflect>,
+    pub(crate) default: fn() -> Box<dyn Reflect>,
 }
 
 impl ReflectDefault {
@@ -77,6 +77,12 @@ impl Default for TypeRegistry {
     }
 }
 
+impl AsRef<TypeRegistry> for TypeRegistry {
+    fn as_ref(&self) -> &TypeRegistry {
+        self
+    }
+}
+
 impl TypeRegistry {
     /// Create a type registry with *no* registered types.
     pub fn empty() -> Self {